@@ -0,0 +1,1019 @@
+//! 导出管理器
+
+use rust_xlsxwriter::{Workbook, Format, FormatAlign, Color};
+use crate::i18n::{locale, tr, trf};
+use crate::logging;
+use crate::logic::{DrawStatistics, OutcomeItem, OutcomeSummary, ScheduleConflict, ScheduleItem};
+use crate::models::{AuditEntry, DrawRecord, Organization};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+/// 导出管理器
+pub struct ExportManager;
+
+impl ExportManager {
+    /// 导出抽签记录到Excel（首行为单位报表抬头）
+    pub fn export_to_excel(records: &[DrawRecord], organization: &Organization, path: &PathBuf) -> Result<(), String> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        
+        // 设置列宽
+        worksheet.set_column_width(0, 20).map_err(|e| e.to_string())?;
+        worksheet.set_column_width(1, 15).map_err(|e| e.to_string())?;
+        worksheet.set_column_width(2, 12).map_err(|e| e.to_string())?;
+        worksheet.set_column_width(3, 12).map_err(|e| e.to_string())?;
+        worksheet.set_column_width(4, 15).map_err(|e| e.to_string())?;
+        worksheet.set_column_width(5, 40).map_err(|e| e.to_string())?;
+        
+        // 报表抬头格式
+        let title_format = Format::new()
+            .set_bold()
+            .set_font_size(16)
+            .set_align(FormatAlign::Center);
+        
+        // 标题格式
+        let header_format = Format::new()
+            .set_bold()
+            .set_font_size(12)
+            .set_align(FormatAlign::Center)
+            .set_background_color(Color::RGB(0x4472C4))
+            .set_font_color(Color::White);
+        
+        // 数据格式
+        let normal_format = Format::new()
+            .set_align(FormatAlign::Center);
+        
+        // 作废记录格式（删除线）
+        let voided_format = Format::new()
+            .set_align(FormatAlign::Center)
+            .set_font_strikethrough()
+            .set_font_color(Color::Gray);
+        
+        // 写入报表抬头
+        worksheet.merge_range(0, 0, 0, 5, &organization.report_title(), &title_format).map_err(|e| e.to_string())?;
+        
+        // 写入标题行
+        worksheet.write_string_with_format(1, 0, tr("抽签时间"), &header_format).map_err(|e| e.to_string())?;
+        worksheet.write_string_with_format(1, 1, tr("被检查部门"), &header_format).map_err(|e| e.to_string())?;
+        worksheet.write_string_with_format(1, 2, tr("专责类型"), &header_format).map_err(|e| e.to_string())?;
+        worksheet.write_string_with_format(1, 3, tr("抽中人员"), &header_format).map_err(|e| e.to_string())?;
+        worksheet.write_string_with_format(1, 4, tr("所属部门"), &header_format).map_err(|e| e.to_string())?;
+        worksheet.write_string_with_format(1, 5, tr("状态"), &header_format).map_err(|e| e.to_string())?;
+        
+        // 写入数据
+        for (idx, record) in records.iter().enumerate() {
+            let row = (idx + 2) as u32;
+            let data_format = if record.is_voided() { &voided_format } else { &normal_format };
+            
+            worksheet.write_string_with_format(
+                row, 0,
+                &record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                data_format
+            ).map_err(|e| e.to_string())?;
+            
+            worksheet.write_string_with_format(
+                row, 1,
+                &record.target_department_name,
+                data_format
+            ).map_err(|e| e.to_string())?;
+            
+            worksheet.write_string_with_format(
+                row, 2,
+                record.specialty_label(),
+                data_format
+            ).map_err(|e| e.to_string())?;
+            
+            worksheet.write_string_with_format(
+                row, 3,
+                &record.selected_specialist_name,
+                data_format
+            ).map_err(|e| e.to_string())?;
+            
+            worksheet.write_string_with_format(
+                row, 4,
+                &record.selected_from_department_name,
+                data_format
+            ).map_err(|e| e.to_string())?;
+            
+            worksheet.write_string_with_format(
+                row, 5,
+                Self::status_text(record),
+                &normal_format
+            ).map_err(|e| e.to_string())?;
+        }
+        
+        // 保存文件
+        workbook.save(path).map_err(|e| e.to_string())?;
+        
+        Ok(())
+    }
+    
+    /// 导出抽签记录到CSV（UTF-8 带 BOM，Excel 可直接打开）
+    pub fn export_to_csv(records: &[DrawRecord], path: &Path) -> Result<(), String> {
+        let headers = ["抽签时间", "轮次", "被检查部门", "专责类型", "抽中部门", "随机种子", "操作员", "状态"];
+        let header: Vec<String> = headers.iter().map(|h| csv_field(tr(h))).collect();
+        let mut csv = format!("\u{feff}{}\n", header.join(","));
+        for record in records {
+            let fields = [
+                record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                record.round.to_string(),
+                record.target_department_name.clone(),
+                record.specialty_label().to_string(),
+                record.selected_specialist_name.clone(),
+                record.seed.map(|s| s.to_string()).unwrap_or_default(),
+                record.operator_name.clone().unwrap_or_default(),
+                Self::status_text(record),
+            ];
+            let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+            csv.push_str(&line.join(","));
+            csv.push('\n');
+        }
+        std::fs::write(path, csv).map_err(|e| e.to_string())
+    }
+    
+    /// 导出抽签记录到PDF
+    ///
+    /// 优先由打印用的 HTML 转换（保留标志和作废删除线）；没有可用的转换工具时用内置排版直接生成
+    pub fn export_to_pdf(records: &[DrawRecord], organization: &Organization, path: &Path) -> Result<(), String> {
+        let html_path = std::env::temp_dir().join(format!("quality_draw_export_{}.html", std::process::id()));
+        std::fs::write(&html_path, Self::generate_print_html(records, organization)).map_err(|e| e.to_string())?;
+        let converted = Self::html_to_pdf(&html_path, path);
+        let _ = std::fs::remove_file(&html_path);
+        
+        match converted {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                logging::info("export", "HTML 转 PDF 不可用，改用内置排版", &[("reason", &e)]);
+                Self::render_pdf(records, organization, path)
+            }
+        }
+    }
+    
+    /// 用内置排版生成 PDF（不依赖外部程序，需要系统中有 TrueType 中文字体）
+    pub fn render_pdf(records: &[DrawRecord], organization: &Organization, path: &Path) -> Result<(), String> {
+        use genpdf::elements::{Break, FrameCellDecorator, Paragraph, TableLayout, Text};
+        use genpdf::style::{Style, StyledString};
+        use genpdf::{fonts, Document, SimplePageDecorator};
+        
+        let font = fonts::FontData::new(load_pdf_font()?, None)
+            .map_err(|e| trf("解析字体数据失败: {}", &[&e]))?;
+        // 中文字体通常只有一个文件，各样式共用同一字体
+        let mut doc = Document::new(fonts::FontFamily {
+            regular: font.clone(),
+            bold: font.clone(),
+            italic: font.clone(),
+            bold_italic: font,
+        });
+        doc.set_title(tr("质量监督检查抽签结果"));
+        let mut decorator = SimplePageDecorator::new();
+        decorator.set_margins(20);
+        doc.set_page_decorator(decorator);
+        
+        doc.push(Paragraph::new(StyledString::new(organization.report_title(), Style::new().bold().with_font_size(18))));
+        doc.push(Break::new(1));
+        doc.push(Paragraph::new(trf("导出时间: {}", &[&chrono::Local::now().format("%Y-%m-%d %H:%M:%S")])));
+        doc.push(Break::new(1));
+        
+        let mut table = TableLayout::new(vec![1, 3, 3, 2, 3]);
+        table.set_cell_decorator(FrameCellDecorator::new(true, true, false));
+        let mut header = table.row();
+        for title in ["序号", "抽签时间", "被检查部门", "专责类型", "抽中人员"] {
+            header = header.element(Text::new(StyledString::new(tr(title), Style::new().bold())));
+        }
+        header.push().map_err(|e| trf("生成 PDF 失败: {}", &[&e]))?;
+        
+        for (idx, record) in records.iter().enumerate() {
+            // PDF 不支持删除线，作废记录在抽中人员后标注
+            let selected = if record.is_voided() {
+                trf("{}（已作废）", &[&record.selected_specialist_name])
+            } else {
+                record.selected_specialist_name.clone()
+            };
+            table
+                .row()
+                .element(Text::new((idx + 1).to_string()))
+                .element(Text::new(record.timestamp.format("%Y-%m-%d %H:%M").to_string()))
+                .element(Text::new(record.target_department_name.as_str()))
+                .element(Text::new(record.specialty_label()))
+                .element(Text::new(selected))
+                .push()
+                .map_err(|e| trf("生成 PDF 失败: {}", &[&e]))?;
+        }
+        doc.push(table);
+        
+        doc.push(Break::new(1));
+        doc.push(Paragraph::new(trf("共计 {} 条抽签记录", &[&records.len()])));
+        for (idx, record) in records.iter().enumerate().filter(|(_, r)| r.is_voided()) {
+            doc.push(Paragraph::new(trf("第{}条{}", &[&(idx + 1), &Self::status_text(record)])));
+        }
+        
+        doc.render_to_file(path).map_err(|e| trf("生成 PDF 失败: {}", &[&e]))
+    }
+    
+    /// 导出审计日志到Excel
+    pub fn export_audit_to_excel(entries: &[AuditEntry], path: &PathBuf) -> Result<(), String> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        
+        // 设置列宽
+        let widths = [20.0, 12.0, 12.0, 30.0, 50.0, 50.0];
+        for (col, width) in widths.iter().enumerate() {
+            worksheet.set_column_width(col as u16, *width).map_err(|e| e.to_string())?;
+        }
+        
+        // 标题格式
+        let header_format = Format::new()
+            .set_bold()
+            .set_font_size(12)
+            .set_align(FormatAlign::Center)
+            .set_background_color(Color::RGB(0x4472C4))
+            .set_font_color(Color::White);
+        
+        // 写入标题行
+        let headers = ["操作时间", "操作员", "操作", "操作对象", "修改前", "修改后"];
+        for (col, header) in headers.iter().enumerate() {
+            worksheet.write_string_with_format(0, col as u16, tr(header), &header_format).map_err(|e| e.to_string())?;
+        }
+        
+        // 写入数据
+        for (idx, entry) in entries.iter().enumerate() {
+            let row = (idx + 1) as u32;
+            let values = [
+                entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                entry.operator_name.clone(),
+                entry.action.display_name().to_string(),
+                entry.target.clone(),
+                entry.old_value.clone().unwrap_or_default(),
+                entry.new_value.clone().unwrap_or_default(),
+            ];
+            for (col, value) in values.iter().enumerate() {
+                worksheet.write_string(row, col as u16, value).map_err(|e| e.to_string())?;
+            }
+        }
+        
+        // 保存文件
+        workbook.save(path).map_err(|e| e.to_string())?;
+        
+        Ok(())
+    }
+    
+    /// 导出检查日程安排表到Excel（首行为单位报表抬头，冲突的安排标红）
+    pub fn export_schedule_to_excel(
+        items: &[ScheduleItem],
+        conflicts: &[ScheduleConflict],
+        organization: &Organization,
+        path: &Path,
+    ) -> Result<(), String> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        
+        // 设置列宽
+        let widths = [14.0, 8.0, 18.0, 12.0, 18.0, 8.0, 30.0, 40.0];
+        for (col, width) in widths.iter().enumerate() {
+            worksheet.set_column_width(col as u16, *width).map_err(|e| e.to_string())?;
+        }
+        
+        let title_format = Format::new()
+            .set_bold()
+            .set_font_size(16)
+            .set_align(FormatAlign::Center);
+        let header_format = Format::new()
+            .set_bold()
+            .set_font_size(12)
+            .set_align(FormatAlign::Center)
+            .set_background_color(Color::RGB(0x4472C4))
+            .set_font_color(Color::White);
+        let normal_format = Format::new()
+            .set_align(FormatAlign::Center);
+        let conflict_format = Format::new()
+            .set_align(FormatAlign::Center)
+            .set_font_color(Color::Red);
+        
+        // 写入报表抬头和标题行
+        let title = trf("{}检查日程安排", &[&organization.name]);
+        worksheet.merge_range(0, 0, 0, 7, &title, &title_format).map_err(|e| e.to_string())?;
+        let headers = ["检查日期", "时段", "被检查部门", "专业", "检查部门", "轮次", "备注", "冲突"];
+        for (col, header) in headers.iter().enumerate() {
+            worksheet.write_string_with_format(1, col as u16, tr(header), &header_format).map_err(|e| e.to_string())?;
+        }
+        
+        // 写入数据（未安排的记录日期留空）
+        for (idx, item) in items.iter().enumerate() {
+            let row = (idx + 2) as u32;
+            let conflict: Vec<String> = conflicts.iter()
+                .filter(|c| c.record_ids.contains(&item.record.id))
+                .map(ScheduleConflict::description)
+                .collect();
+            let data_format = if conflict.is_empty() { &normal_format } else { &conflict_format };
+            let values = [
+                item.entry.map(|e| e.date.format("%Y-%m-%d").to_string()).unwrap_or_else(|| tr("未安排").to_string()),
+                item.entry.map(|e| e.slot.display_name().to_string()).unwrap_or_default(),
+                item.record.target_department_name.clone(),
+                item.record.specialty_label().to_string(),
+                item.record.selected_from_department_name.clone(),
+                item.record.round.to_string(),
+                item.entry.map(|e| e.note.clone()).unwrap_or_default(),
+                conflict.join(tr("；")),
+            ];
+            for (col, value) in values.iter().enumerate() {
+                worksheet.write_string_with_format(row, col as u16, value, data_format).map_err(|e| e.to_string())?;
+            }
+        }
+        
+        workbook.save(path).map_err(|e| e.to_string())?;
+        
+        Ok(())
+    }
+    
+    /// 导出年度质量报告到Excel：检查结果明细和按被检部门的汇总两个工作表
+    pub fn export_annual_report(
+        items: &[OutcomeItem],
+        summaries: &[OutcomeSummary],
+        organization: &Organization,
+        year: i32,
+        path: &Path,
+    ) -> Result<(), String> {
+        let mut workbook = Workbook::new();
+        
+        let title_format = Format::new()
+            .set_bold()
+            .set_font_size(16)
+            .set_align(FormatAlign::Center);
+        let header_format = Format::new()
+            .set_bold()
+            .set_font_size(12)
+            .set_align(FormatAlign::Center)
+            .set_background_color(Color::RGB(0x4472C4))
+            .set_font_color(Color::White);
+        let normal_format = Format::new()
+            .set_align(FormatAlign::Center);
+        let total_format = Format::new()
+            .set_bold()
+            .set_align(FormatAlign::Center);
+        
+        // 检查结果明细
+        let worksheet = workbook.add_worksheet().set_name(tr("检查结果明细")).map_err(|e| e.to_string())?;
+        let widths = [8.0, 18.0, 12.0, 18.0, 10.0, 10.0, 10.0, 10.0, 10.0, 14.0, 40.0];
+        for (col, width) in widths.iter().enumerate() {
+            worksheet.set_column_width(col as u16, *width).map_err(|e| e.to_string())?;
+        }
+        let title = trf("{}{}年度质量监督检查结果", &[&organization.name, &year]);
+        worksheet.merge_range(0, 0, 0, 10, &title, &title_format).map_err(|e| e.to_string())?;
+        let headers = ["轮次", "被检查部门", "专业", "检查部门", "状态", "严重问题", "一般问题", "轻微问题", "评分", "结案日期", "检查报告"];
+        for (col, header) in headers.iter().enumerate() {
+            worksheet.write_string_with_format(1, col as u16, tr(header), &header_format).map_err(|e| e.to_string())?;
+        }
+        for (idx, item) in items.iter().enumerate() {
+            let row = (idx + 2) as u32;
+            let findings = item.outcome.map(|o| o.findings).unwrap_or_default();
+            let values = [
+                item.record.round.to_string(),
+                item.record.target_department_name.clone(),
+                item.record.specialty_label().to_string(),
+                item.record.selected_from_department_name.clone(),
+                item.status().display_name().to_string(),
+                findings.major.to_string(),
+                findings.general.to_string(),
+                findings.minor.to_string(),
+                item.outcome.and_then(|o| o.score).map(|s| format!("{:.1}", s)).unwrap_or_default(),
+                item.outcome.and_then(|o| o.closed_on).map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default(),
+                item.outcome.and_then(|o| o.report_path.clone()).unwrap_or_default(),
+            ];
+            for (col, value) in values.iter().enumerate() {
+                worksheet.write_string_with_format(row, col as u16, value, &normal_format).map_err(|e| e.to_string())?;
+            }
+        }
+        
+        // 按被检部门汇总（第一行为合计）
+        let worksheet = workbook.add_worksheet().set_name(tr("汇总")).map_err(|e| e.to_string())?;
+        let widths = [18.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0, 10.0];
+        for (col, width) in widths.iter().enumerate() {
+            worksheet.set_column_width(col as u16, *width).map_err(|e| e.to_string())?;
+        }
+        let title = trf("{}{}年度质量监督检查汇总", &[&organization.name, &year]);
+        worksheet.merge_range(0, 0, 0, 9, &title, &title_format).map_err(|e| e.to_string())?;
+        let headers = ["被检查部门", "检查项数", "计划中", "检查中", "已完成", "严重问题", "一般问题", "轻微问题", "问题合计", "平均评分"];
+        for (col, header) in headers.iter().enumerate() {
+            worksheet.write_string_with_format(1, col as u16, tr(header), &header_format).map_err(|e| e.to_string())?;
+        }
+        for (idx, summary) in summaries.iter().enumerate() {
+            let row = (idx + 2) as u32;
+            let data_format = if idx == 0 { &total_format } else { &normal_format };
+            let values = [
+                summary.name.clone(),
+                summary.total.to_string(),
+                summary.planned.to_string(),
+                summary.in_progress.to_string(),
+                summary.done.to_string(),
+                summary.findings.major.to_string(),
+                summary.findings.general.to_string(),
+                summary.findings.minor.to_string(),
+                summary.findings.total().to_string(),
+                summary.average_score.map(|s| format!("{:.1}", s)).unwrap_or_default(),
+            ];
+            for (col, value) in values.iter().enumerate() {
+                worksheet.write_string_with_format(row, col as u16, value, data_format).map_err(|e| e.to_string())?;
+            }
+        }
+        
+        workbook.save(path).map_err(|e| e.to_string())?;
+        
+        Ok(())
+    }
+    
+    /// 导出抽签统计到Excel：部门次数、配对热力图、公平性检验和季度趋势四个工作表
+    pub fn export_statistics_to_excel(stats: &DrawStatistics, organization: &Organization, path: &Path) -> Result<(), String> {
+        let mut workbook = Workbook::new();
+        
+        let title_format = Format::new()
+            .set_bold()
+            .set_font_size(16)
+            .set_align(FormatAlign::Center);
+        let header_format = Format::new()
+            .set_bold()
+            .set_font_size(12)
+            .set_align(FormatAlign::Center)
+            .set_background_color(Color::RGB(0x4472C4))
+            .set_font_color(Color::White);
+        let normal_format = Format::new()
+            .set_align(FormatAlign::Center);
+        let section_format = Format::new()
+            .set_bold();
+        let title = trf("{}抽签统计（有效记录 {} 条）", &[&organization.name, &stats.total_draws]);
+        
+        // 部门次数
+        let worksheet = workbook.add_worksheet().set_name(tr("部门次数")).map_err(|e| e.to_string())?;
+        for (col, width) in [12.0, 18.0, 12.0, 12.0, 12.0].iter().enumerate() {
+            worksheet.set_column_width(col as u16, *width).map_err(|e| e.to_string())?;
+        }
+        worksheet.merge_range(0, 0, 0, 4, &title, &title_format).map_err(|e| e.to_string())?;
+        for (col, header) in ["专业", "部门", "被检次数", "检查次数", "期望检查次数"].iter().enumerate() {
+            worksheet.write_string_with_format(1, col as u16, tr(header), &header_format).map_err(|e| e.to_string())?;
+        }
+        let mut row = 2;
+        for specialty in &stats.specialties {
+            for department in &specialty.departments {
+                worksheet.write_string_with_format(row, 0, &specialty.specialty_name, &normal_format).map_err(|e| e.to_string())?;
+                worksheet.write_string_with_format(row, 1, &department.name, &normal_format).map_err(|e| e.to_string())?;
+                worksheet.write_number_with_format(row, 2, department.as_target as f64, &normal_format).map_err(|e| e.to_string())?;
+                worksheet.write_number_with_format(row, 3, department.as_inspector as f64, &normal_format).map_err(|e| e.to_string())?;
+                worksheet.write_string_with_format(row, 4, format!("{:.2}", department.expected), &normal_format).map_err(|e| e.to_string())?;
+                row += 1;
+            }
+        }
+        
+        // 配对热力图：每个专业一块，行为被检部门，列为检查部门，次数越多底色越深
+        let worksheet = workbook.add_worksheet().set_name(tr("配对热力图")).map_err(|e| e.to_string())?;
+        worksheet.set_column_width(0, 18).map_err(|e| e.to_string())?;
+        let mut row = 0;
+        for specialty in &stats.specialties {
+            let section = trf("{}（行：被检部门，列：检查部门）", &[&specialty.specialty_name]);
+            worksheet.write_string_with_format(row, 0, &section, &section_format).map_err(|e| e.to_string())?;
+            row += 1;
+            for (col, department) in specialty.departments.iter().enumerate() {
+                worksheet.set_column_width((col + 1) as u16, 12).map_err(|e| e.to_string())?;
+                worksheet.write_string_with_format(row, (col + 1) as u16, &department.name, &header_format).map_err(|e| e.to_string())?;
+            }
+            row += 1;
+            let max = specialty.pairs.iter().flatten().copied().max().unwrap_or(0).max(1);
+            for (target, counts) in specialty.pairs.iter().enumerate() {
+                worksheet.write_string_with_format(row, 0, &specialty.departments[target].name, &header_format).map_err(|e| e.to_string())?;
+                for (col, count) in counts.iter().enumerate() {
+                    let format = normal_format.clone().set_background_color(Color::RGB(heat_color(*count, max)));
+                    worksheet.write_number_with_format(row, (col + 1) as u16, *count as f64, &format).map_err(|e| e.to_string())?;
+                }
+                row += 1;
+            }
+            row += 1;
+        }
+        
+        // 公平性检验
+        let worksheet = workbook.add_worksheet().set_name(tr("公平性检验")).map_err(|e| e.to_string())?;
+        for (col, width) in [12.0, 10.0, 12.0, 10.0, 8.0, 10.0, 50.0].iter().enumerate() {
+            worksheet.set_column_width(col as u16, *width).map_err(|e| e.to_string())?;
+        }
+        worksheet.merge_range(0, 0, 0, 6, &title, &title_format).map_err(|e| e.to_string())?;
+        for (col, header) in ["专业", "记录数", "参与检验", "卡方值", "自由度", "p值", "结论"].iter().enumerate() {
+            worksheet.write_string_with_format(1, col as u16, tr(header), &header_format).map_err(|e| e.to_string())?;
+        }
+        for (idx, specialty) in stats.specialties.iter().enumerate() {
+            let row = (idx + 2) as u32;
+            let fairness = &specialty.fairness;
+            let values = [
+                specialty.specialty_name.clone(),
+                specialty.draws.to_string(),
+                fairness.draws.to_string(),
+                format!("{:.3}", fairness.chi_square),
+                fairness.degrees_of_freedom.to_string(),
+                fairness.p_value.map(|p| format!("{:.4}", p)).unwrap_or_default(),
+                fairness.conclusion(),
+            ];
+            for (col, value) in values.iter().enumerate() {
+                worksheet.write_string_with_format(row, col as u16, value, &normal_format).map_err(|e| e.to_string())?;
+            }
+        }
+        
+        // 季度趋势
+        let worksheet = workbook.add_worksheet().set_name(tr("季度趋势")).map_err(|e| e.to_string())?;
+        worksheet.set_column_width(0, 18).map_err(|e| e.to_string())?;
+        worksheet.write_string_with_format(0, 0, tr("季度"), &header_format).map_err(|e| e.to_string())?;
+        worksheet.write_string_with_format(0, 1, tr("合计"), &header_format).map_err(|e| e.to_string())?;
+        for (col, specialty) in stats.specialties.iter().enumerate() {
+            worksheet.set_column_width((col + 2) as u16, 12).map_err(|e| e.to_string())?;
+            worksheet.write_string_with_format(0, (col + 2) as u16, &specialty.specialty_name, &header_format).map_err(|e| e.to_string())?;
+        }
+        for (idx, quarter) in stats.quarters.iter().enumerate() {
+            let row = (idx + 1) as u32;
+            worksheet.write_string_with_format(row, 0, &quarter.label, &normal_format).map_err(|e| e.to_string())?;
+            worksheet.write_number_with_format(row, 1, quarter.draws as f64, &normal_format).map_err(|e| e.to_string())?;
+            for (col, count) in quarter.by_specialty.iter().enumerate() {
+                worksheet.write_number_with_format(row, (col + 2) as u16, *count as f64, &normal_format).map_err(|e| e.to_string())?;
+            }
+        }
+        
+        workbook.save(path).map_err(|e| e.to_string())?;
+        
+        Ok(())
+    }
+    
+    /// 导出检查日程到 iCalendar（.ics）文件，可导入 Outlook、手机日历等
+    pub fn export_schedule_to_ics(items: &[ScheduleItem], organization: &Organization, path: &Path) -> Result<(), String> {
+        std::fs::write(path, Self::generate_ics(items, organization)).map_err(|e| e.to_string())
+    }
+    
+    /// 生成 iCalendar 内容，每个已安排的检查一个事件（未安排的记录不导出）
+    pub fn generate_ics(items: &[ScheduleItem], organization: &Organization) -> String {
+        let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//quality_draw//质量监督检查抽签程序//ZH".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            format!("X-WR-CALNAME:{}", ics_escape(&trf("{}检查日程", &[&organization.name]))),
+        ];
+        
+        for item in items {
+            let Some(entry) = item.entry else {
+                continue;
+            };
+            let record = item.record;
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}@quality_draw", record.id));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            match entry.slot.hours() {
+                Some((start, end)) => {
+                    lines.push(format!("DTSTART:{}", entry.date.and_time(start).format("%Y%m%dT%H%M%S")));
+                    lines.push(format!("DTEND:{}", entry.date.and_time(end).format("%Y%m%dT%H%M%S")));
+                }
+                None => {
+                    let next_day = entry.date.succ_opt().unwrap_or(entry.date);
+                    lines.push(format!("DTSTART;VALUE=DATE:{}", entry.date.format("%Y%m%d")));
+                    lines.push(format!("DTEND;VALUE=DATE:{}", next_day.format("%Y%m%d")));
+                }
+            }
+            lines.push(format!("SUMMARY:{}", ics_escape(&trf(
+                "{}检查{}（{}）",
+                &[&record.selected_from_department_name, &record.target_department_name, &record.specialty_label()],
+            ))));
+            lines.push(format!("LOCATION:{}", ics_escape(&record.target_department_name)));
+            let mut description = trf("第{}轮质量监督检查抽签结果", &[&record.round]);
+            if !entry.note.is_empty() {
+                description.push('\n');
+                description.push_str(&entry.note);
+            }
+            lines.push(format!("DESCRIPTION:{}", ics_escape(&description)));
+            lines.push("END:VEVENT".to_string());
+        }
+        
+        lines.push("END:VCALENDAR".to_string());
+        lines.iter().map(|line| fold_ics_line(line)).collect::<Vec<_>>().concat()
+    }
+    
+    /// 记录状态说明（作废记录包含原因、操作人和时间）
+    fn status_text(record: &DrawRecord) -> String {
+        match &record.voided {
+            Some(info) => trf(
+                "已作废：{}（{} {}）",
+                &[&info.reason, &info.operator, &info.timestamp.format("%Y-%m-%d %H:%M")],
+            ),
+            None => tr("有效").to_string(),
+        }
+    }
+    
+    /// 生成打印内容（HTML格式），抬头使用单位的报表抬头和标志
+    pub fn generate_print_html(records: &[DrawRecord], organization: &Organization) -> String {
+        let logo = match organization.logo.as_deref().map(Path::new) {
+            Some(path) if path.exists() => {
+                let url = path.display().to_string().replace('\\', "/");
+                format!(r#"<img class="logo" src="file:///{}" alt="">"#, html_escape(url.trim_start_matches('/')))
+            }
+            _ => String::new(),
+        };
+        
+        let mut html = format!(r#"
+<!DOCTYPE html>
+<html lang="{}">
+<head>
+    <meta charset="UTF-8">
+    <title>{}</title>
+    <style>
+        body {{ font-family: "Microsoft YaHei", sans-serif; padding: 20px; }}
+        h1 {{ text-align: center; color: #333; }}
+        .logo {{ display: block; margin: 0 auto 10px; max-height: 80px; }}
+        table {{ width: 100%; border-collapse: collapse; margin-top: 20px; }}
+        th, td {{ border: 1px solid #ddd; padding: 10px; text-align: center; }}
+        th {{ background-color: #4472C4; color: white; }}
+        tr:nth-child(even) {{ background-color: #f9f9f9; }}
+        tr.voided td.data {{ text-decoration: line-through; color: #999; }}
+        td.status {{ color: #c00; font-size: 12px; }}
+        .footer {{ margin-top: 30px; text-align: right; color: #666; }}
+    </style>
+</head>
+<body>
+"#, locale().code(), tr("质量监督检查抽签结果"));
+        html.push_str(&format!("    {}\n    <h1>{}</h1>\n", logo, html_escape(&organization.report_title())));
+        html.push_str("    <table>\n        <tr>\n");
+        for header in ["序号", "抽签时间", "被检查部门", "专责类型", "抽中人员", "所属部门", "状态"] {
+            html.push_str(&format!("            <th>{}</th>\n", tr(header)));
+        }
+        html.push_str("        </tr>\n");
+        
+        for (idx, record) in records.iter().enumerate() {
+            html.push_str(&format!(
+                r#"        <tr class="{}">
+            <td>{}</td>
+            <td class="data">{}</td>
+            <td class="data">{}</td>
+            <td class="data">{}</td>
+            <td class="data">{}</td>
+            <td class="data">{}</td>
+            <td class="status">{}</td>
+        </tr>
+"#,
+                if record.is_voided() { "voided" } else { "" },
+                idx + 1,
+                record.timestamp.format("%Y-%m-%d %H:%M:%S"),
+                html_escape(&record.target_department_name),
+                html_escape(record.specialty_label()),
+                html_escape(&record.selected_specialist_name),
+                html_escape(&record.selected_from_department_name),
+                if record.is_voided() { html_escape(&Self::status_text(record)) } else { String::new() },
+            ));
+        }
+        
+        html.push_str(&format!(
+            r#"    </table>
+    <div class="footer">
+        <p>{}</p>
+    </div>
+</body>
+</html>"#,
+            trf("打印时间: {}", &[&chrono::Local::now().format("%Y-%m-%d %H:%M:%S")])
+        ));
+        
+        html
+    }
+    
+    /// 打印抽签记录
+    ///
+    /// Windows 下用默认浏览器打开打印预览；Linux/UOS 下优先将 HTML 转为 PDF
+    /// 后通过 CUPS 的 `lp` 命令直接提交打印机，无法转换时退回到 `xdg-open`
+    pub fn print_records(records: &[DrawRecord], organization: &Organization, options: &PrintOptions) -> Result<PrintOutcome, String> {
+        let html = Self::generate_print_html(records, organization);
+        
+        // 保存到临时文件
+        let temp_path = std::env::temp_dir().join("quality_draw_print.html");
+        std::fs::write(&temp_path, html).map_err(|e| e.to_string())?;
+        
+        // 使用默认浏览器打开
+        #[cfg(target_os = "windows")]
+        {
+            let _ = options;
+            std::process::Command::new("cmd")
+                .args(["/C", "start", ""])
+                .arg(&temp_path)
+                .spawn()
+                .map_err(|e| e.to_string())?;
+            Ok(PrintOutcome::OpenedInBrowser)
+        }
+        
+        #[cfg(not(target_os = "windows"))]
+        {
+            // 优先直接提交到 CUPS 打印队列
+            if command_exists("lp") {
+                let pdf_path = std::env::temp_dir().join("quality_draw_print.pdf");
+                if Self::html_to_pdf(&temp_path, &pdf_path).is_ok() {
+                    return Self::submit_to_cups(&pdf_path, options);
+                }
+            }
+            
+            // 退回到浏览器打印预览
+            let opener = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+            if command_exists(opener) {
+                std::process::Command::new(opener)
+                    .arg(&temp_path)
+                    .spawn()
+                    .map_err(|e| e.to_string())?;
+                return Ok(PrintOutcome::OpenedInBrowser);
+            }
+            
+            Err(tr("未找到可用的打印方式（需要安装 CUPS 的 lp 命令，或可用的 xdg-open）").to_string())
+        }
+    }
+    
+    /// 获取系统中可用的打印机列表
+    ///
+    /// 通过 `lpstat -e` 查询 CUPS 打印队列；Windows 下打印机在浏览器打印对话框中选择，返回空列表
+    pub fn list_printers() -> Vec<String> {
+        if cfg!(target_os = "windows") || !command_exists("lpstat") {
+            return Vec::new();
+        }
+        
+        std::process::Command::new("lpstat")
+            .arg("-e")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .map(|line| line.trim().to_string())
+                    .filter(|line| !line.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+    
+    /// 将 HTML 文件转换为 PDF
+    ///
+    /// 依次尝试 wkhtmltopdf 和 Chromium 系浏览器（含 Windows 自带的 Edge）的无头打印
+    pub fn html_to_pdf(html_path: &Path, pdf_path: &Path) -> Result<(), String> {
+        let _ = std::fs::remove_file(pdf_path);
+        
+        if let Some(wkhtmltopdf) = find_command("wkhtmltopdf") {
+            let status = std::process::Command::new(wkhtmltopdf)
+                .arg("--quiet")
+                .arg("--enable-local-file-access")
+                .arg("--encoding")
+                .arg("utf-8")
+                .arg(html_path)
+                .arg(pdf_path)
+                .status();
+            if matches!(status, Ok(s) if s.success()) && pdf_path.exists() {
+                return Ok(());
+            }
+        }
+        
+        for browser in pdf_browsers() {
+            let status = std::process::Command::new(&browser)
+                .args(["--headless", "--disable-gpu", "--no-pdf-header-footer"])
+                .arg(format!("--print-to-pdf={}", pdf_path.display()))
+                .arg(html_path)
+                .status();
+            if matches!(status, Ok(s) if s.success()) && pdf_path.exists() {
+                return Ok(());
+            }
+        }
+        
+        Err(tr("未找到可用的 PDF 转换工具（wkhtmltopdf 或 Chromium）").to_string())
+    }
+    
+    /// 通过 CUPS 的 lp 命令提交打印任务
+    #[cfg(not(target_os = "windows"))]
+    fn submit_to_cups(pdf_path: &Path, options: &PrintOptions) -> Result<PrintOutcome, String> {
+        let mut command = std::process::Command::new("lp");
+        if let Some(printer) = &options.printer {
+            command.arg("-d").arg(printer);
+        }
+        command
+            .arg("-n")
+            .arg(options.copies.max(1).to_string())
+            .arg("-t")
+            .arg(tr("质量监督检查抽签结果"))
+            .arg(pdf_path);
+        
+        let output = command.output().map_err(|e| trf("调用 lp 失败: {}", &[&e]))?;
+        if !output.status.success() {
+            return Err(trf(
+                "提交打印任务失败: {}",
+                &[&String::from_utf8_lossy(&output.stderr).trim()],
+            ));
+        }
+        
+        Ok(PrintOutcome::Submitted {
+            printer: options.printer.clone().unwrap_or_else(|| tr("默认打印机").to_string()),
+            copies: options.copies.max(1),
+        })
+    }
+}
+
+/// 打印选项
+#[derive(Debug, Clone)]
+pub struct PrintOptions {
+    /// 目标打印机（None 表示系统默认打印机）
+    pub printer: Option<String>,
+    /// 打印份数
+    pub copies: u32,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            printer: None,
+            copies: 1,
+        }
+    }
+}
+
+/// 打印结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrintOutcome {
+    /// 已提交到打印机队列
+    Submitted { printer: String, copies: u32 },
+    /// 已在浏览器中打开打印预览
+    OpenedInBrowser,
+}
+
+/// 检查命令是否存在于 PATH 中
+fn command_exists(name: &str) -> bool {
+    find_command(name).is_some()
+}
+
+/// 在 PATH 中查找命令，返回可执行文件的完整路径
+///
+/// Windows 下命令名不带扩展名，按 PATHEXT（默认 `.COM;.EXE;.BAT;.CMD`）逐个补全
+fn find_command(name: &str) -> Option<PathBuf> {
+    let paths = std::env::var_os("PATH")?;
+    let extensions: Vec<String> = if cfg!(target_os = "windows") {
+        std::env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string())
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| ext.to_ascii_lowercase())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    resolve_command(name, &paths, &extensions)
+}
+
+/// 在 `paths`（PATH 格式）的各目录中查找 `name`，依次尝试原名和补上各扩展名后的文件名
+fn resolve_command(name: &str, paths: &OsStr, extensions: &[String]) -> Option<PathBuf> {
+    std::env::split_paths(paths).find_map(|dir| {
+        std::iter::once(dir.join(name))
+            .chain(extensions.iter().map(|ext| dir.join(format!("{}{}", name, ext))))
+            .find(|candidate| candidate.is_file())
+    })
+}
+
+/// 可用于无头打印 PDF 的 Chromium 系浏览器
+///
+/// Windows 下 Edge 和 Chrome 通常不在 PATH 中，另外检查 Program Files 和用户目录下的默认安装位置
+fn pdf_browsers() -> Vec<PathBuf> {
+    let mut browsers: Vec<PathBuf> = [
+        "chromium",
+        "chromium-browser",
+        "google-chrome",
+        "google-chrome-stable",
+        "microsoft-edge",
+        "msedge",
+        "chrome",
+    ]
+    .iter()
+    .filter_map(|name| find_command(name))
+    .collect();
+    
+    if cfg!(target_os = "windows") {
+        for root in ["ProgramFiles(x86)", "ProgramFiles", "LOCALAPPDATA"].iter().filter_map(std::env::var_os) {
+            for relative in ["Microsoft/Edge/Application/msedge.exe", "Google/Chrome/Application/chrome.exe"] {
+                let candidate = Path::new(&root).join(relative);
+                if candidate.is_file() && !browsers.contains(&candidate) {
+                    browsers.push(candidate);
+                }
+            }
+        }
+    }
+    browsers
+}
+
+/// 内置 PDF 排版使用的字体（genpdf 只支持单个 TrueType 文件，不支持 .ttc 字体集）
+fn load_pdf_font() -> Result<Vec<u8>, String> {
+    let windows_fonts = Path::new(&std::env::var_os("WINDIR").unwrap_or_else(|| "C:/Windows".into())).join("Fonts");
+    let candidates: Vec<PathBuf> = ["simhei.ttf", "simfang.ttf", "simkai.ttf", "simsunb.ttf"]
+        .iter()
+        .map(|name| windows_fonts.join(name))
+        .chain(
+            [
+                "/usr/share/fonts/truetype/droid/DroidSansFallbackFull.ttf",
+                "/usr/share/fonts/truetype/arphic-gbsn00lp/gbsn00lp.ttf",
+                "/usr/share/fonts/fonts-cesi/CESI_HT_GB2312.TTF",
+                "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+            ]
+            .iter()
+            .map(PathBuf::from),
+        )
+        .collect();
+    
+    candidates
+        .iter()
+        .find_map(|path| std::fs::read(path).ok())
+        .ok_or_else(|| trf("无法找到合适的中文字体（{}）", &[&"simhei.ttf, simfang.ttf, simkai.ttf, DroidSansFallbackFull.ttf"]))
+}
+
+/// CSV 字段转义（含逗号、引号或换行时加引号）
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// iCalendar 文本转义（反斜杠、分号、逗号和换行）
+fn ics_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// iCalendar 行折叠：每行不超过 75 字节，续行以空格开头，行尾为 CRLF
+fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// HTML 文本转义
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// 热力图底色：0 次为白色，次数越多越接近深蓝色
+fn heat_color(count: usize, max: usize) -> u32 {
+    let ratio = count as f64 / max as f64;
+    let channel = |light: f64, dark: f64| (light + (dark - light) * ratio).round() as u32;
+    (channel(255.0, 0x44 as f64) << 16) | (channel(255.0, 0x72 as f64) << 8) | channel(255.0, 0xC4 as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::default_specialties;
+    
+    #[test]
+    fn test_resolve_command() {
+        let dir = std::env::temp_dir().join(format!("quality_draw_resolve_{}", std::process::id()));
+        let bin = dir.join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        std::fs::write(bin.join("msedge.exe"), "").unwrap();
+        std::fs::write(bin.join("lp"), "").unwrap();
+        let paths = std::env::join_paths([dir.join("missing"), bin.clone()]).unwrap();
+        let extensions = vec![".com".to_string(), ".exe".to_string()];
+        
+        // 不带扩展名的命令按 PATHEXT 补全
+        assert_eq!(resolve_command("msedge", &paths, &extensions), Some(bin.join("msedge.exe")));
+        assert_eq!(resolve_command("msedge.exe", &paths, &extensions), Some(bin.join("msedge.exe")));
+        assert_eq!(resolve_command("lp", &paths, &extensions), Some(bin.join("lp")));
+        // 没有扩展名列表时只匹配原名
+        assert_eq!(resolve_command("msedge", &paths, &[]), None);
+        assert_eq!(resolve_command("wkhtmltopdf", &paths, &extensions), None);
+        // 目录不是可执行文件
+        assert_eq!(resolve_command("bin", &std::env::join_paths([dir.clone()]).unwrap(), &extensions), None);
+        
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+    
+    #[test]
+    fn test_print_html_content() {
+        let specialties = default_specialties();
+        let mut organization = Organization::new("test", "测试单位");
+        organization.report_header = "<测试> 抽签结果".to_string();
+        let records = vec![
+            DrawRecord::test_record("宁东分院", &specialties[0], "石嘴山分院", 1),
+            DrawRecord::test_record("吴忠分院", &specialties[1], "A&B<script>", 1),
+        ];
+        
+        let html = ExportManager::generate_print_html(&records, &organization);
+        assert!(html.contains("<h1>&lt;测试&gt; 抽签结果</h1>"));
+        assert!(html.contains("<td class=\"data\">宁东分院</td>"));
+        assert!(html.contains("<td class=\"data\">石嘴山分院</td>"));
+        assert!(html.contains("A&amp;B&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert_eq!(html.matches("<tr class=\"\">").count(), 2);
+    }
+}
@@ -0,0 +1,500 @@
+//! 英文（en-US）消息目录
+
+/// 简体中文原文 → 英文译文
+pub(super) static MESSAGES: &[(&str, &str)] = &[
+    ("抽签时间", "Draw time"),
+    ("被检查部门", "Inspected department"),
+    ("专责类型", "Specialty"),
+    ("抽中人员", "Selected specialist"),
+    ("所属部门", "Department"),
+    ("状态", "Status"),
+    ("{}检查日程安排", "{} inspection schedule"),
+    ("未安排", "Not scheduled"),
+    ("；", "; "),
+    ("检查结果明细", "Inspection results"),
+    ("{}{}年度质量监督检查结果", "{} {} annual quality inspection results"),
+    ("汇总", "Summary"),
+    ("{}{}年度质量监督检查汇总", "{} {} annual quality inspection summary"),
+    ("{}抽签统计（有效记录 {} 条）", "{} draw statistics ({} valid records)"),
+    ("部门次数", "Department counts"),
+    ("配对热力图", "Pair heatmap"),
+    ("{}（行：被检部门，列：检查部门）", "{} (rows: inspected department, columns: inspecting department)"),
+    ("公平性检验", "Fairness test"),
+    ("季度趋势", "Quarterly trend"),
+    ("季度", "Quarter"),
+    ("合计", "Total"),
+    ("{}检查日程", "{} inspection schedule"),
+    ("{}检查{}（{}）", "{} inspects {} ({})"),
+    ("第{}轮质量监督检查抽签结果", "Round {} quality inspection draw results"),
+    ("已作废：{}（{} {}）", "Voided: {} ({} {})"),
+    ("有效", "Valid"),
+    ("质量监督检查抽签结果", "Quality inspection draw results"),
+    ("打印时间: {}", "Printed at: {}"),
+    ("未找到可用的打印方式（需要安装 CUPS 的 lp 命令，或可用的 xdg-open）", "No printing method available (install the CUPS lp command or xdg-open)"),
+    ("未找到可用的 PDF 转换工具（wkhtmltopdf 或 Chromium）", "No PDF converter available (wkhtmltopdf or Chromium)"),
+    ("调用 lp 失败: {}", "Failed to run lp: {}"),
+    ("提交打印任务失败: {}", "Failed to submit print job: {}"),
+    ("默认打印机", "default printer"),
+    ("请填写登录名", "Please enter a login name"),
+    ("请填写姓名", "Please enter a name"),
+    ("登录名 {} 已存在", "Login name {} already exists"),
+    ("登录名或密码错误", "Incorrect login name or password"),
+    ("见证人不能是执行抽签的操作员本人", "The witness cannot be the operator who performed the draw"),
+    ("密码至少{}位", "Password must be at least {} characters"),
+    ("请填写专责姓名", "Please enter the specialist's name"),
+    ("请选择专责所属部门", "Please select the specialist's department"),
+    ("请选择专责的专业", "Please select the specialist's specialty"),
+    ("重复添加：{} 在该部门的{}专业已存在", "Duplicate: {} already exists in this department for {}"),
+    ("请填写部门名称", "Please enter a department name"),
+    ("部门 {} 已存在", "Department {} already exists"),
+    ("未知的专业 {}", "Unknown specialty {}"),
+    ("{}的检查组部门数 {} 超过上限 {}", "{}: team size {} exceeds the limit {}"),
+    ("部门不需要抽取{}，不能设置该专业的检查组部门数", "The department does not require {}, so its team size cannot be set"),
+    ("请填写专业名称", "Please enter a specialty name"),
+    ("专业 {} 已存在", "Specialty {} already exists"),
+    ("未找到部门 {}", "Department {} not found"),
+    ("{}不需要抽取{}", "{} does not require {}"),
+    ("{}本轮已抽取{}", "{} has already drawn {} this round"),
+    ("{}没有符合条件的{}候选部门", "{} has no eligible {} candidate departments"),
+    ("{}需要抽取{}个{}检查部门，符合条件的候选部门只有{}个", "{} needs {} {} inspecting departments, but only {} candidates are eligible"),
+    ("{} {}同一天安排检查{}", "{} {} has inspections on the same day: {}"),
+    ("、", ", "),
+    ("样本不足，无法检验", "Not enough samples to test"),
+    ("与等概率期望无显著差异", "No significant deviation from equal probability"),
+    ("与等概率期望存在显著差异（p < {}）", "Significant deviation from equal probability (p < {})"),
+    ("，部分部门期望次数小于 5，仅供参考", "; some expected counts are below 5, for reference only"),
+    ("{}年第{}季度", "{} Q{}"),
+    ("登录", "Log in"),
+    ("注销", "Log out"),
+    ("添加专责", "Add specialist"),
+    ("删除专责", "Delete specialist"),
+    ("修改专责", "Edit specialist"),
+    ("添加部门", "Add department"),
+    ("删除部门", "Delete department"),
+    ("修改部门专业", "Edit department specialties"),
+    ("添加专业", "Add specialty"),
+    ("删除专业", "Delete specialty"),
+    ("新增单位", "Add organization"),
+    ("修改单位信息", "Edit organization"),
+    ("作废记录", "Void record"),
+    ("归档轮次", "Archive round"),
+    ("添加账户", "Add account"),
+    ("删除账户", "Delete account"),
+    ("修改角色", "Change role"),
+    ("重设密码", "Reset password"),
+    ("见证确认", "Witness confirmation"),
+    ("取消抽签结果", "Cancel draw result"),
+    ("撤销抽签", "Undo draw"),
+    ("安排检查日程", "Schedule inspection"),
+    ("登记检查结果", "Record inspection result"),
+    ("管理员", "Administrator"),
+    ("抽签员", "Drawer"),
+    ("观察员", "Observer"),
+    ("{}（{}）", "{} ({})"),
+    ("{}质量监督检查抽签程序", "{} Quality Inspection Draw"),
+    ("{}质量监督检查抽签结果", "{} Quality Inspection Draw Results"),
+    ("计划中", "Planned"),
+    ("检查中", "In progress"),
+    ("已完成", "Completed"),
+    ("评分应在 0~100 之间", "Score must be between 0 and 100"),
+    ("已完成的检查需要填写结案日期", "Completed inspections require a closing date"),
+    ("未完成的检查不能填写结案日期", "Unfinished inspections cannot have a closing date"),
+    ("上午", "Morning"),
+    ("下午", "Afternoon"),
+    ("全天", "All day"),
+    ("单位ID只能包含字母、数字、- 和 _", "Organization ID may only contain letters, digits, - and _"),
+    ("请输入单位名称", "Please enter an organization name"),
+    ("单位ID {} 已存在", "Organization ID {} already exists"),
+    ("单位 {} 已存在", "Organization {} already exists"),
+    ("请填写作废原因", "Please enter a reason for voiding"),
+    ("未找到该记录", "Record not found"),
+    ("该记录已作废", "The record has already been voided"),
+    ("没有可导出的记录", "No records to export"),
+    ("抽签结果_{}.xlsx", "draw_results_{}.xlsx"),
+    ("已导出到: {}", "Exported to: {}"),
+    ("导出失败: {}", "Export failed: {}"),
+    ("{} 已确认抽签结果", "{} confirmed the draw result"),
+    ("没有可撤销的抽签", "No draw to undo"),
+    ("撤销失败: {}", "Undo failed: {}"),
+    ("已撤销：{}", "Undone: {}"),
+    ("没有可打印的记录", "No records to print"),
+    ("已提交到 {} 打印 {} 份", "Sent to {} ({} copies)"),
+    ("已在浏览器中打开打印预览", "Opened print preview in the browser"),
+    ("打印失败: {}", "Printing failed: {}"),
+    ("当前单位: {}", "Organization: {}"),
+    ("🏛 切换单位", "🏛 Switch organization"),
+    ("欢迎，{}", "Welcome, {}"),
+    ("当前没有待确认的结果", "No result is awaiting confirmation"),
+    ("🔓 注销", "🔓 Log out"),
+    ("操作员: {}", "Operator: {}"),
+    ("专责: {} 人", "Specialists: {}"),
+    ("📜 查看历史", "📜 History"),
+    ("⚙ 数据管理", "⚙ Data management"),
+    ("🧾 审计日志", "🧾 Audit log"),
+    ("📅 检查日程", "📅 Schedule"),
+    ("📋 检查结果", "📋 Results"),
+    ("📈 统计", "📈 Statistics"),
+    ("🖥 关闭投影", "🖥 Close projection"),
+    ("🖥 投影模式", "🖥 Projection mode"),
+    ("📡 投屏中", "📡 Casting"),
+    ("📡 投屏", "📡 Cast"),
+    ("📊 导出Excel", "📊 Export Excel"),
+    ("🖨 打印", "🖨 Print"),
+    ("本轮已抽: {}", "Drawn this round: {}"),
+    ("↩ 撤销上一次", "↩ Undo last"),
+    ("撤销本轮最近一次抽签（抽签员只能撤销自己的抽签）", "Undo the latest draw of this round (drawers can only undo their own draws)"),
+    ("🔄 开始新一轮", "🔄 New round"),
+    ("已开始第{}轮抽签", "Started round {}"),
+    ("观察员账户只能查看，不能执行抽签", "Observer accounts are read-only and cannot draw"),
+    ("已取消未确认的结果（{}），可重新抽取", "Cancelled the unconfirmed result ({}); you can draw again"),
+    ("已作废 {} 的{}抽签结果", "Voided the draw result of {} for {}"),
+    ("正在重新抽取{}", "Redrawing {}"),
+    ("投屏服务已启动: {}", "Casting service started: {}"),
+    ("投屏服务已停止", "Casting service stopped"),
+    ("🔍 搜索:", "🔍 Search:"),
+    ("所有操作", "All actions"),
+    ("🔄 刷新", "🔄 Refresh"),
+    ("显示 {} / 共 {} 条", "Showing {} of {}"),
+    ("时间", "Time"),
+    ("操作员", "Operator"),
+    ("操作", "Action"),
+    ("操作对象", "Target"),
+    ("修改前", "Before"),
+    ("修改后", "After"),
+    ("审计日志_{}.xlsx", "audit_log_{}.xlsx"),
+    ("📜 抽签历史记录", "📜 Draw history"),
+    ("共 {} 条记录", "{} records"),
+    ("显示已归档", "Show archived"),
+    ("暂无抽签记录", "No draw records yet"),
+    ("筛选结果 {} / 共 {} 条", "{} of {} records match"),
+    ("轮次", "Round"),
+    ("被检部门", "Inspected"),
+    ("专业", "Specialty"),
+    ("🔍 详情", "🔍 Details"),
+    ("◀ 上一页", "◀ Previous"),
+    ("第 {} / {} 页", "Page {} / {}"),
+    ("下一页 ▶", "Next ▶"),
+    ("取消", "Cancel"),
+    ("确认归档", "Confirm archive"),
+    ("{}（{}条记录）", "{} ({} records)"),
+    ("归档{}后，这些记录将不再出现在列表和导出中", "After archiving {}, these records will no longer appear in lists or exports"),
+    ("📦 归档", "📦 Archive"),
+    ("选择轮次", "Select round"),
+    ("日期:", "Date:"),
+    ("起始 YYYY-MM-DD", "From YYYY-MM-DD"),
+    ("至", "to"),
+    ("截止 YYYY-MM-DD", "To YYYY-MM-DD"),
+    ("日期格式应为 YYYY-MM-DD", "Dates must be in YYYY-MM-DD format"),
+    ("轮次:", "Round:"),
+    ("所有轮次", "All rounds"),
+    ("专业:", "Specialty:"),
+    ("所有专业", "All specialties"),
+    ("被检部门:", "Inspected:"),
+    ("检查部门:", "Inspecting:"),
+    ("搜索部门、规则说明", "Search departments or rule notes"),
+    ("❌ 重置", "❌ Reset"),
+    ("所有部门", "All departments"),
+    ("🔍 记录详情", "🔍 Record details"),
+    ("记录ID:", "Record ID:"),
+    ("抽签时间:", "Draw time:"),
+    ("专责类型:", "Specialty:"),
+    ("抽中结果:", "Selected:"),
+    ("检查组:", "Team:"),
+    ("本次抽取{}个部门中的第{}个", "{} departments in this draw, this is no. {}"),
+    ("随机种子:", "Random seed:"),
+    ("操作员:", "Operator:"),
+    ("见证人:", "Witness:"),
+    ("，局域网 {}", ", LAN {}"),
+    ("状态:", "Status:"),
+    ("已归档", "Archived"),
+    ("候选名单", "Candidates"),
+    ("（该记录生成于旧版本，未保存候选名单）", "(Created by an older version; no candidate list was saved)"),
+    ("规则说明", "Rule notes"),
+    ("（该记录生成于旧版本，未保存规则说明）", "(Created by an older version; no rule notes were saved)"),
+    ("无被排除的部门", "No departments were excluded"),
+    ("🔁 重新抽取", "🔁 Redraw"),
+    ("作废该记录", "Void this record"),
+    ("原因:", "Reason:"),
+    ("必填", "Required"),
+    ("操作人: {}", "By: {}"),
+    ("⛔ 作废", "⛔ Void"),
+    ("旧记录", "Legacy record"),
+    ("第{}轮", "Round {}"),
+    ("首次使用，请创建管理员账户", "First use: please create an administrator account"),
+    ("请登录", "Please log in"),
+    ("登录名:", "Login name:"),
+    ("姓名:", "Name:"),
+    ("密码:", "Password:"),
+    ("确认密码:", "Confirm password:"),
+    ("创建并登录", "Create and log in"),
+    ("🔑 登录", "🔑 Log in"),
+    ("两次输入的密码不一致", "The passwords do not match"),
+    ("请选择被检查部门，然后点击开始抽签", "Select the inspected department, then click Start draw"),
+    ("减速中...", "Slowing down..."),
+    ("抽签完成，等待见证人确认", "Draw complete, awaiting witness confirmation"),
+    ("抽签完成！", "Draw complete!"),
+    ("已取消未确认的结果，可重新抽取", "Cancelled the unconfirmed result; you can draw again"),
+    ("选择被检查部门", "Select inspected department"),
+    ("请先完成见证确认", "Please complete the witness confirmation first"),
+    ("该部门未设置需要抽取的专业，请在数据管理中设置", "No required specialties are set for this department; set them in Data management"),
+    ("请选择被检查部门", "Please select the inspected department"),
+    ("{}抽选", "{} draw"),
+    ("{}抽选（{}个部门）", "{} draw ({} departments)"),
+    ("🎉 中签 🎉", "🎉 Selected 🎉"),
+    ("准备就绪", "Ready"),
+    ("⚡ 正在选定...", "⚡ Selecting..."),
+    ("🎲 开始抽签", "🎲 Start draw"),
+    ("⏹ 停止", "⏹ Stop"),
+    ("请先选择被检查部门", "Please select the inspected department first"),
+    ("该部门未设置需要抽取的专业", "No required specialties are set for this department"),
+    ("未找到被检查部门", "Inspected department not found"),
+    ("该部门本轮已抽过，请点击'开始新一轮'重新开始", "This department has already been drawn this round; click 'New round' to start over"),
+    ("没有可抽取的部门！", "No departments available to draw!"),
+    ("正在抽取{}部门...", "Drawing {} departments..."),
+    ("正在抽取部门...", "Drawing departments..."),
+    ("{}需要抽取{}个部门，符合条件的候选部门只有{}个", "{} needs {} departments, but only {} candidates are eligible"),
+    ("正在抽签或等待见证确认，请稍后再重新抽取", "A draw is in progress or awaiting witness confirmation; please redraw later"),
+    ("未知", "Unknown"),
+    ("📋 {} 抽签结果", "📋 {} draw results"),
+    ("{}质量专责部门：", "{} inspecting departments:"),
+    ("未分类", "Uncategorized"),
+    ("质量监督检查抽签程序", "Quality Inspection Draw"),
+    ("请选择单位", "Please select an organization"),
+    ("评分应为数字", "Score must be a number"),
+    ("📋 检查结果跟踪", "📋 Inspection result tracking"),
+    ("未完成检查 {} 项", "{} open inspections"),
+    ("（计划中 {} / 检查中 {}）", "(planned {} / in progress {})"),
+    ("显示全年检查", "Show whole year"),
+    ("年度:", "Year:"),
+    ("{}年", "{}"),
+    ("📊 导出年度报告", "📊 Export annual report"),
+    ("该年度没有有效的抽签记录", "No valid draw records for this year"),
+    ("没有未完成的检查", "No open inspections"),
+    ("检查部门", "Inspecting department"),
+    ("检查日期", "Inspection date"),
+    ("问题（严重/一般/轻微）", "Issues (major/moderate/minor)"),
+    ("评分", "Score"),
+    ("结案日期", "Closing date"),
+    ("登记检查结果：第{}轮 {} {} → {}", "Record result: round {} {} {} → {}"),
+    ("✏ 登记", "✏ Record"),
+    ("问题数:", "Issues:"),
+    ("严重", "Major"),
+    ("一般", "Moderate"),
+    ("轻微", "Minor"),
+    ("评分:", "Score:"),
+    ("0~100，可留空", "0-100, optional"),
+    ("检查报告:", "Report:"),
+    ("报告文件路径", "Report file path"),
+    ("结案日期:", "Closing date:"),
+    ("已完成时填写，如 2026-11-20", "When completed, e.g. 2026-11-20"),
+    ("💾 保存", "💾 Save"),
+    ("第{}轮 {} {} → {}：{}", "Round {} {} {} → {}: {}"),
+    ("已保存检查结果", "Inspection result saved"),
+    ("年度质量报告_{}_{}.xlsx", "annual_quality_report_{}_{}.xlsx"),
+    ("质量监督检查抽签 - 投影", "Quality Inspection Draw - Projection"),
+    ("🖥 投影", "🖥 Projection"),
+    ("{}质量监督检查抽签", "{} Quality Inspection Draw"),
+    ("等待选择被检查部门", "Waiting for the inspected department"),
+    ("第 {} 轮　被检查部门：{}", "Round {}　Inspected department: {}"),
+    ("第 {} 轮抽签结果", "Round {} draw results"),
+    ("打印机:", "Printer:"),
+    ("在浏览器打印对话框中选择", "Choose in the browser print dialog"),
+    ("份数:", "Copies:"),
+    ("📅 检查日程安排", "📅 Inspection schedule"),
+    ("已安排 {} / 共 {} 项", "{} of {} scheduled"),
+    ("📅 导出日历 (.ics)", "📅 Export calendar (.ics)"),
+    ("📊 导出安排表", "📊 Export schedule"),
+    ("⚠ 冲突：{}", "⚠ Conflict: {}"),
+    ("该轮没有有效的抽签记录", "No valid draw records in this round"),
+    ("时段", "Time slot"),
+    ("备注", "Notes"),
+    ("取消安排：{}", "Unscheduled: {}"),
+    ("已取消安排", "Schedule cleared"),
+    ("带队人员、集合地点等", "Team leader, meeting point, etc."),
+    ("保存", "Save"),
+    ("✏ 安排", "✏ Schedule"),
+    ("取消安排", "Clear"),
+    ("已保存安排", "Schedule saved"),
+    ("已保存，但存在冲突：{}", "Saved, but with conflicts: {}"),
+    ("第{}轮 {} {} → {}", "Round {} {} {} → {}"),
+    ("检查日程_第{}轮_{}.{}", "inspection_schedule_round{}_{}.{}"),
+    ("📡 投屏服务", "📡 Casting service"),
+    ("在投影仪或观察员电脑的浏览器中打开查看地址，即可实时观看转盘（只读）。", "Open the viewer address in a browser on the projector or observer computer to watch the wheels live (read-only)."),
+    ("● 运行中（{}）", "● Running ({})"),
+    ("📋 复制", "📋 Copy"),
+    ("已连接查看器: {}", "Connected viewers: {}"),
+    ("⏹ 停止服务", "⏹ Stop service"),
+    ("端口:", "Port:"),
+    ("访问范围:", "Access:"),
+    ("允许局域网访问", "Allow LAN access"),
+    ("▶ 启动服务", "▶ Start service"),
+    ("👤 质量专责管理", "👤 Specialists"),
+    ("🏢 部门管理", "🏢 Departments"),
+    ("🧩 专业管理", "🧩 Specialties"),
+    ("🔑 账户管理", "🔑 Accounts"),
+    ("🏛 单位信息", "🏛 Organization"),
+    ("添加新专责", "Add specialist"),
+    ("部门:", "Department:"),
+    ("请选择", "Select"),
+    ("➕ 添加", "➕ Add"),
+    ("筛选:", "Filter:"),
+    ("专责列表 (显示 {} / 共 {} 人)", "Specialists (showing {} of {})"),
+    ("🗑 删除", "🗑 Delete"),
+    ("添加新部门", "Add department"),
+    ("名称:", "Name:"),
+    ("分类:", "Category:"),
+    ("如 综合类", "e.g. General"),
+    ("被检查时按所需专业抽取，只有能派出该专业的部门参与抽签", "When inspected, each required specialty is drawn from the departments that can provide it"),
+    ("部门列表 (共{}个)", "Departments ({})"),
+    ("{}  |  {}  |  需要: {}  |  派出: {}", "{}  |  {}  |  Requires: {}  |  Provides: {}"),
+    ("✏ 专业", "✏ Specialties"),
+    ("需要抽取:", "Requires:"),
+    ("检查组部门数:", "Team size:"),
+    ("可以派出:", "Provides:"),
+    ("确定", "OK"),
+    ("当前单位", "Current organization"),
+    ("单位ID:", "Organization ID:"),
+    ("单位名称:", "Organization name:"),
+    ("标志图片:", "Logo image:"),
+    ("图片文件路径（可选）", "Image file path (optional)"),
+    ("报表抬头:", "Report header:"),
+    ("抽签规则:", "Draw rules:"),
+    ("本轮已被抽中的部门不再参与抽取", "Departments already selected this round are excluded"),
+    ("交叉回避（不能反过来检查本轮检查过自己的部门）", "Cross avoidance (cannot inspect a department that inspected it this round)"),
+    ("⚠ 请填写单位名称", "⚠ Please enter an organization name"),
+    ("单位信息已保存", "Organization saved"),
+    ("每个单位的部门、专业、专责、账户和抽签记录相互独立，启动时选择进入的单位", "Each organization has its own departments, specialties, specialists, accounts and records; choose one at startup"),
+    ("如 yc", "e.g. yc"),
+    ("添加新专业", "Add specialty"),
+    ("已添加专业 {}，请在部门管理中设置需要和派出该专业的部门", "Added specialty {}; set which departments require and provide it under Departments"),
+    ("专业列表 (共{}个)", "Specialties ({})"),
+    ("{}  |  需要的部门 {}  |  派出的部门 {}  |  专责 {} 人", "{}  |  Required by {}  |  Provided by {}  |  Specialists {}"),
+    ("⚠ 专业 {} 仍被部门或专责使用，请先在部门管理和专责管理中移除", "⚠ Specialty {} is still used by departments or specialists; remove it there first"),
+    ("无", "None"),
+    ("添加新账户", "Add account"),
+    ("已添加账户 {}", "Added account {}"),
+    ("账户列表 (共{}个)", "Accounts ({})"),
+    ("（当前登录）", "(current)"),
+    ("🔑 重设密码", "🔑 Reset password"),
+    ("新密码:", "New password:"),
+    ("⚠ 至少需要保留一个管理员", "⚠ At least one administrator is required"),
+    ("已重设 {} 的密码", "Reset the password of {}"),
+    ("📈 抽签统计", "📈 Draw statistics"),
+    ("有效记录 {} 条", "{} valid records"),
+    ("暂无有效的抽签记录", "No valid draw records yet"),
+    ("部门", "Department"),
+    ("被检次数", "Times inspected"),
+    ("检查次数", "Times inspecting"),
+    ("期望检查次数", "Expected inspecting"),
+    ("行：被检部门　列：检查部门", "Rows: inspected department　Columns: inspecting department"),
+    ("{} 次", "{} times"),
+    ("每次抽取在候选名单内等概率，部门的期望次数为其参与的每次抽取的 1/候选数 之和；没有候选名单的旧记录不参与检验。", "Each draw is uniform over its candidates, so a department's expected count is the sum of 1/candidates over the draws it took part in; legacy records without candidate lists are excluded."),
+    ("记录数", "Records"),
+    ("参与检验", "Tested"),
+    ("卡方值", "Chi-square"),
+    ("自由度", "DoF"),
+    ("p值", "p-value"),
+    ("结论", "Conclusion"),
+    ("抽签统计_{}.xlsx", "draw_statistics_{}.xlsx"),
+    ("选错被检查部门", "Wrong inspected department"),
+    ("↩ 撤销上一次抽签", "↩ Undo last draw"),
+    ("以下结果将被作废，被检部门可重新抽取：", "The following results will be voided and the department can draw again:"),
+    ("第{}轮  {} {} → {}", "Round {}  {} {} → {}"),
+    ("撤销原因:", "Reason:"),
+    ("↩ 撤销", "↩ Undo"),
+    ("请填写撤销原因", "Please enter a reason for undoing"),
+    ("✍ 见证确认", "✍ Witness confirmation"),
+    ("以下抽签结果需见证人确认后才会保存：", "The following results are saved only after a witness confirms:"),
+    ("也可在局域网查看器页面上确认", "You can also confirm on the LAN viewer page"),
+    ("没有其他账户可作为见证人，请管理员在账户管理中添加（如观察员账户）", "No other account can act as witness; ask an administrator to add one (e.g. an observer)"),
+    ("见证人登录名:", "Witness login:"),
+    ("✔ 见证人确认", "✔ Witness confirm"),
+    ("取消原因:", "Cancel reason:"),
+    ("✖ 取消结果", "✖ Cancel result"),
+    ("请填写取消原因", "Please enter a reason for cancelling"),
+    ("抽中部门", "Selected department"),
+    ("随机种子", "Random seed"),
+    ("操作时间", "Time"),
+    ("冲突", "Conflicts"),
+    ("严重问题", "Major issues"),
+    ("一般问题", "Moderate issues"),
+    ("轻微问题", "Minor issues"),
+    ("检查报告", "Report"),
+    ("检查项数", "Inspections"),
+    ("问题合计", "Total issues"),
+    ("平均评分", "Average score"),
+    ("序号", "No."),
+    ("承压类", "Pressure equipment"),
+    ("机电类", "Electromechanical"),
+    ("综合类", "General"),
+    ("开始抽签", "Start draw"),
+    ("深色", "Dark"),
+    ("浅色", "Light"),
+    ("高对比度", "High contrast"),
+    ("投影大字体", "Large projector font"),
+    ("快捷键：空格开始/停止，↑↓ 切换被检查部门", "Shortcuts: Space to start/stop, ↑↓ to change the inspected department"),
+    ("快捷键：空格", "Shortcut: Space"),
+    ("标准", "Standard"),
+    ("快速", "Quick"),
+    ("仪式", "Ceremony"),
+    ("匀减速", "Linear"),
+    ("平滑", "Smooth"),
+    ("悬念", "Suspense"),
+    ("🎞 动画设置", "🎞 Animation settings"),
+    ("🎞 动画", "🎞 Animation"),
+    ("动画方案:", "Profile:"),
+    ("减速曲线:", "Easing:"),
+    ("自动停止:", "Auto stop:"),
+    ("不需要点击停止", "No need to click Stop"),
+    ("转动时间:", "Spin time:"),
+    (" 秒", " s"),
+    ("提示音:", "Sound:"),
+    ("开始、减速和出结果时播放", "Play on start, slowdown and result"),
+    ("🔊 试听", "🔊 Preview"),
+    ("动画只影响展示，中签结果仍由抽签引擎按随机种子决定", "Animation only affects the display; results are still decided by the draw engine from the random seed"),
+    ("🎬 回放转盘", "🎬 Replay wheel"),
+    ("🎬 转盘回放", "🎬 Wheel replay"),
+    ("✅ 回放与随机种子重算结果一致", "✅ Replay matches the result recomputed from the random seed"),
+    ("⚠ 回放与随机种子重算结果不一致", "⚠ Replay does not match the result recomputed from the random seed"),
+    ("（该记录缺少随机种子或候选名单，无法复核）", "(This record has no random seed or candidate list and cannot be verified)"),
+    ("🔁 重新播放", "🔁 Play again"),
+    ("错误", "Error"),
+    ("警告", "Warning"),
+    ("信息", "Info"),
+    ("调试", "Debug"),
+    ("读取日志文件失败: {}", "Failed to read log file: {}"),
+    ("导出日志失败: {}", "Failed to export logs: {}"),
+    ("📝 运行日志", "📝 Logs"),
+    ("所有级别", "All levels"),
+    ("📤 导出日志", "📤 Export logs"),
+    ("日志文件: {}", "Log file: {}"),
+    ("级别", "Level"),
+    ("模块", "Module"),
+    ("内容", "Message"),
+    ("未启用运行日志", "Logging is not enabled"),
+    ("运行日志_{}.log", "logs_{}.log"),
+    ("⚠ 部门 {} 仍有专责或本轮抽签结果，请先移除其专责或开始新一轮", "⚠ Department {} still has specialists or results in the current round; remove its specialists or start a new round first"),
+    ("无法读取账户文件 {}: {}", "Cannot read the account file {}: {}"),
+    ("账户文件 {} 已损坏: {}", "The account file {} is corrupted: {}"),
+    ("⚠ 账户或抽签记录文件无法读取，已禁止登录。请从备份恢复该文件后重新启动程序。", "⚠ The account or draw record file cannot be read, so login is disabled. Restore that file from a backup and restart the program."),
+    ("新单位的首个账户必须是管理员", "The first account of a new organization must be an administrator"),
+    ("新单位的管理员账户在此一并创建，其他人不能在登录界面为新单位创建管理员", "The new organization's administrator account is created here; nobody can create it from the login screen"),
+    ("管理员登录名:", "Admin username:"),
+    ("管理员姓名:", "Admin name:"),
+    ("管理员密码:", "Admin password:"),
+    ("已新增单位 {}，注销后可选择进入并用其管理员账户登录", "Added organization {}; log out to select it and sign in with its administrator account"),
+    ("⚠ 该单位还没有任何账户，不能在登录界面创建管理员。请从备份恢复该单位的账户文件。", "⚠ This organization has no accounts and an administrator cannot be created from the login screen. Restore its account file from a backup."),
+    ("正在打印…", "Printing…"),
+    ("打印任务意外中止", "The print job stopped unexpectedly"),
+    ("解析字体数据失败: {}", "Failed to parse font data: {}"),
+    ("生成 PDF 失败: {}", "Failed to generate PDF: {}"),
+    ("导出时间: {}", "Exported: {}"),
+    ("{}（已作废）", "{} (voided)"),
+    ("共计 {} 条抽签记录", "{} draw records in total"),
+    ("第{}条{}", "Record {}: {}"),
+    ("无法找到合适的中文字体（{}）", "No suitable Chinese font found ({})"),
+    ("无法读取抽签记录文件 {}: {}", "Cannot read the draw record file {}: {}"),
+    ("抽签记录文件 {} 已损坏: {}", "The draw record file {} is corrupt: {}"),
+    ("保存抽签记录失败 {}: {}", "Failed to save draw records to {}: {}"),
+    ("保存失败，结果仍待确认: {}", "Saving failed; the result is still awaiting confirmation: {}"),
+];
@@ -0,0 +1,140 @@
+//! 界面和报表文字的多语言支持
+//!
+//! 以简体中文原文作为消息键：`tr("开始抽签")` 在英文界面下返回目录中的译文，
+//! 目录未收录的文本（部门名称等用户数据）原样返回。带参数的文本用 `{}` 占位，
+//! 由 `trf` 按顺序填入参数。当前语言是进程内全局设置，切换后新生成的界面文字、
+//! Excel 表头和打印/PDF 内容都使用新的语言。
+
+mod en_us;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use serde::{Deserialize, Serialize};
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+    /// 简体中文（默认）
+    #[default]
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    /// 英语
+    #[serde(rename = "en-US")]
+    EnUs,
+}
+
+impl Locale {
+    /// 所有语言
+    pub fn all() -> [Locale; 2] {
+        [Locale::ZhCn, Locale::EnUs]
+    }
+
+    /// 语言代码，如"zh-CN"
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::EnUs => "en-US",
+        }
+    }
+
+    /// 按语言代码查找，不区分大小写，也接受"zh"、"en"等简写
+    pub fn from_code(code: &str) -> Option<Locale> {
+        match code.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "zh" | "zh-cn" | "zh-hans" => Some(Locale::ZhCn),
+            "en" | "en-us" => Some(Locale::EnUs),
+            _ => None,
+        }
+    }
+
+    /// 语言名称（始终以该语言本身显示，便于在任意界面语言下找到）
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Locale::ZhCn => "简体中文",
+            Locale::EnUs => "English",
+        }
+    }
+
+    /// 翻译文本，目录未收录时返回原文
+    pub fn translate<'a>(&self, text: &'a str) -> &'a str {
+        match self {
+            Locale::ZhCn => text,
+            Locale::EnUs => en_us_messages().get(text).copied().unwrap_or(text),
+        }
+    }
+}
+
+/// 当前语言
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// 当前界面语言
+pub fn locale() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Locale::EnUs,
+        _ => Locale::ZhCn,
+    }
+}
+
+/// 切换界面语言
+pub fn set_locale(locale: Locale) {
+    let value = match locale {
+        Locale::ZhCn => 0,
+        Locale::EnUs => 1,
+    };
+    CURRENT.store(value, Ordering::Relaxed);
+}
+
+/// 按当前语言翻译文本
+pub fn tr(text: &str) -> &str {
+    locale().translate(text)
+}
+
+/// 按当前语言翻译带 `{}` 占位符的文本，并按顺序填入参数
+pub fn trf(template: &str, args: &[&dyn Display]) -> String {
+    fill(tr(template), args)
+}
+
+/// 依次用参数替换 `{}` 占位符，多余的占位符保持原样
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(position) = rest.find("{}") {
+        text.push_str(&rest[..position]);
+        match args.next() {
+            Some(arg) => text.push_str(&arg.to_string()),
+            None => text.push_str("{}"),
+        }
+        rest = &rest[position + 2..];
+    }
+    text.push_str(rest);
+    text
+}
+
+/// 英文消息目录（原文 → 译文）
+fn en_us_messages() -> &'static HashMap<&'static str, &'static str> {
+    static MESSAGES: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    MESSAGES.get_or_init(|| en_us::MESSAGES.iter().copied().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalogue() {
+        let mut seen = std::collections::HashSet::new();
+        for (source, translated) in en_us::MESSAGES {
+            assert!(seen.insert(source), "重复的消息键: {}", source);
+            assert_eq!(source.matches("{}").count(), translated.matches("{}").count(), "占位符数量不一致: {}", source);
+        }
+
+        assert_eq!(Locale::EnUs.translate("开始抽签"), "Start draw");
+        assert_eq!(Locale::ZhCn.translate("开始抽签"), "开始抽签");
+        assert_eq!(Locale::EnUs.translate("宁东分院"), "宁东分院");
+        assert_eq!(fill(Locale::EnUs.translate("已导出到: {}"), &[&"a.xlsx"]), "Exported to: a.xlsx");
+        assert_eq!(Locale::from_code("EN_us"), Some(Locale::EnUs));
+        assert_eq!(serde_json::to_string(&Locale::ZhCn).unwrap(), "\"zh-CN\"");
+    }
+}
@@ -0,0 +1,10 @@
+//! 宁夏特检院质量监督检查抽签程序 - 核心库
+//! 
+//! 桌面版（egui，含命令行）和 Tauri 版共用的数据模型、数据存储、抽签逻辑、导出功能和运行日志。
+
+pub mod models;
+pub mod storage;
+pub mod logic;
+pub mod export;
+pub mod i18n;
+pub mod logging;
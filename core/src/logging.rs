@@ -0,0 +1,316 @@
+//! 运行日志
+//!
+//! 分级的结构化日志：每行一条 JSON（时间、级别、模块、消息和附加字段），便于筛选和随问题反馈提交。
+//! 日志文件按大小滚动，超过上限时依次改名为 `.1`、`.2`……，超出保留个数的最旧文件被删除。
+//! 进程内只有一个日志器，由图形界面启动时 `init`；未初始化时（命令行、测试）记录调用不做任何事。
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use crate::i18n::{tr, trf};
+
+/// 日志级别（越靠前越严重）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// 错误：操作失败，如数据保存失败、程序崩溃
+    Error,
+    /// 警告：操作未完成或数据异常，如候选部门不足、数据文件无法解析
+    Warn,
+    /// 信息：正常的业务过程，如开始抽签、抽签结果
+    Info,
+    /// 调试：详细过程，如候选名单和规则说明
+    Debug,
+}
+
+impl Level {
+    /// 所有级别
+    pub fn all() -> [Level; 4] {
+        [Level::Error, Level::Warn, Level::Info, Level::Debug]
+    }
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Level::Error => tr("错误"),
+            Level::Warn => tr("警告"),
+            Level::Info => tr("信息"),
+            Level::Debug => tr("调试"),
+        }
+    }
+
+    /// 按名称查找（不区分大小写，用于 `QUALITY_DRAW_LOG` 环境变量）
+    pub fn from_name(name: &str) -> Option<Level> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" | "warning" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            _ => None,
+        }
+    }
+}
+
+/// 一条日志
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// 记录时间
+    pub timestamp: DateTime<Local>,
+    /// 级别
+    pub level: Level,
+    /// 模块，如 app、storage、draw
+    pub target: String,
+    /// 消息
+    pub message: String,
+    /// 附加字段（如部门、专业、随机种子、错误原因）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+}
+
+/// 按大小滚动的日志文件
+pub struct Logger {
+    /// 当前日志文件
+    path: PathBuf,
+    /// 记录的最低级别（更详细的级别被忽略）
+    max_level: Level,
+    /// 单个文件的大小上限（字节）
+    max_bytes: u64,
+    /// 保留的历史文件个数
+    max_files: usize,
+    /// 写入和滚动互斥
+    lock: Mutex<()>,
+}
+
+impl Logger {
+    /// 单个日志文件的默认大小上限（1 MB）
+    pub const DEFAULT_MAX_BYTES: u64 = 1024 * 1024;
+    /// 默认保留的历史文件个数
+    pub const DEFAULT_MAX_FILES: usize = 3;
+
+    /// 创建日志器，记录 `max_level` 及更严重的日志
+    pub fn new(path: impl Into<PathBuf>, max_level: Level) -> Self {
+        Self {
+            path: path.into(),
+            max_level,
+            max_bytes: Self::DEFAULT_MAX_BYTES,
+            max_files: Self::DEFAULT_MAX_FILES,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// 设置单个文件的大小上限和保留的历史文件个数
+    pub fn with_rotation(mut self, max_bytes: u64, max_files: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self.max_files = max_files;
+        self
+    }
+
+    /// 当前日志文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 该级别是否会被记录
+    pub fn enabled(&self, level: Level) -> bool {
+        level <= self.max_level
+    }
+
+    /// 写入一条日志（写入失败时静默忽略，日志不能影响抽签）
+    pub fn log(&self, level: Level, target: &str, message: &str, fields: &[(&str, &dyn Display)]) {
+        if !self.enabled(level) {
+            return;
+        }
+        let entry = LogEntry {
+            timestamp: Local::now(),
+            level,
+            target: target.to_string(),
+            message: message.to_string(),
+            fields: fields.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+        };
+        let Ok(line) = serde_json::to_string(&entry) else {
+            return;
+        };
+
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let size = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if size > 0 && size + line.len() as u64 + 1 > self.max_bytes {
+            self.rotate();
+        }
+        if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(&self.path) {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    /// 滚动：`.1` 改为 `.2`……当前文件改为 `.1`，超出保留个数的删除
+    fn rotate(&self) {
+        if self.max_files == 0 {
+            let _ = fs::remove_file(&self.path);
+            return;
+        }
+        let _ = fs::remove_file(self.rotated(self.max_files));
+        for n in (1..self.max_files).rev() {
+            let _ = fs::rename(self.rotated(n), self.rotated(n + 1));
+        }
+        let _ = fs::rename(&self.path, self.rotated(1));
+    }
+
+    /// 第 `n` 个历史文件的路径（如 `quality_draw.log.1`）
+    fn rotated(&self, n: usize) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", n));
+        PathBuf::from(name)
+    }
+
+    /// 现有的日志文件（从旧到新）
+    pub fn files(&self) -> Vec<PathBuf> {
+        (1..=self.max_files)
+            .rev()
+            .map(|n| self.rotated(n))
+            .chain(std::iter::once(self.path.clone()))
+            .filter(|path| path.exists())
+            .collect()
+    }
+
+    /// 读取全部日志（从旧到新，无法解析的行如旧版本的纯文本日志被跳过）
+    pub fn read_entries(&self) -> Vec<LogEntry> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        self.files()
+            .iter()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .flat_map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect::<Vec<LogEntry>>()
+            })
+            .collect()
+    }
+
+    /// 把全部日志文件按从旧到新合并导出到一个文件（用于随问题反馈提交）
+    pub fn export(&self, dest: &Path) -> Result<(), String> {
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut content = Vec::new();
+        for path in self.files() {
+            let mut data = fs::read(&path).map_err(|e| trf("读取日志文件失败: {}", &[&e]))?;
+            if !data.is_empty() && !data.ends_with(b"\n") {
+                data.push(b'\n');
+            }
+            content.extend(data);
+        }
+        fs::write(dest, content).map_err(|e| trf("导出日志失败: {}", &[&e]))
+    }
+}
+
+/// 进程内的日志器
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+/// 设置进程内的日志器（只能设置一次，重复调用返回 false）
+pub fn init(logger: Logger) -> bool {
+    LOGGER.set(logger).is_ok()
+}
+
+/// 进程内的日志器（未初始化时为 None）
+pub fn logger() -> Option<&'static Logger> {
+    LOGGER.get()
+}
+
+/// 记录一条日志，`fields` 为附加字段，如 `&[("seed", &seed)]`
+pub fn log(level: Level, target: &str, message: &str, fields: &[(&str, &dyn Display)]) {
+    if let Some(logger) = LOGGER.get() {
+        logger.log(level, target, message, fields);
+    }
+}
+
+/// 记录错误
+pub fn error(target: &str, message: &str, fields: &[(&str, &dyn Display)]) {
+    log(Level::Error, target, message, fields);
+}
+
+/// 记录警告
+pub fn warn(target: &str, message: &str, fields: &[(&str, &dyn Display)]) {
+    log(Level::Warn, target, message, fields);
+}
+
+/// 记录信息
+pub fn info(target: &str, message: &str, fields: &[(&str, &dyn Display)]) {
+    log(Level::Info, target, message, fields);
+}
+
+/// 记录调试信息
+pub fn debug(target: &str, message: &str, fields: &[(&str, &dyn Display)]) {
+    log(Level::Debug, target, message, fields);
+}
+
+/// 该级别是否会被记录（用于跳过代价较大的字段计算）
+pub fn enabled(level: Level) -> bool {
+    LOGGER.get().is_some_and(|logger| logger.enabled(level))
+}
+
+/// 把程序崩溃信息（位置和原因）记录为错误日志
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|panic_info| {
+        let location = panic_info
+            .location()
+            .map(|l| format!("{}:{}", l.file(), l.line()))
+            .unwrap_or_default();
+        let reason = panic_info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic_info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        error("panic", "程序崩溃", &[("location", &location), ("reason", &reason)]);
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_rotation_and_export() {
+        let dir = std::env::temp_dir().join(format!("quality_draw_log_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let logger = Logger::new(dir.join("quality_draw.log"), Level::Info).with_rotation(400, 2);
+
+        // 调试日志低于记录级别，不写入
+        logger.log(Level::Debug, "draw", "候选名单", &[]);
+        assert!(logger.files().is_empty());
+
+        for i in 0..20 {
+            logger.log(Level::Info, "draw", "抽签结果", &[("seed", &i), ("selected", &"检验一部")]);
+        }
+        logger.log(Level::Error, "storage", "保存失败", &[("path", &"records.json")]);
+
+        // 滚动后最多保留当前文件和两个历史文件，每个都不超过上限
+        let files = logger.files();
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().all(|path| fs::metadata(path).unwrap().len() <= 400));
+        assert!(!dir.join("quality_draw.log.3").exists());
+
+        // 读取按从旧到新排列，最旧的记录已被删除
+        let entries = logger.read_entries();
+        let last = entries.last().unwrap();
+        assert_eq!(last.level, Level::Error);
+        assert_eq!(last.fields.get("path").map(String::as_str), Some("records.json"));
+        assert!(entries.len() < 21);
+        let seeds: Vec<u32> = entries.iter()
+            .filter_map(|e| e.fields.get("seed")?.parse().ok())
+            .collect();
+        assert!(seeds.windows(2).all(|w| w[0] + 1 == w[1]));
+        assert_eq!(seeds.last(), Some(&19));
+
+        // 导出的文件包含全部现有日志
+        let dest = dir.join("export.log");
+        logger.export(&dest).unwrap();
+        assert_eq!(fs::read_to_string(&dest).unwrap().lines().count(), entries.len());
+
+        assert_eq!(Level::from_name("WARNING"), Some(Level::Warn));
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,163 @@
+//! 操作员账户认证
+//! 
+//! 密码使用 PBKDF2-HMAC-SHA256 加随机盐保存，数据文件中不保存明文密码。
+
+use chrono::Local;
+use pbkdf2::pbkdf2_hmac_array;
+use rand::RngCore;
+use sha2::Sha256;
+use crate::i18n::{tr, trf};
+use crate::models::{Operator, Role};
+
+/// PBKDF2 迭代次数
+const PBKDF2_ROUNDS: u32 = 100_000;
+
+/// 密码最小长度
+const MIN_PASSWORD_LEN: usize = 6;
+
+/// 账户认证管理
+pub struct AuthManager;
+
+impl AuthManager {
+    /// 创建新操作员
+    /// 
+    /// 登录名不能与已有账户重复，密码至少6位
+    pub fn create_operator(
+        existing: &[Operator],
+        username: &str,
+        display_name: &str,
+        role: Role,
+        password: &str,
+    ) -> Result<Operator, String> {
+        let username = username.trim();
+        let display_name = display_name.trim();
+        if username.is_empty() {
+            return Err(tr("请填写登录名").to_string());
+        }
+        if display_name.is_empty() {
+            return Err(tr("请填写姓名").to_string());
+        }
+        if existing.iter().any(|o| o.username == username) {
+            return Err(trf("登录名 {} 已存在", &[&username]));
+        }
+        Self::check_password(password)?;
+        
+        let salt = Self::new_salt();
+        Ok(Operator {
+            id: uuid::Uuid::new_v4().to_string(),
+            username: username.to_string(),
+            display_name: display_name.to_string(),
+            role,
+            password_hash: Self::hash_password(password, &salt),
+            salt,
+            created_at: Local::now(),
+        })
+    }
+    
+    /// 校验登录名和密码，成功返回对应的操作员
+    pub fn authenticate(operators: &[Operator], username: &str, password: &str) -> Result<Operator, String> {
+        operators
+            .iter()
+            .find(|o| o.username == username.trim())
+            .filter(|o| Self::hash_password(password, &o.salt) == o.password_hash)
+            .cloned()
+            .ok_or_else(|| tr("登录名或密码错误").to_string())
+    }
+    
+    /// 验证见证人：见证人必须是执行抽签的操作员以外的账户
+    pub fn authenticate_witness(
+        operators: &[Operator],
+        drawer_id: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<Operator, String> {
+        let witness = Self::authenticate(operators, username, password)?;
+        Self::check_witness(drawer_id, &witness)?;
+        Ok(witness)
+    }
+    
+    /// 检查已通过密码验证的见证人不是执行抽签的操作员本人
+    pub fn check_witness(drawer_id: &str, witness: &Operator) -> Result<(), String> {
+        if witness.id == drawer_id {
+            return Err(tr("见证人不能是执行抽签的操作员本人").to_string());
+        }
+        Ok(())
+    }
+    
+    /// 重设密码（重新生成盐）
+    pub fn set_password(operator: &mut Operator, password: &str) -> Result<(), String> {
+        Self::check_password(password)?;
+        operator.salt = Self::new_salt();
+        operator.password_hash = Self::hash_password(password, &operator.salt);
+        Ok(())
+    }
+    
+    /// 检查删除或降级后是否仍至少保留一个管理员
+    pub fn keeps_admin(operators: &[Operator], changed_id: &str, new_role: Option<Role>) -> bool {
+        operators.iter().any(|o| {
+            let role = if o.id == changed_id { new_role } else { Some(o.role) };
+            role == Some(Role::Admin)
+        })
+    }
+    
+    /// 检查密码强度
+    fn check_password(password: &str) -> Result<(), String> {
+        if password.chars().count() < MIN_PASSWORD_LEN {
+            return Err(trf("密码至少{}位", &[&MIN_PASSWORD_LEN]));
+        }
+        Ok(())
+    }
+    
+    /// 生成16字节随机盐
+    fn new_salt() -> String {
+        let mut salt = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        to_hex(&salt)
+    }
+    
+    /// 计算密码哈希
+    fn hash_password(password: &str, salt: &str) -> String {
+        let hash = pbkdf2_hmac_array::<Sha256, 32>(password.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS);
+        to_hex(&hash)
+    }
+}
+
+/// 字节转十六进制字符串
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authenticate() {
+        let admin = AuthManager::create_operator(&[], "admin", "管理员", Role::Admin, "secret123").unwrap();
+        assert_ne!(admin.password_hash, "secret123");
+        let operators = vec![admin];
+
+        assert!(AuthManager::authenticate(&operators, "admin", "secret123").is_ok());
+        assert!(AuthManager::authenticate(&operators, "admin", "wrong").is_err());
+        assert!(AuthManager::authenticate(&operators, "nobody", "secret123").is_err());
+
+        // 抽签操作员不能为自己见证
+        assert!(AuthManager::authenticate_witness(&operators, &operators[0].id, "admin", "secret123").is_err());
+        assert!(AuthManager::authenticate_witness(&operators, "other", "admin", "secret123").is_ok());
+
+        // 登录名重复、密码过短
+        assert!(AuthManager::create_operator(&operators, "admin", "张三", Role::Observer, "secret123").is_err());
+        assert!(AuthManager::create_operator(&operators, "zhangsan", "张三", Role::Observer, "123").is_err());
+    }
+
+    #[test]
+    fn test_keeps_admin() {
+        let admin = AuthManager::create_operator(&[], "admin", "管理员", Role::Admin, "secret123").unwrap();
+        let observer = AuthManager::create_operator(&[], "guest", "观察员", Role::Observer, "secret123").unwrap();
+        let operators = vec![admin.clone(), observer.clone()];
+
+        assert!(!AuthManager::keeps_admin(&operators, &admin.id, None));
+        assert!(!AuthManager::keeps_admin(&operators, &admin.id, Some(Role::DrawOperator)));
+        assert!(AuthManager::keeps_admin(&operators, &observer.id, None));
+    }
+}
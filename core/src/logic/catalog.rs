@@ -0,0 +1,135 @@
+//! 部门、专责和专业的数据管理校验
+//!
+//! 新增和修改前检查名称、引用的部门和专业是否有效，删除部门和专业前检查是否仍被使用。
+
+use crate::i18n::{tr, trf};
+use crate::models::{Department, QualitySpecialist, Specialty};
+
+/// 检查组部门数上限
+pub const MAX_TEAM_SIZE: usize = 5;
+
+/// 数据管理
+pub struct CatalogManager;
+
+impl CatalogManager {
+    /// 校验专责：姓名不能为空，部门和专业必须存在，同一部门同一专业不能重名
+    pub fn validate_specialist(
+        specialists: &[QualitySpecialist],
+        departments: &[Department],
+        specialties: &[Specialty],
+        specialist: &QualitySpecialist,
+    ) -> Result<(), String> {
+        if specialist.name.trim().is_empty() {
+            return Err(tr("请填写专责姓名").to_string());
+        }
+        if !departments.iter().any(|d| d.id == specialist.department_id) {
+            return Err(tr("请选择专责所属部门").to_string());
+        }
+        let Some(specialty) = specialties.iter().find(|s| s.id == specialist.specialty) else {
+            return Err(tr("请选择专责的专业").to_string());
+        };
+        let duplicate = specialists.iter().any(|s| {
+            s.id != specialist.id
+                && s.name == specialist.name
+                && s.department_id == specialist.department_id
+                && s.specialty == specialist.specialty
+        });
+        if duplicate {
+            return Err(trf("重复添加：{} 在该部门的{}专业已存在", &[&specialist.name, &tr(&specialty.name)]));
+        }
+        Ok(())
+    }
+
+    /// 校验部门：名称不能为空且不能重复，专业必须存在，检查组部门数为 1~5
+    pub fn validate_department(
+        departments: &[Department],
+        specialties: &[Specialty],
+        department: &Department,
+    ) -> Result<(), String> {
+        if department.name.trim().is_empty() {
+            return Err(tr("请填写部门名称").to_string());
+        }
+        if departments.iter().any(|d| d.id != department.id && d.name == department.name) {
+            return Err(trf("部门 {} 已存在", &[&department.name]));
+        }
+        let unknown = department.needs.iter()
+            .chain(&department.supplies)
+            .chain(department.team_sizes.keys())
+            .find(|id| !specialties.iter().any(|s| &s.id == *id));
+        if let Some(id) = unknown {
+            return Err(trf("未知的专业 {}", &[id]));
+        }
+        let name = |id: &str| specialties.iter().find(|s| s.id == id).map(|s| tr(&s.name).to_string()).unwrap_or_default();
+        if let Some((id, size)) = department.team_sizes.iter().find(|(_, &size)| size > MAX_TEAM_SIZE) {
+            return Err(trf("{}的检查组部门数 {} 超过上限 {}", &[&name(id), size, &MAX_TEAM_SIZE]));
+        }
+        if let Some(id) = department.team_sizes.keys().find(|id| !department.needs_specialty(id)) {
+            return Err(trf("部门不需要抽取{}，不能设置该专业的检查组部门数", &[&name(id)]));
+        }
+        Ok(())
+    }
+
+    /// 校验专业：名称不能为空且不能重复
+    pub fn validate_specialty(specialties: &[Specialty], specialty: &Specialty) -> Result<(), String> {
+        if specialty.name.trim().is_empty() {
+            return Err(tr("请填写专业名称").to_string());
+        }
+        if specialties.iter().any(|s| s.id != specialty.id && s.name == specialty.name) {
+            return Err(trf("专业 {} 已存在", &[&specialty.name]));
+        }
+        Ok(())
+    }
+
+    /// 专业是否仍被部门（需要或派出）或专责使用
+    pub fn specialty_in_use(departments: &[Department], specialists: &[QualitySpecialist], specialty_id: &str) -> bool {
+        departments.iter().any(|d| d.needs_specialty(specialty_id) || d.supplies_specialty(specialty_id))
+            || specialists.iter().any(|s| s.specialty == specialty_id)
+    }
+
+    /// 部门是否仍被专责（所属部门）或本轮抽签结果（被检或抽中）引用
+    ///
+    /// `round_pairs` 为本轮各专业已抽中的 (被检部门ID, 抽中部门ID)；历史记录保存了部门名称，不受删除影响
+    pub fn department_in_use<'a>(
+        specialists: &[QualitySpecialist],
+        round_pairs: impl IntoIterator<Item = &'a (String, String)>,
+        department_id: &str,
+    ) -> bool {
+        specialists.iter().any(|s| s.department_id == department_id)
+            || round_pairs.into_iter().any(|(target, selected)| target == department_id || selected == department_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{default_departments, default_specialties};
+
+    #[test]
+    fn test_validation() {
+        let departments = default_departments();
+        let specialties = default_specialties();
+        let specialists = vec![QualitySpecialist::new("s1", "张三", &departments[0].id, "Pressure")];
+
+        let duplicate = QualitySpecialist::new("s2", "张三", &departments[0].id, "Pressure");
+        assert!(CatalogManager::validate_specialist(&specialists, &departments, &specialties, &duplicate).is_err());
+        // 修改自身不算重复
+        assert!(CatalogManager::validate_specialist(&specialists, &departments, &specialties, &specialists[0]).is_ok());
+        let unknown = QualitySpecialist::new("s3", "李四", "missing", "Pressure");
+        assert!(CatalogManager::validate_specialist(&specialists, &departments, &specialties, &unknown).is_err());
+
+        let mut department = departments[0].clone();
+        assert!(CatalogManager::validate_department(&departments, &specialties, &department).is_ok());
+        department.set_team_size("Pressure", MAX_TEAM_SIZE + 1);
+        assert!(CatalogManager::validate_department(&departments, &specialties, &department).is_err());
+        let renamed = Department::new("new", &departments[1].name, "综合类", &["Pressure"]);
+        assert!(CatalogManager::validate_department(&departments, &specialties, &renamed).is_err());
+
+        assert!(CatalogManager::specialty_in_use(&departments, &specialists, "Pressure"));
+        assert!(!CatalogManager::specialty_in_use(&departments, &specialists, "Lifting"));
+        assert!(CatalogManager::department_in_use(&specialists, &[], &departments[0].id));
+        let pairs = [(departments[2].id.clone(), departments[1].id.clone())];
+        assert!(CatalogManager::department_in_use(&[], &pairs, &departments[1].id));
+        assert!(!CatalogManager::department_in_use(&specialists, &pairs, &departments[3].id));
+        assert!(CatalogManager::validate_specialty(&specialties, &Specialty::new("x", "承压类")).is_err());
+    }
+}
@@ -0,0 +1,758 @@
+//! 抽签算法实现
+
+use std::collections::BTreeMap;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use crate::i18n::{tr, trf};
+use crate::logging::{self, Level};
+use crate::models::{Department, DrawRules, QualitySpecialist, Specialty, DrawRecord};
+
+/// 抽签引擎
+pub struct DrawEngine;
+
+/// 候选部门及规则说明
+#[derive(Debug, Clone)]
+pub struct DepartmentCandidates<'a> {
+    /// 符合条件的候选部门
+    pub departments: Vec<&'a Department>,
+    /// 被排除的部门及原因
+    pub rule_notes: Vec<String>,
+}
+
+/// 按部门抽签的结果（不含动画）
+#[derive(Debug, Clone)]
+pub struct DepartmentDraw {
+    /// 中签的检查部门（检查组有多个部门时按抽中顺序排列）
+    pub selected: Vec<Department>,
+    /// 使用的随机种子
+    pub seed: u64,
+    /// 参与抽取的候选名单
+    pub candidates: Vec<String>,
+    /// 规则说明（被排除的部门及原因）
+    pub rule_notes: Vec<String>,
+}
+
+/// 按部门抽签失败的原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrawError {
+    /// 未找到被检部门（部门ID）
+    DepartmentNotFound(String),
+    /// 被检部门不需要抽取该专业（部门名称、专业名称）
+    NotNeeded(String, String),
+    /// 被检部门本轮已抽取该专业（部门名称、专业名称）
+    AlreadyDrawn(String, String),
+    /// 没有符合条件的候选部门（部门名称、专业名称）
+    NoCandidates(String, String),
+    /// 候选部门不足（部门名称、专业名称、需要数、候选数）
+    NotEnoughCandidates(String, String, usize, usize),
+}
+
+impl std::fmt::Display for DrawError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            DrawError::DepartmentNotFound(id) => trf("未找到部门 {}", &[id]),
+            DrawError::NotNeeded(department, specialty) => trf("{}不需要抽取{}", &[department, specialty]),
+            DrawError::AlreadyDrawn(department, specialty) => trf("{}本轮已抽取{}", &[department, specialty]),
+            DrawError::NoCandidates(department, specialty) => {
+                trf("{}没有符合条件的{}候选部门", &[department, specialty])
+            }
+            DrawError::NotEnoughCandidates(department, specialty, needed, available) => trf(
+                "{}需要抽取{}个{}检查部门，符合条件的候选部门只有{}个",
+                &[department, needed, specialty, available],
+            ),
+        };
+        f.write_str(&message)
+    }
+}
+
+impl std::error::Error for DrawError {}
+
+/// 命令行等按文字提示错误的调用方
+impl From<DrawError> for String {
+    fn from(error: DrawError) -> Self {
+        error.to_string()
+    }
+}
+
+impl DrawEngine {
+    /// 获取可抽取的候选人列表
+    /// 
+    /// # 参数
+    /// - `specialists`: 所有质量专责列表
+    /// - `departments`: 所有部门列表
+    /// - `target_department_id`: 被检查部门ID（需要规避的部门）
+    /// - `specialty_type`: 需要抽取的专责类型
+    /// - `last_selected_id`: 上一次抽中的人员ID（需要规避连续抽取）
+    /// - `current_round_selected_ids`: 本轮已抽中的人员ID列表
+    /// - `cross_avoidance_dept_ids`: 需要交叉回避的部门ID列表（这些部门的专责不能参与）
+    /// 
+    /// # 返回
+    /// 符合条件的候选人列表
+    pub fn get_candidates<'a>(
+        specialists: &'a [QualitySpecialist],
+        _departments: &[Department],
+        target_department_id: &str,
+        specialty_type: &str,
+        last_selected_id: Option<&str>,
+        current_round_selected_ids: &[String],
+        cross_avoidance_dept_ids: &[String],
+    ) -> Vec<&'a QualitySpecialist> {
+        specialists
+            .iter()
+            .filter(|s| {
+                // 1. 专业类型必须匹配
+                s.specialty == specialty_type
+                // 2. 不能是被检查部门的人
+                && s.department_id != target_department_id
+                // 3. 不能是上次抽中的人（连续回避）
+                && last_selected_id.map_or(true, |id| s.id != id)
+                // 4. 不能是本轮已抽中的人
+                && !current_round_selected_ids.contains(&s.id)
+                // 5. 不能是需要交叉回避的部门的人
+                && !cross_avoidance_dept_ids.contains(&s.department_id)
+            })
+            .collect()
+    }
+    
+    /// 从候选人中随机抽取一人
+    pub fn draw_one<'a>(candidates: &[&'a QualitySpecialist]) -> Option<&'a QualitySpecialist> {
+        if candidates.is_empty() {
+            return None;
+        }
+        let mut rng = rand::thread_rng();
+        candidates.choose(&mut rng).copied()
+    }
+    
+    /// 执行一次完整的抽签
+    /// 
+    /// # 参数
+    /// - `specialists`: 所有质量专责列表
+    /// - `departments`: 所有部门列表
+    /// - `target_department`: 被检查部门
+    /// - `specialty_type`: 需要抽取的专责类型
+    /// - `records`: 历史抽签记录（用于查找上次抽中的人）
+    /// - `current_round_selected_ids`: 本轮已抽中的人员ID列表
+    /// 
+    /// # 返回
+    /// 抽中的人员及其所属部门名称
+    pub fn execute_draw(
+        specialists: &[QualitySpecialist],
+        departments: &[Department],
+        target_department: &Department,
+        specialty_type: &str,
+        records: &[DrawRecord],
+        current_round_selected_ids: &[String],
+    ) -> Option<(QualitySpecialist, String)> {
+        // 查找上次抽取该部门该类型时抽中的人（作废的记录不计）
+        let last_selected_id = records
+            .iter()
+            .rev()
+            .find(|r| {
+                !r.is_voided()
+                && r.target_department_id == target_department.id
+                && r.specialty_type == specialty_type
+            })
+            .map(|r| r.selected_specialist_id.as_str());
+        
+        // 计算交叉回避的部门列表
+        // 逻辑：如果目标部门(A)被其他部门(B)的某专责检查过，那么A的同类型专责也不能去检查B
+        // 即：找到所有 "抽中的人所属部门是target_department" 的记录，这些记录的被检部门需要回避
+        let cross_avoidance_dept_ids: Vec<String> = records
+            .iter()
+            .filter(|r| {
+                // 找到本类型中，抽中人员来自目标部门的记录
+                !r.is_voided()
+                && r.specialty_type == specialty_type
+                && r.selected_from_department_id == target_department.id
+            })
+            .map(|r| r.target_department_id.clone())
+            .collect();
+        
+        // 获取候选人
+        let candidates = Self::get_candidates(
+            specialists,
+            departments,
+            &target_department.id,
+            specialty_type,
+            last_selected_id,
+            current_round_selected_ids,
+            &cross_avoidance_dept_ids,
+        );
+        
+        // 随机抽取
+        if let Some(selected) = Self::draw_one(&candidates) {
+            // 获取部门名称
+            let dept_name = departments
+                .iter()
+                .find(|d| d.id == selected.department_id)
+                .map(|d| d.name.clone())
+                .unwrap_or_else(|| "未知部门".to_string());
+            
+            Some((selected.clone(), dept_name))
+        } else {
+            None
+        }
+    }
+    
+    /// 获取可抽取的检查部门（按部门抽签模式）
+    /// 
+    /// # 参数
+    /// - `departments`: 所有部门列表
+    /// - `target_department_id`: 被检查部门ID
+    /// - `specialty`: 需要抽取的专业
+    /// - `current_round`: 本轮该类型已抽中的 (被检部门ID, 抽中部门ID) 列表
+    /// - `rules`: 单位配置的抽签规则（第4、5条可关闭）
+    /// 
+    /// # 返回
+    /// 符合条件的候选部门，以及每个被排除部门的排除原因
+    pub fn get_candidate_departments<'a>(
+        departments: &'a [Department],
+        target_department_id: &str,
+        specialty: &Specialty,
+        current_round: &[(String, String)],
+        rules: &DrawRules,
+    ) -> DepartmentCandidates<'a> {
+        let mut candidates = Vec::new();
+        let mut rule_notes = Vec::new();
+        
+        for dept in departments {
+            // 1. 部门必须能派出该专业的检查人员
+            if !dept.supplies_specialty(&specialty.id) {
+                continue;
+            }
+            
+            // 2. 不能检查本部门
+            if dept.id == target_department_id {
+                rule_notes.push(format!("排除 {}：被检查部门本身", dept.name));
+                continue;
+            }
+            
+            // 3. 同一检查组的部门不能重复
+            if current_round.iter().any(|(target, selected)| target == target_department_id && selected == &dept.id) {
+                rule_notes.push(format!("排除 {}：已在本轮{}检查组中", dept.name, specialty.name));
+                continue;
+            }
+            
+            // 4. 本轮已被抽中的部门不再参与
+            if let Some((target, _)) = current_round.iter()
+                .find(|(_, selected)| rules.once_per_round && selected == &dept.id)
+            {
+                let target_name = departments.iter()
+                    .find(|d| &d.id == target)
+                    .map(|d| d.name.as_str())
+                    .unwrap_or("未知部门");
+                rule_notes.push(format!(
+                    "排除 {}：本轮已被抽中检查{}（{}）",
+                    dept.name, target_name, specialty.name
+                ));
+                continue;
+            }
+            
+            // 5. 交叉回避：被检部门本轮已去检查过的部门，不能反过来检查被检部门
+            if rules.cross_avoidance
+                && current_round.iter().any(|(target, selected)| target == &dept.id && selected == target_department_id)
+            {
+                rule_notes.push(format!(
+                    "排除 {}：交叉回避（本轮已由被检部门检查其{}）",
+                    dept.name, specialty.name
+                ));
+                continue;
+            }
+            
+            candidates.push(dept);
+        }
+        
+        DepartmentCandidates {
+            departments: candidates,
+            rule_notes,
+        }
+    }
+    
+    /// 使用随机种子打乱候选名单并决定中签位置
+    /// 
+    /// 相同的候选名单和种子总是得到相同的结果，用于事后复核
+    /// 
+    /// # 返回
+    /// (打乱后的名单, 中签者在打乱后名单中的索引)
+    pub fn seeded_draw(candidates: &[String], seed: u64) -> (Vec<String>, usize) {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut shuffled = candidates.to_vec();
+        shuffled.shuffle(&mut rng);
+        let index = if shuffled.is_empty() {
+            0
+        } else {
+            rng.gen_range(0..shuffled.len())
+        };
+        (shuffled, index)
+    }
+    
+    /// 使用随机种子一次抽取检查组的多个部门
+    /// 
+    /// 与 `seeded_draw` 打乱方式相同，检查组为转盘上从中签位置起连续的 `size` 个部门，
+    /// 因此只抽一个部门时结果与 `seeded_draw` 一致
+    /// 
+    /// # 返回
+    /// (打乱后的名单, 各成员在打乱后名单中的索引)
+    pub fn seeded_team_draw(candidates: &[String], seed: u64, size: usize) -> (Vec<String>, Vec<usize>) {
+        let (shuffled, index) = Self::seeded_draw(candidates, seed);
+        let len = shuffled.len();
+        let members = (0..size.min(len)).map(|offset| (index + offset) % len).collect();
+        (shuffled, members)
+    }
+    
+    /// 为被检部门按随机种子抽取检查部门
+    /// 
+    /// 按被检部门设置的检查组人数抽取本轮尚缺的部门（作废成员后只补抽缺少的部分）。
+    /// 与图形界面的转盘使用相同的候选规则和种子算法，用于命令行等无动画场景
+    pub fn draw_department(
+        departments: &[Department],
+        target_department_id: &str,
+        specialty: &Specialty,
+        current_round: &[(String, String)],
+        rules: &DrawRules,
+        seed: u64,
+    ) -> Result<DepartmentDraw, DrawError> {
+        let target = departments.iter()
+            .find(|d| d.id == target_department_id)
+            .ok_or_else(|| DrawError::DepartmentNotFound(target_department_id.to_string()))?;
+        let specialty_name = tr(&specialty.name).to_string();
+        if !target.needs_specialty(&specialty.id) {
+            return Err(DrawError::NotNeeded(target.name.clone(), specialty_name));
+        }
+        let drawn = current_round.iter().filter(|(t, _)| t == target_department_id).count();
+        let size = target.team_size(&specialty.id).saturating_sub(drawn);
+        if size == 0 {
+            return Err(DrawError::AlreadyDrawn(target.name.clone(), specialty_name));
+        }
+        
+        let result = Self::get_candidate_departments(departments, target_department_id, specialty, current_round, rules);
+        if result.departments.is_empty() {
+            return Err(DrawError::NoCandidates(target.name.clone(), specialty_name));
+        }
+        if result.departments.len() < size {
+            return Err(DrawError::NotEnoughCandidates(target.name.clone(), specialty_name, size, result.departments.len()));
+        }
+        
+        // 候选名单与候选部门一一对应，按下标取回中签部门
+        let candidates: Vec<String> = result.departments.iter().map(|d| d.name.clone()).collect();
+        let (order, members) = Self::seeded_team_draw(&candidates, seed, size);
+        let selected = members.iter()
+            .filter_map(|&index| result.departments.iter().find(|d| d.name == order[index]))
+            .map(|d| (*d).clone())
+            .collect::<Vec<Department>>();
+        // 模拟时会反复调用，只在调试级别记录
+        if logging::enabled(Level::Debug) {
+            let names: Vec<&str> = selected.iter().map(|d| d.name.as_str()).collect();
+            logging::debug("draw", "按种子抽取检查部门", &[
+                ("target", &target.name),
+                ("specialty", &specialty.name),
+                ("seed", &seed),
+                ("candidates", &candidates.join("、")),
+                ("excluded", &result.rule_notes.len()),
+                ("selected", &names.join("、")),
+            ]);
+        }
+        
+        Ok(DepartmentDraw {
+            selected,
+            seed,
+            candidates,
+            rule_notes: result.rule_notes,
+        })
+    }
+    
+    /// 从抽签记录中恢复某一轮某专业已抽中的 (被检部门ID, 抽中部门ID) 列表（作废记录不计）
+    pub fn round_pairs(records: &[DrawRecord], round: u32, specialty_type: &str) -> Vec<(String, String)> {
+        records.iter()
+            .filter(|r| r.round == round && r.specialty_type == specialty_type && !r.is_voided())
+            .map(|r| (r.target_department_id.clone(), r.selected_from_department_id.clone()))
+            .collect()
+    }
+    
+    /// 从抽签记录中恢复某一轮全部专业已抽中的列表（专业ID → (被检部门ID, 抽中部门ID)，作废记录不计）
+    pub fn round_pairs_by_specialty(records: &[DrawRecord], round: u32) -> BTreeMap<String, Vec<(String, String)>> {
+        let mut pairs: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+        for r in records.iter().filter(|r| r.round == round && !r.is_voided()) {
+            pairs.entry(r.specialty_type.clone())
+                .or_default()
+                .push((r.target_department_id.clone(), r.selected_from_department_id.clone()));
+        }
+        pairs
+    }
+    
+    /// 复核抽签记录：用记录中的种子和候选名单重放抽签，检查结果是否一致
+    /// 
+    /// 检查组记录按其在本次抽取结果中的位置复核；旧版本记录没有种子和候选名单，返回 None
+    pub fn verify_record(record: &DrawRecord) -> Option<bool> {
+        let seed = record.seed?;
+        if record.candidates.is_empty() {
+            return None;
+        }
+        let (order, index) = Self::seeded_draw(&record.candidates, seed);
+        let position = record.team.as_ref().map_or(0, |team| team.position);
+        Some(order[(index + position) % order.len()] == record.selected_specialist_name)
+    }
+    
+    /// 用随机种子复核记录保存的转盘回放数据
+    /// 
+    /// 回放的候选顺序和中签位置必须与种子重算的结果一致，且停下的位置正是记录中的部门；
+    /// 没有回放数据或种子时返回 None
+    pub fn verify_replay(record: &DrawRecord) -> Option<bool> {
+        let replay = record.replay.as_ref()?;
+        let seed = record.seed?;
+        if record.candidates.is_empty() || replay.order.is_empty() {
+            return None;
+        }
+        let (order, index) = Self::seeded_draw(&record.candidates, seed);
+        let position = record.team.as_ref().map_or(0, |team| team.position);
+        Some(
+            replay.order == order
+                && replay.final_index == index
+                && replay.order[(replay.final_index + position) % replay.order.len()] == record.selected_specialist_name,
+        )
+    }
+    
+    /// 本轮最近一次抽签产生的有效记录（用于撤销）
+    /// 
+    /// 需要多个专业的部门各专业结果由同一次见证确认保存，视为同一次抽签
+    pub fn last_draw(records: &[DrawRecord], round: u32) -> Vec<&DrawRecord> {
+        let Some(last) = records.iter().rev().find(|r| r.round == round && !r.is_voided()) else {
+            return Vec::new();
+        };
+        let confirmed_at = last.witness.as_ref().map(|w| w.confirmed_at);
+        records.iter()
+            .filter(|r| r.round == round && !r.is_voided())
+            .filter(|r| {
+                r.id == last.id
+                    || (confirmed_at.is_some()
+                        && r.target_department_id == last.target_department_id
+                        && r.witness.as_ref().map(|w| w.confirmed_at) == confirmed_at)
+            })
+            .collect()
+    }
+    
+    /// 获取随机滚动显示的名单（用于动画）
+    pub fn get_rolling_names(
+        specialists: &[QualitySpecialist],
+        target_department_id: &str,
+        specialty_type: &str,
+        last_selected_id: Option<&str>,
+        current_round_selected_ids: &[String],
+        cross_avoidance_dept_ids: &[String],
+    ) -> Vec<String> {
+        specialists
+            .iter()
+            .filter(|s| {
+                // 1. 专业类型必须匹配
+                s.specialty == specialty_type
+                // 2. 不能是被检查部门的人
+                && s.department_id != target_department_id
+                // 3. 不能是上次抽中的人
+                && last_selected_id.map_or(true, |id| s.id != id)
+                // 4. 不能是本轮已抽中的人
+                && !current_round_selected_ids.contains(&s.id)
+                // 5. 不能是需要交叉回避的部门的人
+                && !cross_avoidance_dept_ids.contains(&s.department_id)
+            })
+            .map(|s| s.name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{default_departments, default_specialties};
+    
+    fn create_test_specialists() -> Vec<QualitySpecialist> {
+        vec![
+            QualitySpecialist::new("1", "张三", "szs", "Pressure"),
+            QualitySpecialist::new("2", "李四", "nd", "Pressure"),
+            QualitySpecialist::new("3", "王五", "cy1", "Pressure"),
+            QualitySpecialist::new("4", "赵六", "szs", "Mechanical"),
+            QualitySpecialist::new("5", "钱七", "jd1", "Mechanical"),
+        ]
+    }
+    
+    #[test]
+    fn test_department_avoidance() {
+        let specialists = create_test_specialists();
+        let departments = default_departments();
+        
+        // 抽取石嘴山分院的承压类专责，应该排除张三（石嘴山分院的人）
+        let candidates = DrawEngine::get_candidates(
+            &specialists,
+            &departments,
+            "szs",
+            "Pressure",
+            None,
+            &[],
+            &[],
+        );
+        
+        assert!(!candidates.iter().any(|s| s.name == "张三"));
+        assert!(candidates.iter().any(|s| s.name == "李四"));
+        assert!(candidates.iter().any(|s| s.name == "王五"));
+    }
+    
+    #[test]
+    fn test_consecutive_avoidance() {
+        let specialists = create_test_specialists();
+        let departments = default_departments();
+        
+        // 上次抽中李四，这次应该排除李四
+        let candidates = DrawEngine::get_candidates(
+            &specialists,
+            &departments,
+            "szs",
+            "Pressure",
+            Some("2"), // 李四的ID
+            &[],
+            &[],
+        );
+        
+        assert!(!candidates.iter().any(|s| s.name == "李四"));
+        assert!(candidates.iter().any(|s| s.name == "王五"));
+    }
+    
+    #[test]
+    fn test_specialty_filter() {
+        let specialists = create_test_specialists();
+        let departments = default_departments();
+        
+        // 抽取机电类专责
+        let candidates = DrawEngine::get_candidates(
+            &specialists,
+            &departments,
+            "szs",
+            "Mechanical",
+            None,
+            &[],
+            &[],
+        );
+        
+        // 只应该有机电类的人，且不包含石嘴山分院的赵六
+        assert!(!candidates.iter().any(|s| s.name == "赵六"));
+        assert!(candidates.iter().any(|s| s.name == "钱七"));
+    }
+    
+    #[test]
+    fn test_candidate_departments_rules() {
+        let departments = default_departments();
+        let pressure = &default_specialties()[0];
+        // 本轮：石嘴山分院已被抽中检查宁东分院，宁东分院已被抽中检查吴忠分院
+        let current_round = vec![
+            ("nd".to_string(), "szs".to_string()),
+            ("wz".to_string(), "nd".to_string()),
+        ];
+        
+        let result = DrawEngine::get_candidate_departments(
+            &departments,
+            "wz",
+            pressure,
+            &current_round,
+            &DrawRules::default(),
+        );
+        let ids: Vec<&str> = result.departments.iter().map(|d| d.id.as_str()).collect();
+        
+        // 被检部门本身、本轮已抽中部门、机电类部门均不参与
+        assert!(!ids.contains(&"wz"));
+        assert!(!ids.contains(&"szs"));
+        assert!(!ids.contains(&"nd"));
+        assert!(!ids.contains(&"jd1"));
+        assert!(ids.contains(&"cy1"));
+        assert!(ids.contains(&"gy"));
+        assert_eq!(result.rule_notes.len(), 3);
+        
+        // 交叉回避：宁东分院检查过吴忠分院，吴忠分院不能检查宁东分院
+        let result = DrawEngine::get_candidate_departments(
+            &departments,
+            "nd",
+            pressure,
+            &[("wz".to_string(), "nd".to_string())],
+            &DrawRules::default(),
+        );
+        assert!(!result.departments.iter().any(|d| d.id == "wz"));
+        assert!(result.rule_notes.iter().any(|n| n.contains("交叉回避")));
+        
+        // 单位关闭了本轮不重复和交叉回避规则时，只排除被检部门本身和检查组已有的部门
+        let rules = DrawRules { once_per_round: false, cross_avoidance: false };
+        let result = DrawEngine::get_candidate_departments(&departments, "wz", pressure, &current_round, &rules);
+        let ids: Vec<&str> = result.departments.iter().map(|d| d.id.as_str()).collect();
+        assert!(!ids.contains(&"wz"));
+        assert!(ids.contains(&"szs"));
+        assert!(!ids.contains(&"nd"));
+        assert!(result.rule_notes.iter().any(|n| n.contains("检查组")));
+        assert_eq!(result.rule_notes.len(), 2);
+    }
+    
+    #[test]
+    fn test_custom_specialty() {
+        // 自定义专业：电梯由机电一部派出，只有分院需要抽取
+        let elevator = Specialty::new("elevator", "电梯");
+        let mut departments = default_departments();
+        for dept in departments.iter_mut() {
+            if dept.category == "综合类" {
+                dept.needs.push(elevator.id.clone());
+            }
+            if dept.id == "jd1" {
+                dept.supplies.push(elevator.id.clone());
+            }
+        }
+
+        let result = DrawEngine::get_candidate_departments(&departments, "nd", &elevator, &[], &DrawRules::default());
+        let ids: Vec<&str> = result.departments.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, ["jd1"]);
+
+        let draw = DrawEngine::draw_department(&departments, "wz", &elevator, &[], &DrawRules::default(), 3).unwrap();
+        assert_eq!(draw.selected[0].id, "jd1");
+        assert!(DrawEngine::draw_department(&departments, "jd1", &elevator, &[], &DrawRules::default(), 3).is_err());
+    }
+
+    #[test]
+    fn test_seeded_draw_is_reproducible() {
+        let names: Vec<String> = ["宁东分院", "石嘴山分院", "吴忠分院", "中卫分院"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        
+        let (order_a, index_a) = DrawEngine::seeded_draw(&names, 42);
+        let (order_b, index_b) = DrawEngine::seeded_draw(&names, 42);
+        
+        assert_eq!(order_a, order_b);
+        assert_eq!(index_a, index_b);
+        assert!(index_a < names.len());
+        assert_eq!(order_a.len(), names.len());
+    }
+
+    #[test]
+    fn test_draw_department_and_verify() {
+        let departments = crate::models::default_departments();
+        let pressure = &default_specialties()[0];
+
+        let draw = DrawEngine::draw_department(&departments, "nd", pressure, &[], &DrawRules::default(), 7).unwrap();
+        let selected = &draw.selected[0];
+        assert_eq!(draw.selected.len(), 1);
+        assert_ne!(selected.id, "nd");
+        assert!(draw.candidates.contains(&selected.name));
+
+        // 纯机电部门不需要抽取承压类
+        assert!(DrawEngine::draw_department(&departments, "jd1", pressure, &[], &DrawRules::default(), 7).is_err());
+
+        let record = DrawRecord::new(
+            "nd", "宁东分院", pressure,
+            &selected.id, &selected.name, &selected.id, &selected.name,
+        ).with_draw_info(1, draw.seed, draw.candidates.clone(), draw.rule_notes.clone());
+        assert_eq!(DrawEngine::verify_record(&record), Some(true));
+
+        // 回放数据与种子重算的转盘一致
+        assert_eq!(DrawEngine::verify_replay(&record), None);
+        let (order, index) = DrawEngine::seeded_draw(&draw.candidates, draw.seed);
+        let replay = crate::models::DrawReplay {
+            order,
+            final_index: index,
+            team_size: 1,
+            speed: 30.0,
+            rolling_ms: 2500,
+            slowdown_ms: 3000,
+            easing: Default::default(),
+        };
+        let replayed = record.clone().with_replay(replay.clone());
+        assert_eq!(DrawEngine::verify_replay(&replayed), Some(true));
+        let mut moved = replay;
+        moved.final_index = (moved.final_index + 1) % moved.order.len();
+        assert_eq!(DrawEngine::verify_replay(&record.clone().with_replay(moved)), Some(false));
+
+        // 本轮已抽取的部门不能重复抽取
+        let pairs = DrawEngine::round_pairs(std::slice::from_ref(&record), 1, "Pressure");
+        assert!(DrawEngine::draw_department(&departments, "nd", pressure, &pairs, &DrawRules::default(), 8).is_err());
+
+        // 篡改结果后复核失败
+        let mut tampered = record.clone();
+        tampered.selected_specialist_name = "不存在的部门".to_string();
+        assert_eq!(DrawEngine::verify_record(&tampered), Some(false));
+    }
+
+    #[test]
+    fn test_team_draw() {
+        let mut departments = default_departments();
+        let pressure = &default_specialties()[0];
+        departments.iter_mut().find(|d| d.id == "nd").unwrap().set_team_size("Pressure", 3);
+
+        // 一次抽出3个互不相同的部门，每个部门都满足回避规则
+        let current_round = vec![("wz".to_string(), "szs".to_string()), ("cy1".to_string(), "nd".to_string())];
+        let draw = DrawEngine::draw_department(&departments, "nd", pressure, &current_round, &DrawRules::default(), 11).unwrap();
+        let ids: Vec<&str> = draw.selected.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.iter().all(|id| !["nd", "szs", "cy1"].contains(id) && pressure_supplier(&departments, id)));
+        assert!(ids.iter().enumerate().all(|(i, id)| !ids[..i].contains(id)));
+
+        // 分组保存的记录逐条复核
+        let group_id = "group".to_string();
+        let records: Vec<DrawRecord> = draw.selected.iter().enumerate()
+            .map(|(position, d)| DrawRecord::new("nd", "宁东分院", pressure, &d.id, &d.name, &d.id, &d.name)
+                .with_draw_info(1, draw.seed, draw.candidates.clone(), draw.rule_notes.clone())
+                .with_team(crate::models::TeamInfo { group_id: group_id.clone(), position, size: 3 }))
+            .collect();
+        assert!(records.iter().all(|r| DrawEngine::verify_record(r) == Some(true)));
+
+        // 作废一个成员后只补抽一个，且不能与剩余成员重复
+        let mut pairs = DrawEngine::round_pairs(&records, 1, "Pressure");
+        assert_eq!(DrawEngine::round_pairs_by_specialty(&records, 1).get("Pressure"), Some(&pairs));
+        assert!(matches!(
+            DrawEngine::draw_department(&departments, "nd", pressure, &pairs, &DrawRules::default(), 12),
+            Err(DrawError::AlreadyDrawn(..))
+        ));
+        pairs.remove(0);
+        let rules = DrawRules { once_per_round: false, cross_avoidance: false };
+        let redraw = DrawEngine::draw_department(&departments, "nd", pressure, &pairs, &rules, 12).unwrap();
+        assert_eq!(redraw.selected.len(), 1);
+        assert!(!pairs.iter().any(|(_, selected)| selected == &redraw.selected[0].id));
+
+        // 候选部门不足时不抽取
+        departments.iter_mut().find(|d| d.id == "nd").unwrap().set_team_size("Pressure", 8);
+        assert!(matches!(
+            DrawEngine::draw_department(&departments, "nd", pressure, &[], &DrawRules::default(), 11),
+            Err(DrawError::NotEnoughCandidates(_, _, 8, _))
+        ));
+    }
+
+    fn pressure_supplier(departments: &[Department], id: &str) -> bool {
+        departments.iter().any(|d| d.id == id && d.supplies_specialty("Pressure"))
+    }
+
+    #[test]
+    fn test_last_draw() {
+        let witness = crate::models::WitnessInfo {
+            operator_id: "w".to_string(),
+            name: "见证人".to_string(),
+            confirmed_at: chrono::Local::now(),
+            remote_addr: None,
+        };
+        let specialties = default_specialties();
+        let record = |target: &str, specialty: &Specialty, round| {
+            DrawRecord::test_record(target, specialty, "石嘴山分院", round).with_witness(witness.clone())
+        };
+        let mut records = vec![
+            record("wz", &specialties[0], 1),
+            record("nd", &specialties[0], 2),
+            record("nd", &specialties[1], 2),
+        ];
+
+        // 同一次确认的综合类结果一并撤销
+        let last: Vec<&str> = DrawEngine::last_draw(&records, 2).iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(last, [records[1].id.as_str(), records[2].id.as_str()]);
+
+        // 作废的记录不计入，其他轮次不受影响
+        records[2].voided = Some(crate::models::VoidInfo {
+            reason: "测试".to_string(),
+            operator: "管理员".to_string(),
+            operator_id: None,
+            timestamp: chrono::Local::now(),
+        });
+        records[1].voided = records[2].voided.clone();
+        assert!(DrawEngine::last_draw(&records, 2).is_empty());
+        assert_eq!(DrawEngine::last_draw(&records, 1).len(), 1);
+    }
+}
@@ -0,0 +1,184 @@
+//! 历史记录筛选与排序
+
+use chrono::NaiveDate;
+use crate::models::DrawRecord;
+
+/// 排序列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    /// 抽签时间
+    Time,
+    /// 轮次
+    Round,
+    /// 被检部门
+    Target,
+    /// 专责类型
+    Specialty,
+    /// 抽中部门
+    Inspecting,
+}
+
+/// 历史记录筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordFilter {
+    /// 起始日期（含）
+    pub date_from: Option<NaiveDate>,
+    /// 截止日期（含）
+    pub date_to: Option<NaiveDate>,
+    /// 被检部门ID
+    pub target_department_id: Option<String>,
+    /// 抽中（检查）部门ID
+    pub inspecting_department_id: Option<String>,
+    /// 专业ID
+    pub specialty: Option<String>,
+    /// 轮次
+    pub round: Option<u32>,
+    /// 关键字（匹配部门名称、规则说明）
+    pub search: String,
+    /// 是否包含已归档的记录
+    pub include_archived: bool,
+}
+
+impl RecordFilter {
+    /// 判断记录是否满足筛选条件
+    pub fn matches(&self, record: &DrawRecord) -> bool {
+        if record.archived && !self.include_archived {
+            return false;
+        }
+        let date = record.timestamp.date_naive();
+        if self.date_from.is_some_and(|from| date < from) {
+            return false;
+        }
+        if self.date_to.is_some_and(|to| date > to) {
+            return false;
+        }
+        if let Some(id) = &self.target_department_id {
+            if &record.target_department_id != id {
+                return false;
+            }
+        }
+        if let Some(id) = &self.inspecting_department_id {
+            if &record.selected_from_department_id != id {
+                return false;
+            }
+        }
+        if self.specialty.as_ref().is_some_and(|s| &record.specialty_type != s) {
+            return false;
+        }
+        if self.round.is_some_and(|r| record.round != r) {
+            return false;
+        }
+        
+        let keyword = self.search.trim();
+        keyword.is_empty()
+            || record.target_department_name.contains(keyword)
+            || record.selected_specialist_name.contains(keyword)
+            || record.selected_from_department_name.contains(keyword)
+            || record.rule_notes.iter().any(|note| note.contains(keyword))
+            || record.voided.as_ref().is_some_and(|v| v.reason.contains(keyword))
+    }
+}
+
+/// 按筛选条件过滤记录并排序
+pub fn query_records<'a>(
+    records: &'a [DrawRecord],
+    filter: &RecordFilter,
+    sort_column: SortColumn,
+    ascending: bool,
+) -> Vec<&'a DrawRecord> {
+    let mut result: Vec<&DrawRecord> = records.iter().filter(|r| filter.matches(r)).collect();
+    
+    // 稳定排序，同值时保持时间顺序
+    result.sort_by(|a, b| {
+        let ordering = match sort_column {
+            SortColumn::Time => a.timestamp.cmp(&b.timestamp),
+            SortColumn::Round => a.round.cmp(&b.round),
+            SortColumn::Target => a.target_department_name.cmp(&b.target_department_name),
+            SortColumn::Specialty => a.specialty_label().cmp(b.specialty_label()),
+            SortColumn::Inspecting => a.selected_from_department_name.cmp(&b.selected_from_department_name),
+        };
+        if ascending { ordering } else { ordering.reverse() }
+    });
+    
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Local};
+    use crate::models::default_specialties;
+    
+    fn create_test_records() -> Vec<DrawRecord> {
+        let specialties = default_specialties();
+        let mut first = DrawRecord::new("nd", "宁东分院", &specialties[0], "szs", "石嘴山分院", "szs", "石嘴山分院")
+            .with_draw_info(1, 7, vec!["石嘴山分院".to_string()], vec!["排除 宁东分院：被检查部门本身".to_string()]);
+        first.timestamp = Local::now() - Duration::days(10);
+        let second = DrawRecord::new("wz", "吴忠分院", &specialties[1], "jd1", "机电特种设备一部", "jd1", "机电特种设备一部")
+            .with_draw_info(2, 8, Vec::new(), Vec::new());
+        let third = DrawRecord::new("cy1", "承压特种设备一部", &specialties[0], "nd", "宁东分院", "nd", "宁东分院")
+            .with_draw_info(2, 9, Vec::new(), Vec::new());
+        vec![first, second, third]
+    }
+    
+    #[test]
+    fn test_filter_by_fields() {
+        let records = create_test_records();
+        
+        let filter = RecordFilter {
+            round: Some(2),
+            specialty: Some("Pressure".to_string()),
+            ..Default::default()
+        };
+        let result = query_records(&records, &filter, SortColumn::Time, true);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].target_department_id, "cy1");
+        
+        let filter = RecordFilter {
+            inspecting_department_id: Some("szs".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(query_records(&records, &filter, SortColumn::Time, true).len(), 1);
+    }
+    
+    #[test]
+    fn test_filter_by_date_and_search() {
+        let records = create_test_records();
+        let today = Local::now().date_naive();
+        
+        let filter = RecordFilter {
+            date_from: Some(today - Duration::days(1)),
+            ..Default::default()
+        };
+        assert_eq!(query_records(&records, &filter, SortColumn::Time, true).len(), 2);
+        
+        // 关键字同时匹配部门名称和规则说明
+        let filter = RecordFilter {
+            search: "宁东".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(query_records(&records, &filter, SortColumn::Time, true).len(), 2);
+    }
+    
+    #[test]
+    fn test_archived_hidden_by_default() {
+        let mut records = create_test_records();
+        records[0].archived = true;
+        
+        assert_eq!(query_records(&records, &RecordFilter::default(), SortColumn::Time, true).len(), 2);
+        
+        let filter = RecordFilter {
+            include_archived: true,
+            ..Default::default()
+        };
+        assert_eq!(query_records(&records, &filter, SortColumn::Time, true).len(), 3);
+    }
+    
+    #[test]
+    fn test_sort_descending() {
+        let records = create_test_records();
+        let result = query_records(&records, &RecordFilter::default(), SortColumn::Round, false);
+        assert_eq!(result.first().map(|r| r.round), Some(2));
+        assert_eq!(result.last().map(|r| r.round), Some(1));
+    }
+}
@@ -0,0 +1,22 @@
+//! 业务逻辑模块
+
+mod draw;
+mod history;
+mod auth;
+mod schedule;
+mod outcome;
+mod stats;
+mod simulation;
+mod catalog;
+
+pub use draw::{DepartmentDraw, DrawEngine, DrawError};
+pub use auth::AuthManager;
+pub use catalog::{CatalogManager, MAX_TEAM_SIZE};
+pub use history::{RecordFilter, SortColumn, query_records};
+pub use schedule::{ScheduleConflict, ScheduleItem, ScheduleManager};
+pub use outcome::{OutcomeItem, OutcomeManager, OutcomeSummary};
+pub use simulation::{DepartmentLoad, PairFrequency, SimulationReport, Simulator};
+pub use stats::{
+    DepartmentCount, DrawStatistics, FairnessTest, QuarterTrend, SpecialtyStatistics, StatsManager, SIGNIFICANCE_LEVEL,
+    chi_square_p_value,
+};
@@ -0,0 +1,170 @@
+//! 检查结果跟踪：未完成检查看板和年度汇总
+
+use chrono::Datelike;
+use crate::i18n::tr;
+use crate::models::{DrawRecord, FindingCounts, InspectionOutcome, InspectionStatus};
+
+/// 检查结果管理器
+pub struct OutcomeManager;
+
+/// 一条有效抽签记录及其检查结果
+#[derive(Debug, Clone, Copy)]
+pub struct OutcomeItem<'a> {
+    /// 抽签记录
+    pub record: &'a DrawRecord,
+    /// 检查结果（尚未登记为 None）
+    pub outcome: Option<&'a InspectionOutcome>,
+}
+
+impl OutcomeItem<'_> {
+    /// 检查状态（尚未登记的视为计划中）
+    pub fn status(&self) -> InspectionStatus {
+        self.outcome.map(|o| o.status).unwrap_or_default()
+    }
+}
+
+/// 检查结果汇总（整体或单个被检部门）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutcomeSummary {
+    /// 汇总对象名称（整体汇总为"合计"）
+    pub name: String,
+    /// 检查项数
+    pub total: usize,
+    /// 计划中
+    pub planned: usize,
+    /// 检查中
+    pub in_progress: usize,
+    /// 已完成
+    pub done: usize,
+    /// 问题数合计
+    pub findings: FindingCounts,
+    /// 平均评分（只统计已评分的检查）
+    pub average_score: Option<f32>,
+}
+
+impl OutcomeSummary {
+    fn from_items<'a>(name: impl Into<String>, items: impl IntoIterator<Item = &'a OutcomeItem<'a>>) -> Self {
+        let mut summary = OutcomeSummary { name: name.into(), ..Default::default() };
+        let mut scores = Vec::new();
+        for item in items {
+            summary.total += 1;
+            match item.status() {
+                InspectionStatus::Planned => summary.planned += 1,
+                InspectionStatus::InProgress => summary.in_progress += 1,
+                InspectionStatus::Done => summary.done += 1,
+            }
+            if let Some(outcome) = item.outcome {
+                summary.findings.add(&outcome.findings);
+                scores.extend(outcome.score);
+            }
+        }
+        if !scores.is_empty() {
+            summary.average_score = Some(scores.iter().sum::<f32>() / scores.len() as f32);
+        }
+        summary
+    }
+}
+
+impl OutcomeManager {
+    /// 未完成的检查（计划中和检查中），按轮次和被检部门排序
+    pub fn open_items<'a>(records: &'a [DrawRecord], outcomes: &'a [InspectionOutcome]) -> Vec<OutcomeItem<'a>> {
+        let mut items: Vec<OutcomeItem> = Self::items(records, outcomes)
+            .filter(|item| item.status() != InspectionStatus::Done)
+            .collect();
+        items.sort_by(|a, b| {
+            a.record.round.cmp(&b.record.round)
+                .then_with(|| a.record.target_department_name.cmp(&b.record.target_department_name))
+        });
+        items
+    }
+
+    /// 某一年抽签的全部检查（按抽签时间排序），用于年度质量报告
+    pub fn year_items<'a>(records: &'a [DrawRecord], outcomes: &'a [InspectionOutcome], year: i32) -> Vec<OutcomeItem<'a>> {
+        let mut items: Vec<OutcomeItem> = Self::items(records, outcomes)
+            .filter(|item| item.record.timestamp.year() == year)
+            .collect();
+        items.sort_by_key(|item| item.record.timestamp);
+        items
+    }
+
+    /// 有抽签记录的年份（从新到旧）
+    pub fn years(records: &[DrawRecord]) -> Vec<i32> {
+        let mut years: Vec<i32> = records.iter().filter(|r| !r.is_voided()).map(|r| r.timestamp.year()).collect();
+        years.sort_unstable_by(|a, b| b.cmp(a));
+        years.dedup();
+        years
+    }
+
+    /// 汇总：第一项为合计，其后按被检部门逐项汇总
+    pub fn summarize(items: &[OutcomeItem]) -> Vec<OutcomeSummary> {
+        let mut departments: Vec<&str> = Vec::new();
+        for item in items {
+            if !departments.contains(&item.record.target_department_name.as_str()) {
+                departments.push(&item.record.target_department_name);
+            }
+        }
+        departments.sort_unstable();
+
+        let mut summaries = vec![OutcomeSummary::from_items(tr("合计"), items)];
+        summaries.extend(departments.into_iter().map(|name| {
+            OutcomeSummary::from_items(name, items.iter().filter(|item| item.record.target_department_name == name))
+        }));
+        summaries
+    }
+
+    /// 有效记录及其检查结果（作废的记录不跟踪检查结果）
+    fn items<'a>(records: &'a [DrawRecord], outcomes: &'a [InspectionOutcome]) -> impl Iterator<Item = OutcomeItem<'a>> {
+        records.iter()
+            .filter(|r| !r.is_voided())
+            .map(move |record| OutcomeItem {
+                record,
+                outcome: outcomes.iter().find(|o| o.record_id == record.id),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use crate::models::default_specialties;
+
+    #[test]
+    fn test_open_items_and_summary() {
+        let specialties = default_specialties();
+        let records = vec![
+            DrawRecord::test_record("宁东分院", &specialties[0], "石嘴山分院", 1),
+            DrawRecord::test_record("宁东分院", &specialties[0], "固原分院", 1),
+            DrawRecord::test_record("吴忠分院", &specialties[0], "石嘴山分院", 1),
+        ];
+
+        let mut done = InspectionOutcome::new(&records[0].id);
+        done.status = InspectionStatus::Done;
+        done.findings = FindingCounts { major: 1, general: 2, minor: 3 };
+        done.score = Some(90.0);
+        assert!(done.validate().is_err());
+        done.closed_on = NaiveDate::from_ymd_opt(2026, 11, 20);
+        assert!(done.validate().is_ok());
+
+        let mut in_progress = InspectionOutcome::new(&records[1].id);
+        in_progress.status = InspectionStatus::InProgress;
+        in_progress.findings.general = 1;
+        in_progress.score = Some(80.0);
+        let outcomes = vec![done, in_progress];
+
+        // 未登记结果的记录视为计划中，已完成的不在看板中
+        let open = OutcomeManager::open_items(&records, &outcomes);
+        assert_eq!(open.len(), 2);
+        assert_eq!(open[0].record.target_department_name, "吴忠分院");
+        assert_eq!(open[0].status(), InspectionStatus::Planned);
+        assert_eq!(open[1].status(), InspectionStatus::InProgress);
+
+        let year = records[0].timestamp.year();
+        let summaries = OutcomeManager::summarize(&OutcomeManager::year_items(&records, &outcomes, year));
+        assert_eq!(summaries.len(), 3);
+        assert_eq!((summaries[0].total, summaries[0].done, summaries[0].in_progress, summaries[0].planned), (3, 1, 1, 1));
+        assert_eq!(summaries[0].findings.total(), 7);
+        assert_eq!(summaries[0].average_score, Some(85.0));
+        assert!(OutcomeManager::year_items(&records, &outcomes, year - 1).is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+//! 检查日程安排：按轮次整理安排、检测冲突
+
+use std::collections::BTreeMap;
+use chrono::NaiveDate;
+use crate::i18n::{tr, trf};
+use crate::models::{DrawRecord, ScheduleEntry};
+
+/// 检查日程管理器
+pub struct ScheduleManager;
+
+/// 一条有效抽签记录及其检查安排
+#[derive(Debug, Clone, Copy)]
+pub struct ScheduleItem<'a> {
+    /// 抽签记录
+    pub record: &'a DrawRecord,
+    /// 检查安排（尚未安排为 None）
+    pub entry: Option<&'a ScheduleEntry>,
+}
+
+/// 日程冲突：同一检查部门同一天被安排去检查不同的被检部门
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleConflict {
+    /// 冲突日期
+    pub date: NaiveDate,
+    /// 检查部门名称
+    pub department_name: String,
+    /// 当天被安排检查的被检部门
+    pub targets: Vec<String>,
+    /// 涉及的抽签记录ID
+    pub record_ids: Vec<String>,
+}
+
+impl ScheduleConflict {
+    /// 冲突说明
+    pub fn description(&self) -> String {
+        trf(
+            "{} {}同一天安排检查{}",
+            &[&self.date.format("%Y-%m-%d"), &self.department_name, &self.targets.join(tr("、"))],
+        )
+    }
+}
+
+impl ScheduleManager {
+    /// 某一轮的有效记录及其安排
+    ///
+    /// 已安排的按日期和时段排在前面，未安排的按被检部门排在后面
+    pub fn round_items<'a>(records: &'a [DrawRecord], schedule: &'a [ScheduleEntry], round: u32) -> Vec<ScheduleItem<'a>> {
+        let mut items: Vec<ScheduleItem> = records.iter()
+            .filter(|r| r.round == round && !r.is_voided())
+            .map(|record| ScheduleItem {
+                record,
+                entry: schedule.iter().find(|e| e.record_id == record.id),
+            })
+            .collect();
+        items.sort_by(|a, b| {
+            let key = |item: &ScheduleItem| item.entry.map(|e| (e.date, e.slot));
+            match (key(a), key(b)) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }
+            .then_with(|| a.record.target_department_name.cmp(&b.record.target_department_name))
+        });
+        items
+    }
+
+    /// 检测日程冲突
+    ///
+    /// 检查部门同一天只能去一个被检部门；同一被检部门的多个专业安排在同一天视为一次检查，不算冲突。
+    /// 作废的记录不参与检测
+    pub fn conflicts(records: &[DrawRecord], schedule: &[ScheduleEntry]) -> Vec<ScheduleConflict> {
+        // (日期, 检查部门ID) → 当天的记录
+        let mut by_day: BTreeMap<(NaiveDate, &str), Vec<&DrawRecord>> = BTreeMap::new();
+        for entry in schedule {
+            let Some(record) = records.iter().find(|r| r.id == entry.record_id && !r.is_voided()) else {
+                continue;
+            };
+            by_day.entry((entry.date, record.selected_from_department_id.as_str()))
+                .or_default()
+                .push(record);
+        }
+
+        by_day.into_iter()
+            .filter_map(|((date, _), day_records)| {
+                let mut targets: Vec<String> = Vec::new();
+                for record in &day_records {
+                    if !targets.contains(&record.target_department_name) {
+                        targets.push(record.target_department_name.clone());
+                    }
+                }
+                (targets.len() > 1).then(|| ScheduleConflict {
+                    date,
+                    department_name: day_records[0].selected_from_department_name.clone(),
+                    targets,
+                    record_ids: day_records.iter().map(|r| r.id.clone()).collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TimeSlot, VoidInfo, default_specialties};
+
+    #[test]
+    fn test_conflicts() {
+        let specialties = default_specialties();
+        let record = |target: &str, specialty, selected: &str| DrawRecord::test_record(target, specialty, selected, 1);
+        let mut records = vec![
+            record("宁东分院", &specialties[0], "石嘴山分院"),
+            record("宁东分院", &specialties[1], "石嘴山分院"),
+            record("吴忠分院", &specialties[0], "石嘴山分院"),
+            record("中卫分院", &specialties[0], "固原分院"),
+        ];
+        let day = NaiveDate::from_ymd_opt(2026, 11, 3).unwrap();
+        let schedule: Vec<ScheduleEntry> = records.iter()
+            .map(|r| ScheduleEntry::new(&r.id, day, TimeSlot::Morning))
+            .collect();
+
+        // 石嘴山分院同一天检查宁东分院和吴忠分院，宁东分院的两个专业不算冲突
+        let conflicts = ScheduleManager::conflicts(&records, &schedule);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].department_name, "石嘴山分院");
+        assert_eq!(conflicts[0].targets, ["宁东分院", "吴忠分院"]);
+        assert_eq!(conflicts[0].record_ids.len(), 3);
+
+        // 作废的记录不参与检测
+        records[2].voided = Some(VoidInfo {
+            reason: "测试".to_string(),
+            operator: "管理员".to_string(),
+            operator_id: None,
+            timestamp: chrono::Local::now(),
+        });
+        assert!(ScheduleManager::conflicts(&records, &schedule).is_empty());
+
+        // 已安排的排在未安排的前面
+        let items = ScheduleManager::round_items(&records, &schedule[..1], 1);
+        assert_eq!(items.len(), 3);
+        assert!(items[0].entry.is_some() && items[1].entry.is_none());
+    }
+}
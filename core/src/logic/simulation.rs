@@ -0,0 +1,256 @@
+//! 抽签规则模拟（蒙特卡洛）
+//!
+//! 调整回避规则前，用当前部门配置和候选规则按种子模拟大量虚拟轮次：
+//! 每轮按随机顺序为每个被检部门的每个专业抽签（与命令行抽签相同的候选规则和种子算法），
+//! 统计无法抽完的轮次比例、各部门检查任务的均衡程度和相邻轮次的配对重复率。
+
+use std::collections::{BTreeMap, BTreeSet};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::Serialize;
+use crate::models::{Department, DrawRules, Specialty, specialty_name};
+use super::DrawEngine;
+
+/// 报告中列出的最常见死局原因和高频配对条数
+const TOP_ENTRIES: usize = 5;
+
+/// 规则模拟器
+pub struct Simulator;
+
+/// 模拟结果
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationReport {
+    /// 模拟使用的规则
+    pub rules: DrawRules,
+    /// 模拟轮数
+    pub rounds: usize,
+    /// 随机种子
+    pub seed: u64,
+    /// 每轮的抽取次数（被检部门 × 需要的专业）
+    pub draws_per_round: usize,
+    /// 至少有一次抽取无法完成的轮数
+    pub dead_end_rounds: usize,
+    /// 死局率：无法抽完的轮数 / 模拟轮数
+    pub dead_end_rate: f64,
+    /// 无法完成的抽取次数
+    pub failed_draws: usize,
+    /// 最常见的无法完成原因及次数（按次数从多到少）
+    pub dead_end_reasons: Vec<(String, usize)>,
+    /// 各部门每轮被抽中去检查的次数
+    pub loads: Vec<DepartmentLoad>,
+    /// 各部门平均每轮检查次数之间的方差，越大说明任务越不均衡
+    pub load_variance: f64,
+    /// 配对重复率：每轮的 (被检部门, 专业, 检查部门) 配对中与上一轮相同的比例
+    pub pair_repeat_rate: f64,
+    /// 出现最频繁的配对
+    pub top_pairs: Vec<PairFrequency>,
+}
+
+/// 部门的检查任务量
+#[derive(Debug, Clone, Serialize)]
+pub struct DepartmentLoad {
+    /// 部门名称
+    pub name: String,
+    /// 平均每轮检查次数
+    pub mean: f64,
+    /// 每轮检查次数的方差
+    pub variance: f64,
+    /// 单轮最多检查次数
+    pub max: usize,
+}
+
+/// 配对出现频率
+#[derive(Debug, Clone, Serialize)]
+pub struct PairFrequency {
+    /// 被检部门名称
+    pub target: String,
+    /// 专业名称
+    pub specialty: String,
+    /// 检查部门名称
+    pub inspector: String,
+    /// 出现该配对的轮数占比
+    pub rate: f64,
+}
+
+impl Simulator {
+    /// 按种子模拟 `rounds` 轮抽签，相同的部门配置、规则和种子结果相同
+    pub fn run(
+        departments: &[Department],
+        specialties: &[Specialty],
+        rules: &DrawRules,
+        rounds: usize,
+        seed: u64,
+    ) -> SimulationReport {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        // 每轮需要完成的抽取：(被检部门, 专业)
+        let tasks: Vec<(&Department, Specialty)> = departments.iter()
+            .flat_map(|d| d.needs.iter().map(move |s| (d, s)))
+            .map(|(d, id)| {
+                let specialty = specialties.iter()
+                    .find(|s| &s.id == id)
+                    .cloned()
+                    .unwrap_or_else(|| Specialty::new(id, specialty_name(specialties, id)));
+                (d, specialty)
+            })
+            .collect();
+
+        let mut dead_end_rounds = 0;
+        let mut failed_draws = 0;
+        let mut reasons: BTreeMap<String, usize> = BTreeMap::new();
+        // 部门ID → 每轮检查次数
+        let mut loads: BTreeMap<&str, Vec<usize>> = departments.iter()
+            .filter(|d| !d.supplies.is_empty())
+            .map(|d| (d.id.as_str(), Vec::with_capacity(rounds)))
+            .collect();
+        let mut pair_counts: BTreeMap<(String, String, String), usize> = BTreeMap::new();
+        let mut previous: BTreeSet<(String, String, String)> = BTreeSet::new();
+        let mut repeated = 0;
+        let mut compared = 0;
+
+        for round in 0..rounds {
+            let mut order: Vec<usize> = (0..tasks.len()).collect();
+            order.shuffle(&mut rng);
+
+            // 专业ID → 本轮已抽中的 (被检部门ID, 抽中部门ID)
+            let mut current_round: BTreeMap<&str, Vec<(String, String)>> = BTreeMap::new();
+            let mut failed = false;
+            for index in order {
+                let (target, specialty) = &tasks[index];
+                let pairs = current_round.entry(specialty.id.as_str()).or_default();
+                match DrawEngine::draw_department(departments, &target.id, specialty, pairs, rules, rng.gen()) {
+                    Ok(draw) => {
+                        pairs.extend(draw.selected.into_iter().map(|d| (target.id.clone(), d.id)));
+                    }
+                    Err(e) => {
+                        failed = true;
+                        failed_draws += 1;
+                        *reasons.entry(e.to_string()).or_default() += 1;
+                    }
+                }
+            }
+            if failed {
+                dead_end_rounds += 1;
+            }
+
+            let pairs: BTreeSet<(String, String, String)> = current_round.iter()
+                .flat_map(|(specialty, pairs)| {
+                    pairs.iter().map(move |(t, s)| (t.clone(), specialty.to_string(), s.clone()))
+                })
+                .collect();
+            for (id, counts) in loads.iter_mut() {
+                counts.push(pairs.iter().filter(|(_, _, s)| s == id).count());
+            }
+            if round > 0 {
+                repeated += pairs.intersection(&previous).count();
+                compared += pairs.len();
+            }
+            for pair in &pairs {
+                *pair_counts.entry(pair.clone()).or_default() += 1;
+            }
+            previous = pairs;
+        }
+
+        let name = |id: &str| departments.iter().find(|d| d.id == id).map(|d| d.name.clone()).unwrap_or_else(|| id.to_string());
+        let loads: Vec<DepartmentLoad> = loads.into_iter()
+            .map(|(id, counts)| {
+                let (mean, variance) = mean_variance(counts.iter().map(|&c| c as f64));
+                DepartmentLoad { name: name(id), mean, variance, max: counts.into_iter().max().unwrap_or(0) }
+            })
+            .collect();
+        let (_, load_variance) = mean_variance(loads.iter().map(|l| l.mean));
+
+        let mut reasons: Vec<(String, usize)> = reasons.into_iter().collect();
+        reasons.sort_by_key(|r| std::cmp::Reverse(r.1));
+        reasons.truncate(TOP_ENTRIES);
+
+        let mut top_pairs: Vec<((String, String, String), usize)> = pair_counts.into_iter().collect();
+        top_pairs.sort_by_key(|p| std::cmp::Reverse(p.1));
+        let top_pairs = top_pairs.into_iter()
+            .take(TOP_ENTRIES)
+            .map(|((target, specialty, inspector), count)| PairFrequency {
+                target: name(&target),
+                specialty: specialty_name(specialties, &specialty).to_string(),
+                inspector: name(&inspector),
+                rate: ratio(count, rounds),
+            })
+            .collect();
+
+        SimulationReport {
+            rules: *rules,
+            rounds,
+            seed,
+            draws_per_round: tasks.len(),
+            dead_end_rounds,
+            dead_end_rate: ratio(dead_end_rounds, rounds),
+            failed_draws,
+            dead_end_reasons: reasons,
+            loads,
+            load_variance,
+            pair_repeat_rate: ratio(repeated, compared),
+            top_pairs,
+        }
+    }
+}
+
+/// 比例（分母为 0 时为 0）
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 { 0.0 } else { numerator as f64 / denominator as f64 }
+}
+
+/// 均值和总体方差
+fn mean_variance(values: impl Iterator<Item = f64> + Clone) -> (f64, f64) {
+    let count = values.clone().count();
+    if count == 0 {
+        return (0.0, 0.0);
+    }
+    let mean = values.clone().sum::<f64>() / count as f64;
+    let variance = values.map(|v| (v - mean).powi(2)).sum::<f64>() / count as f64;
+    (mean, variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{default_departments, default_specialties};
+
+    #[test]
+    fn test_simulation() {
+        let departments = default_departments();
+        let specialties = default_specialties();
+        let rules = DrawRules::default();
+        let report = Simulator::run(&departments, &specialties, &rules, 200, 7);
+
+        // 相同种子结果相同
+        let again = Simulator::run(&departments, &specialties, &rules, 200, 7);
+        assert_eq!(report.dead_end_rounds, again.dead_end_rounds);
+        assert_eq!(report.pair_repeat_rate, again.pair_repeat_rate);
+
+        assert_eq!(report.draws_per_round, 15);
+        assert_eq!(report.loads.len(), departments.len());
+        assert!((0.0..=1.0).contains(&report.dead_end_rate));
+        assert!((0.0..=1.0).contains(&report.pair_repeat_rate));
+        // 每轮没有失败的抽取都落在某个部门上
+        let total: f64 = report.loads.iter().map(|l| l.mean).sum();
+        let expected = (report.draws_per_round * 200 - report.failed_draws) as f64 / 200.0;
+        assert!((total - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dead_end() {
+        // 两个部门只能互相检查：先抽的一方抽中对方后，交叉回避让另一方无部门可抽
+        let departments = vec![
+            Department::new("a", "甲", "综合类", &["Pressure"]),
+            Department::new("b", "乙", "综合类", &["Pressure"]),
+        ];
+        let specialties = default_specialties();
+        let strict = Simulator::run(&departments, &specialties, &DrawRules::default(), 50, 1);
+        assert_eq!(strict.dead_end_rate, 1.0);
+        assert_eq!(strict.failed_draws, 50);
+
+        let relaxed = DrawRules { once_per_round: true, cross_avoidance: false };
+        let report = Simulator::run(&departments, &specialties, &relaxed, 50, 1);
+        assert_eq!(report.dead_end_rate, 0.0);
+        assert_eq!(report.pair_repeat_rate, 1.0);
+    }
+}
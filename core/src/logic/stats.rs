@@ -0,0 +1,346 @@
+//! 抽签历史统计：各部门被检和检查次数、配对频次、公平性检验和季度趋势
+//!
+//! 统计全部有效记录（含已归档，不含作废）。公平性检验中每次抽取在其候选名单内等概率，
+//! 所以部门的期望次数为其出现过的每次抽取的 1/候选数 之和；没有候选名单的旧记录不参与检验。
+
+use chrono::Datelike;
+use serde::Serialize;
+use crate::i18n::{tr, trf};
+use crate::models::{Department, DrawRecord, Specialty};
+
+/// 统计管理器
+pub struct StatsManager;
+
+/// 显著性水平：p 值低于此值认为分布与等概率期望有显著差异
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// 抽签历史统计结果
+#[derive(Debug, Clone, Serialize)]
+pub struct DrawStatistics {
+    /// 有效记录数
+    pub total_draws: usize,
+    /// 按专业统计
+    pub specialties: Vec<SpecialtyStatistics>,
+    /// 季度趋势（按时间先后）
+    pub quarters: Vec<QuarterTrend>,
+}
+
+/// 单个专业的统计
+#[derive(Debug, Clone, Serialize)]
+pub struct SpecialtyStatistics {
+    /// 专业ID
+    pub specialty: String,
+    /// 专业名称
+    pub specialty_name: String,
+    /// 该专业的有效记录数
+    pub draws: usize,
+    /// 各部门次数（按部门配置顺序）
+    pub departments: Vec<DepartmentCount>,
+    /// 配对次数：`pairs[i][j]` 为 `departments[i]` 被 `departments[j]` 检查的次数
+    pub pairs: Vec<Vec<usize>>,
+    /// 公平性检验
+    pub fairness: FairnessTest,
+}
+
+/// 部门在某一专业中的次数
+#[derive(Debug, Clone, Serialize)]
+pub struct DepartmentCount {
+    /// 部门名称
+    pub name: String,
+    /// 作为被检部门的次数
+    pub as_target: usize,
+    /// 作为检查部门被抽中的次数
+    pub as_inspector: usize,
+    /// 等概率抽取时作为检查部门的期望次数（只统计有候选名单的记录）
+    pub expected: f64,
+}
+
+/// 卡方公平性检验：检查部门被抽中次数与等概率期望是否一致
+#[derive(Debug, Clone, Serialize)]
+pub struct FairnessTest {
+    /// 参与检验的记录数（有候选名单的记录）
+    pub draws: usize,
+    /// 卡方统计量
+    pub chi_square: f64,
+    /// 自由度（期望次数大于 0 的部门数 - 1）
+    pub degrees_of_freedom: usize,
+    /// p 值（自由度为 0 时无法检验）
+    pub p_value: Option<f64>,
+    /// 存在期望次数小于 5 的部门，样本偏少，检验结果仅供参考
+    pub low_expectation: bool,
+}
+
+impl FairnessTest {
+    /// 是否没有显著差异（无法检验时为 None）
+    pub fn is_fair(&self) -> Option<bool> {
+        self.p_value.map(|p| p >= SIGNIFICANCE_LEVEL)
+    }
+
+    /// 检验结论
+    pub fn conclusion(&self) -> String {
+        let mut text = match self.is_fair() {
+            None => tr("样本不足，无法检验").to_string(),
+            Some(true) => tr("与等概率期望无显著差异").to_string(),
+            Some(false) => trf("与等概率期望存在显著差异（p < {}）", &[&SIGNIFICANCE_LEVEL]),
+        };
+        if self.low_expectation && self.p_value.is_some() {
+            text.push_str(tr("，部分部门期望次数小于 5，仅供参考"));
+        }
+        text
+    }
+}
+
+/// 一个季度的抽签次数
+#[derive(Debug, Clone, Serialize)]
+pub struct QuarterTrend {
+    /// 季度，如"2026年第4季度"
+    pub label: String,
+    /// 有效记录数
+    pub draws: usize,
+    /// 各专业的记录数（与 `DrawStatistics::specialties` 顺序一致）
+    pub by_specialty: Vec<usize>,
+}
+
+impl StatsManager {
+    /// 统计抽签历史
+    pub fn compute(records: &[DrawRecord], departments: &[Department], specialties: &[Specialty]) -> DrawStatistics {
+        let valid: Vec<&DrawRecord> = records.iter().filter(|r| !r.is_voided()).collect();
+
+        // 专业顺序：配置的专业在前，其后是只在旧记录中出现的专业
+        let mut specialty_ids: Vec<(String, String)> = specialties.iter()
+            .filter(|s| valid.iter().any(|r| r.specialty_type == s.id))
+            .map(|s| (s.id.clone(), s.name.clone()))
+            .collect();
+        for record in &valid {
+            if !specialty_ids.iter().any(|(id, _)| id == &record.specialty_type) {
+                specialty_ids.push((record.specialty_type.clone(), record.specialty_label().to_string()));
+            }
+        }
+
+        let specialty_stats: Vec<SpecialtyStatistics> = specialty_ids.iter()
+            .map(|(id, name)| {
+                let records: Vec<&DrawRecord> = valid.iter().copied().filter(|r| &r.specialty_type == id).collect();
+                Self::specialty_statistics(id, name, &records, departments)
+            })
+            .collect();
+
+        let mut quarters: Vec<((i32, u32), QuarterTrend)> = Vec::new();
+        for record in &valid {
+            let key = (record.timestamp.year(), (record.timestamp.month() - 1) / 3 + 1);
+            let index = match quarters.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    quarters.push((key, QuarterTrend {
+                        label: trf("{}年第{}季度", &[&key.0, &key.1]),
+                        draws: 0,
+                        by_specialty: vec![0; specialty_ids.len()],
+                    }));
+                    quarters.len() - 1
+                }
+            };
+            let trend = &mut quarters[index].1;
+            trend.draws += 1;
+            if let Some(s) = specialty_ids.iter().position(|(id, _)| id == &record.specialty_type) {
+                trend.by_specialty[s] += 1;
+            }
+        }
+        quarters.sort_by_key(|(key, _)| *key);
+
+        DrawStatistics {
+            total_draws: valid.len(),
+            specialties: specialty_stats,
+            quarters: quarters.into_iter().map(|(_, trend)| trend).collect(),
+        }
+    }
+
+    /// 单个专业的次数、配对和公平性检验
+    fn specialty_statistics(id: &str, name: &str, records: &[&DrawRecord], departments: &[Department]) -> SpecialtyStatistics {
+        // 部门顺序：按部门配置顺序，其后是已删除或改名的部门
+        let mut names: Vec<String> = Vec::new();
+        let mentioned = |n: &str| records.iter().any(|r| {
+            r.target_department_name == n || r.selected_from_department_name == n || r.candidates.iter().any(|c| c == n)
+        });
+        for department in departments {
+            if mentioned(&department.name) {
+                names.push(department.name.clone());
+            }
+        }
+        for record in records {
+            for n in [&record.target_department_name, &record.selected_from_department_name].into_iter().chain(&record.candidates) {
+                if !names.contains(n) {
+                    names.push(n.clone());
+                }
+            }
+        }
+
+        let index = |n: &str| names.iter().position(|x| x == n).expect("部门已收集");
+        let mut counts: Vec<DepartmentCount> = names.iter()
+            .map(|n| DepartmentCount { name: n.clone(), as_target: 0, as_inspector: 0, expected: 0.0 })
+            .collect();
+        let mut pairs = vec![vec![0; names.len()]; names.len()];
+        let mut observed = vec![0usize; names.len()];
+        let mut tested = 0;
+
+        for record in records {
+            let target = index(&record.target_department_name);
+            let inspector = index(&record.selected_from_department_name);
+            counts[target].as_target += 1;
+            counts[inspector].as_inspector += 1;
+            pairs[target][inspector] += 1;
+
+            if !record.candidates.is_empty() {
+                tested += 1;
+                observed[inspector] += 1;
+                let share = 1.0 / record.candidates.len() as f64;
+                for candidate in &record.candidates {
+                    counts[index(candidate)].expected += share;
+                }
+            }
+        }
+
+        let expected: Vec<f64> = counts.iter().map(|c| c.expected).collect();
+        SpecialtyStatistics {
+            specialty: id.to_string(),
+            specialty_name: name.to_string(),
+            draws: records.len(),
+            departments: counts,
+            pairs,
+            fairness: Self::chi_square_test(&observed, &expected, tested),
+        }
+    }
+
+    /// 卡方拟合优度检验（只计入期望次数大于 0 的部门）
+    fn chi_square_test(observed: &[usize], expected: &[f64], draws: usize) -> FairnessTest {
+        let cells: Vec<(f64, f64)> = observed.iter()
+            .zip(expected)
+            .filter(|(_, e)| **e > 0.0)
+            .map(|(o, e)| (*o as f64, *e))
+            .collect();
+        let chi_square: f64 = cells.iter().map(|(o, e)| (o - e).powi(2) / e).sum();
+        let degrees_of_freedom = cells.len().saturating_sub(1);
+        FairnessTest {
+            draws,
+            chi_square,
+            degrees_of_freedom,
+            p_value: (degrees_of_freedom > 0).then(|| chi_square_p_value(chi_square, degrees_of_freedom)),
+            low_expectation: cells.iter().any(|(_, e)| *e < 5.0),
+        }
+    }
+}
+
+/// 卡方分布的上侧概率 P(X ≥ x)
+pub fn chi_square_p_value(x: f64, degrees_of_freedom: usize) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    upper_regularized_gamma(degrees_of_freedom as f64 / 2.0, x / 2.0)
+}
+
+/// 正则化上不完全伽马函数 Q(a, x)：x < a + 1 时用级数展开，否则用连分式
+fn upper_regularized_gamma(a: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-12;
+    const MAX_ITERATIONS: usize = 500;
+    let log_prefix = a * x.ln() - x - ln_gamma(a);
+
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (1.0 - sum * log_prefix.exp()).clamp(0.0, 1.0)
+    } else {
+        // Lentz 算法
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..MAX_ITERATIONS {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        (log_prefix.exp() * h).clamp(0.0, 1.0)
+    }
+}
+
+/// ln Γ(x)（Lanczos 近似，x > 0）
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.001_208_650_973_866_179,
+        -0.000_005_395_239_384_953,
+    ];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let mut series = 1.000_000_000_190_015;
+    for (i, c) in COEFFICIENTS.iter().enumerate() {
+        series += c / (x + 1.0 + i as f64);
+    }
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::default_specialties;
+
+    #[test]
+    fn test_chi_square_p_value() {
+        assert!((chi_square_p_value(3.841, 1) - 0.05).abs() < 1e-3);
+        assert!((chi_square_p_value(11.07, 5) - 0.05).abs() < 1e-3);
+        assert!((chi_square_p_value(2.0, 2) - (-1.0f64).exp()).abs() < 1e-9);
+        assert_eq!(chi_square_p_value(0.0, 3), 1.0);
+    }
+
+    #[test]
+    fn test_compute() {
+        let specialties = default_specialties();
+        let candidates = vec!["甲".to_string(), "乙".to_string()];
+        let record = |target: &str, selected: &str| DrawRecord {
+            candidates: candidates.clone(),
+            ..DrawRecord::test_record(target, &specialties[0], selected, 1)
+        };
+        // 40 次抽取全部抽中甲：明显不公平
+        let records: Vec<DrawRecord> = (0..40).map(|_| record("丙", "甲")).collect();
+        let stats = StatsManager::compute(&records, &[], &specialties);
+        assert_eq!(stats.total_draws, 40);
+        assert_eq!(stats.specialties.len(), 1);
+        assert_eq!(stats.quarters.len(), 1);
+
+        let s = &stats.specialties[0];
+        let names: Vec<&str> = s.departments.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["丙", "甲", "乙"]);
+        assert_eq!(s.pairs[0][1], 40);
+        assert_eq!(s.departments[1].expected, 20.0);
+        assert_eq!(s.fairness.degrees_of_freedom, 1);
+        assert_eq!(s.fairness.chi_square, 40.0);
+        assert_eq!(s.fairness.is_fair(), Some(false));
+
+        // 均匀分布时无显著差异
+        let records: Vec<DrawRecord> = (0..40).map(|i| record("丙", if i % 2 == 0 { "甲" } else { "乙" })).collect();
+        let stats = StatsManager::compute(&records, &[], &specialties);
+        assert_eq!(stats.specialties[0].fairness.chi_square, 0.0);
+        assert_eq!(stats.specialties[0].fairness.is_fair(), Some(true));
+    }
+}
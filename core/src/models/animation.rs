@@ -0,0 +1,193 @@
+//! 转盘动画设置模型
+//!
+//! 动画方案决定转速和减速时长，减速曲线决定停下的节奏，自动停止和提示音可分别开启。
+//! 动画只影响展示，中签结果始终由抽签引擎按随机种子决定，转盘最终停在该结果上。
+
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::i18n::tr;
+
+/// 动画方案
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnimationProfile {
+    /// 标准（默认）
+    #[default]
+    Standard,
+    /// 快速：转得快、停得快，适合连续抽取
+    Quick,
+    /// 仪式：转得慢、减速时间长，适合会场现场抽签
+    Ceremony,
+}
+
+impl AnimationProfile {
+    /// 所有方案
+    pub fn all() -> [AnimationProfile; 3] {
+        [AnimationProfile::Standard, AnimationProfile::Quick, AnimationProfile::Ceremony]
+    }
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AnimationProfile::Standard => tr("标准"),
+            AnimationProfile::Quick => tr("快速"),
+            AnimationProfile::Ceremony => tr("仪式"),
+        }
+    }
+
+    /// 每秒转过的圈数
+    pub fn rotations_per_second(&self) -> f32 {
+        match self {
+            AnimationProfile::Standard => 3.0,
+            AnimationProfile::Quick => 4.0,
+            AnimationProfile::Ceremony => 2.0,
+        }
+    }
+
+    /// 转速范围（每秒滚过的项目数）
+    pub fn speed_range(&self) -> (f32, f32) {
+        match self {
+            AnimationProfile::Standard => (30.0, 80.0),
+            AnimationProfile::Quick => (40.0, 100.0),
+            AnimationProfile::Ceremony => (20.0, 50.0),
+        }
+    }
+
+    /// 减速持续时间
+    pub fn slowdown(&self) -> Duration {
+        match self {
+            AnimationProfile::Standard => Duration::from_millis(3000),
+            AnimationProfile::Quick => Duration::from_millis(1200),
+            AnimationProfile::Ceremony => Duration::from_millis(6000),
+        }
+    }
+}
+
+/// 减速曲线（缓出函数 1 - (1 - t)^n）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Easing {
+    /// 匀减速（n = 2）
+    Quadratic,
+    /// 平滑（n = 3，默认）
+    #[default]
+    Cubic,
+    /// 悬念：很快降速，最后慢慢挪到结果上（n = 5）
+    Quintic,
+}
+
+impl Easing {
+    /// 所有曲线
+    pub fn all() -> [Easing; 3] {
+        [Easing::Quadratic, Easing::Cubic, Easing::Quintic]
+    }
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Easing::Quadratic => tr("匀减速"),
+            Easing::Cubic => tr("平滑"),
+            Easing::Quintic => tr("悬念"),
+        }
+    }
+
+    /// 曲线指数
+    fn exponent(&self) -> i32 {
+        match self {
+            Easing::Quadratic => 2,
+            Easing::Cubic => 3,
+            Easing::Quintic => 5,
+        }
+    }
+
+    /// 进度 `t`（0~1）时已走过的距离比例
+    pub fn position(&self, t: f32) -> f32 {
+        1.0 - (1.0 - t.clamp(0.0, 1.0)).powi(self.exponent())
+    }
+
+    /// 进度 `t` 时的速度比例（距离比例对进度的导数，开始时为 n，结束时为 0）
+    pub fn velocity(&self, t: f32) -> f32 {
+        let n = self.exponent();
+        n as f32 * (1.0 - t.clamp(0.0, 1.0)).powi(n - 1)
+    }
+}
+
+/// 转盘动画设置
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnimationSettings {
+    /// 动画方案
+    pub profile: AnimationProfile,
+    /// 减速曲线
+    pub easing: Easing,
+    /// 自动停止（不需要点击停止）
+    pub auto_stop: bool,
+    /// 自动停止前转动的秒数
+    pub auto_stop_secs: f32,
+    /// 自动停止时间的随机浮动（±秒），各转盘分别浮动
+    pub jitter_secs: f32,
+    /// 开始、减速和中签时播放提示音
+    pub sound: bool,
+}
+
+impl AnimationSettings {
+    /// 自动停止秒数的范围
+    pub const AUTO_STOP_RANGE: std::ops::RangeInclusive<f32> = 1.0..=60.0;
+    /// 随机浮动秒数的范围
+    pub const JITTER_RANGE: std::ops::RangeInclusive<f32> = 0.0..=10.0;
+
+    /// 自动停止前的转动时间，`jitter` 为 0~1 之间的随机数（未开启自动停止时为 None）
+    ///
+    /// 浮动后至少转动 1 秒
+    pub fn auto_stop_after(&self, jitter: f32) -> Option<Duration> {
+        if !self.auto_stop {
+            return None;
+        }
+        let offset = (jitter.clamp(0.0, 1.0) * 2.0 - 1.0) * self.jitter_secs;
+        Some(Duration::from_secs_f32((self.auto_stop_secs + offset).max(1.0)))
+    }
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self {
+            profile: AnimationProfile::default(),
+            easing: Easing::default(),
+            auto_stop: false,
+            auto_stop_secs: 5.0,
+            jitter_secs: 1.5,
+            sound: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_easing_and_auto_stop() {
+        for easing in Easing::all() {
+            assert_eq!(easing.position(0.0), 0.0);
+            assert_eq!(easing.position(1.0), 1.0);
+            assert_eq!(easing.velocity(1.0), 0.0);
+            // 速度与位置曲线的数值导数一致
+            let h = 1e-3;
+            let numeric = (easing.position(0.5 + h) - easing.position(0.5 - h)) / (2.0 * h);
+            assert!((numeric - easing.velocity(0.5)).abs() < 1e-2, "{:?}", easing);
+        }
+
+        let mut settings = AnimationSettings::default();
+        assert_eq!(settings.auto_stop_after(0.5), None);
+        settings.auto_stop = true;
+        assert_eq!(settings.auto_stop_after(0.5), Some(Duration::from_secs(5)));
+        assert_eq!(settings.auto_stop_after(0.0), Some(Duration::from_secs_f32(3.5)));
+        settings.auto_stop_secs = 1.0;
+        assert_eq!(settings.auto_stop_after(0.0), Some(Duration::from_secs(1)));
+
+        // 旧版本或手工编辑的文件缺少字段时使用默认值
+        let parsed: AnimationSettings = serde_json::from_str(r#"{"profile":"ceremony","auto_stop":true}"#).unwrap();
+        assert_eq!(parsed.profile, AnimationProfile::Ceremony);
+        assert_eq!(parsed.auto_stop_secs, 5.0);
+    }
+}
@@ -0,0 +1,168 @@
+//! 审计日志相关数据模型
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use super::Operator;
+use crate::i18n::tr;
+
+/// 审计操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditAction {
+    /// 登录
+    Login,
+    /// 注销
+    Logout,
+    /// 添加专责
+    AddSpecialist,
+    /// 删除专责
+    DeleteSpecialist,
+    /// 修改专责
+    UpdateSpecialist,
+    /// 添加部门
+    AddDepartment,
+    /// 删除部门
+    DeleteDepartment,
+    /// 修改部门需要/派出的专业
+    UpdateDepartment,
+    /// 添加专业
+    AddSpecialty,
+    /// 删除专业
+    DeleteSpecialty,
+    /// 新增单位
+    AddOrganization,
+    /// 修改单位信息
+    UpdateOrganization,
+    /// 作废抽签记录
+    VoidRecord,
+    /// 归档轮次
+    ArchiveRound,
+    /// 添加账户
+    AddOperator,
+    /// 删除账户
+    DeleteOperator,
+    /// 修改账户角色
+    ChangeRole,
+    /// 重设密码
+    ResetPassword,
+    /// 见证人确认抽签结果
+    ConfirmDraw,
+    /// 取消未确认的抽签结果
+    CancelDraw,
+    /// 撤销本轮最近一次抽签
+    UndoDraw,
+    /// 安排或取消检查日程
+    UpdateSchedule,
+    /// 登记或修改检查结果
+    UpdateOutcome,
+}
+
+impl AuditAction {
+    /// 获取显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AuditAction::Login => tr("登录"),
+            AuditAction::Logout => tr("注销"),
+            AuditAction::AddSpecialist => tr("添加专责"),
+            AuditAction::DeleteSpecialist => tr("删除专责"),
+            AuditAction::UpdateSpecialist => tr("修改专责"),
+            AuditAction::AddDepartment => tr("添加部门"),
+            AuditAction::DeleteDepartment => tr("删除部门"),
+            AuditAction::UpdateDepartment => tr("修改部门专业"),
+            AuditAction::AddSpecialty => tr("添加专业"),
+            AuditAction::DeleteSpecialty => tr("删除专业"),
+            AuditAction::AddOrganization => tr("新增单位"),
+            AuditAction::UpdateOrganization => tr("修改单位信息"),
+            AuditAction::VoidRecord => tr("作废记录"),
+            AuditAction::ArchiveRound => tr("归档轮次"),
+            AuditAction::AddOperator => tr("添加账户"),
+            AuditAction::DeleteOperator => tr("删除账户"),
+            AuditAction::ChangeRole => tr("修改角色"),
+            AuditAction::ResetPassword => tr("重设密码"),
+            AuditAction::ConfirmDraw => tr("见证确认"),
+            AuditAction::CancelDraw => tr("取消抽签结果"),
+            AuditAction::UndoDraw => tr("撤销抽签"),
+            AuditAction::UpdateSchedule => tr("安排检查日程"),
+            AuditAction::UpdateOutcome => tr("登记检查结果"),
+        }
+    }
+    
+    /// 所有操作类型
+    pub fn all() -> [AuditAction; 23] {
+        [
+            AuditAction::Login,
+            AuditAction::Logout,
+            AuditAction::AddSpecialist,
+            AuditAction::DeleteSpecialist,
+            AuditAction::UpdateSpecialist,
+            AuditAction::AddDepartment,
+            AuditAction::DeleteDepartment,
+            AuditAction::UpdateDepartment,
+            AuditAction::AddSpecialty,
+            AuditAction::DeleteSpecialty,
+            AuditAction::AddOrganization,
+            AuditAction::UpdateOrganization,
+            AuditAction::VoidRecord,
+            AuditAction::ArchiveRound,
+            AuditAction::AddOperator,
+            AuditAction::DeleteOperator,
+            AuditAction::ChangeRole,
+            AuditAction::ResetPassword,
+            AuditAction::ConfirmDraw,
+            AuditAction::CancelDraw,
+            AuditAction::UndoDraw,
+            AuditAction::UpdateSchedule,
+            AuditAction::UpdateOutcome,
+        ]
+    }
+}
+
+/// 审计日志条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// 条目ID
+    pub id: String,
+    /// 操作时间
+    pub timestamp: DateTime<Local>,
+    /// 操作员ID
+    pub operator_id: String,
+    /// 操作员姓名
+    pub operator_name: String,
+    /// 操作类型
+    pub action: AuditAction,
+    /// 操作对象说明（如"张三（承压类）"）
+    pub target: String,
+    /// 修改前的值（JSON）
+    #[serde(default)]
+    pub old_value: Option<String>,
+    /// 修改后的值（JSON）
+    #[serde(default)]
+    pub new_value: Option<String>,
+}
+
+impl AuditEntry {
+    /// 创建审计日志条目
+    pub fn new(operator: &Operator, action: AuditAction, target: impl Into<String>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Local::now(),
+            operator_id: operator.id.clone(),
+            operator_name: operator.display_name.clone(),
+            action,
+            target: target.into(),
+            old_value: None,
+            new_value: None,
+        }
+    }
+    
+    /// 记录修改前的值
+    pub fn with_old<T: Serialize>(mut self, value: &T) -> Self {
+        self.old_value = serde_json::to_string(value).ok();
+        self
+    }
+    
+    /// 记录修改后的值
+    pub fn with_new<T: Serialize>(mut self, value: &T) -> Self {
+        self.new_value = serde_json::to_string(value).ok();
+        self
+    }
+}
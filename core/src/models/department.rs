@@ -0,0 +1,175 @@
+//! 部门相关数据模型
+
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use super::ChangeStamp;
+
+/// 部门
+/// 
+/// 部门需要抽取哪些专业、能派出哪些专业由数据决定，
+/// 例如综合类分院同时需要并提供承压类和机电类
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "DepartmentData")]
+pub struct Department {
+    /// 部门ID
+    pub id: String,
+    /// 部门名称
+    pub name: String,
+    /// 部门分类（仅用于分组显示，如综合类、承压类）
+    pub category: String,
+    /// 被检查时需要抽取的专业ID
+    pub needs: Vec<String>,
+    /// 可以派出检查的专业ID
+    pub supplies: Vec<String>,
+    /// 各专业检查组人数（专业ID → 抽取的检查部门数，未设置为1）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub team_sizes: BTreeMap<String, usize>,
+    /// 最后修改人（预置部门为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<ChangeStamp>,
+}
+
+/// 部门的保存格式，兼容旧版本的固定部门类型
+#[derive(Deserialize)]
+struct DepartmentData {
+    id: String,
+    name: String,
+    #[serde(default)]
+    category: String,
+    #[serde(default)]
+    needs: Vec<String>,
+    #[serde(default)]
+    supplies: Vec<String>,
+    #[serde(default)]
+    team_sizes: BTreeMap<String, usize>,
+    #[serde(default)]
+    updated: Option<ChangeStamp>,
+    /// 旧版本的部门类型（Pressure / Mechanical / Comprehensive）
+    #[serde(default)]
+    department_type: Option<String>,
+}
+
+impl From<DepartmentData> for Department {
+    fn from(data: DepartmentData) -> Self {
+        let mut department = Self {
+            id: data.id,
+            name: data.name,
+            category: data.category,
+            needs: data.needs,
+            supplies: data.supplies,
+            team_sizes: data.team_sizes,
+            updated: data.updated,
+        };
+        if let Some(legacy) = data.department_type {
+            let (category, specialties): (&str, &[&str]) = match legacy.as_str() {
+                "Pressure" => ("承压类", &["Pressure"]),
+                "Mechanical" => ("机电类", &["Mechanical"]),
+                _ => ("综合类", &["Pressure", "Mechanical"]),
+            };
+            if department.category.is_empty() {
+                department.category = category.to_string();
+            }
+            if department.needs.is_empty() && department.supplies.is_empty() {
+                department.needs = specialties.iter().map(|s| s.to_string()).collect();
+                department.supplies = department.needs.clone();
+            }
+        }
+        department
+    }
+}
+
+impl Department {
+    /// 创建新部门，需要抽取和可以派出的专业相同
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        category: impl Into<String>,
+        specialties: &[&str],
+    ) -> Self {
+        let specialties: Vec<String> = specialties.iter().map(|s| s.to_string()).collect();
+        Self {
+            id: id.into(),
+            name: name.into(),
+            category: category.into(),
+            needs: specialties.clone(),
+            supplies: specialties,
+            team_sizes: BTreeMap::new(),
+            updated: None,
+        }
+    }
+    
+    /// 记录修改人
+    pub fn stamped(mut self, stamp: ChangeStamp) -> Self {
+        self.updated = Some(stamp);
+        self
+    }
+    
+    /// 被检查时是否需要抽取该专业
+    pub fn needs_specialty(&self, specialty_id: &str) -> bool {
+        self.needs.iter().any(|s| s == specialty_id)
+    }
+    
+    /// 是否可以派出该专业的检查人员
+    pub fn supplies_specialty(&self, specialty_id: &str) -> bool {
+        self.supplies.iter().any(|s| s == specialty_id)
+    }
+    
+    /// 被检查时该专业需要抽取的检查部门数（至少1个）
+    pub fn team_size(&self, specialty_id: &str) -> usize {
+        self.team_sizes.get(specialty_id).copied().unwrap_or(1).max(1)
+    }
+    
+    /// 设置该专业的检查组人数，1个时不单独保存
+    pub fn set_team_size(&mut self, specialty_id: &str, size: usize) {
+        if size > 1 {
+            self.team_sizes.insert(specialty_id.to_string(), size);
+        } else {
+            self.team_sizes.remove(specialty_id);
+        }
+    }
+}
+
+/// 预置部门列表
+pub fn default_departments() -> Vec<Department> {
+    const BOTH: &[&str] = &["Pressure", "Mechanical"];
+    vec![
+        // 综合类（需要同时抽取承压和机电）
+        Department::new("nd", "宁东分院", "综合类", BOTH),
+        Department::new("szs", "石嘴山分院", "综合类", BOTH),
+        Department::new("wz", "吴忠分院", "综合类", BOTH),
+        Department::new("zw", "中卫分院", "综合类", BOTH),
+        Department::new("gy", "固原分院", "综合类", BOTH),
+        // 承压类
+        Department::new("cy1", "承压特种设备一部", "承压类", &["Pressure"]),
+        Department::new("cy2", "承压特种设备二部", "承压类", &["Pressure"]),
+        Department::new("zh", "综合检验检测站", "承压类", &["Pressure"]),
+        // 机电类
+        Department::new("jd1", "机电特种设备一部", "机电类", &["Mechanical"]),
+        Department::new("jd2", "机电特种设备二部", "机电类", &["Mechanical"]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_department_type() {
+        let json = r#"[
+            {"id": "nd", "name": "宁东分院", "department_type": "Comprehensive"},
+            {"id": "jd1", "name": "机电特种设备一部", "department_type": "Mechanical"}
+        ]"#;
+        let departments: Vec<Department> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(departments[0].category, "综合类");
+        assert!(departments[0].needs_specialty("Pressure") && departments[0].supplies_specialty("Mechanical"));
+        assert!(!departments[1].needs_specialty("Pressure"));
+        assert!(departments[1].supplies_specialty("Mechanical"));
+
+        // 保存后不再写出旧字段，重新读取结果一致
+        let saved = serde_json::to_string(&departments).unwrap();
+        assert!(!saved.contains("department_type"));
+        let reloaded: Vec<Department> = serde_json::from_str(&saved).unwrap();
+        assert_eq!(reloaded[1].needs, departments[1].needs);
+    }
+}
@@ -0,0 +1,52 @@
+//! 显示设置模型
+//!
+//! 转盘和界面的配色主题、投影大字体，保存在数据根目录，所有单位共用。
+
+use serde::{Deserialize, Serialize};
+use crate::i18n::tr;
+
+/// 配色主题
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    /// 深色（默认）
+    #[default]
+    Dark,
+    /// 浅色
+    Light,
+    /// 高对比度（黑底、白字、黄色强调）
+    HighContrast,
+}
+
+impl Theme {
+    /// 所有主题
+    pub fn all() -> [Theme; 3] {
+        [Theme::Dark, Theme::Light, Theme::HighContrast]
+    }
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Theme::Dark => tr("深色"),
+            Theme::Light => tr("浅色"),
+            Theme::HighContrast => tr("高对比度"),
+        }
+    }
+}
+
+/// 显示设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplaySettings {
+    /// 配色主题
+    pub theme: Theme,
+    /// 投影大字体（界面和转盘文字放大）
+    pub large_font: bool,
+}
+
+impl DisplaySettings {
+    /// 文字放大倍数
+    pub fn font_scale(&self) -> f32 {
+        if self.large_font { 1.5 } else { 1.0 }
+    }
+}
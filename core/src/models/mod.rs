@@ -0,0 +1,23 @@
+//! 数据模型模块
+
+mod department;
+mod specialist;
+mod record;
+mod operator;
+mod audit;
+mod organization;
+mod schedule;
+mod outcome;
+mod display;
+mod animation;
+
+pub use department::{Department, default_departments};
+pub use specialist::{QualitySpecialist, Specialty, default_specialties, specialty_name};
+pub use record::{DrawRecord, DrawReplay, TeamInfo, VoidInfo, WitnessInfo};
+pub use operator::{ChangeStamp, Operator, Role};
+pub use audit::{AuditAction, AuditEntry};
+pub use organization::{DrawRules, Organization, DEFAULT_ORGANIZATION_ID, default_organization};
+pub use schedule::{ScheduleEntry, TimeSlot};
+pub use outcome::{FindingCounts, InspectionOutcome, InspectionStatus};
+pub use display::{DisplaySettings, Theme};
+pub use animation::{AnimationProfile, AnimationSettings, Easing};
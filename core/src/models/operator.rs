@@ -0,0 +1,90 @@
+//! 操作员账户相关数据模型
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use crate::i18n::{tr, trf};
+
+/// 操作员角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    /// 管理员（可管理数据、账户，作废和归档记录）
+    Admin,
+    /// 抽签员（可执行抽签、导出和打印）
+    DrawOperator,
+    /// 观察员（只读）
+    Observer,
+}
+
+impl Role {
+    /// 获取显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Role::Admin => tr("管理员"),
+            Role::DrawOperator => tr("抽签员"),
+            Role::Observer => tr("观察员"),
+        }
+    }
+    
+    /// 是否可以执行抽签（包括开始新一轮和重新抽取）
+    pub fn can_draw(&self) -> bool {
+        matches!(self, Role::Admin | Role::DrawOperator)
+    }
+    
+    /// 是否可以修改数据（专责、部门、账户管理，作废和归档记录）
+    pub fn can_manage(&self) -> bool {
+        matches!(self, Role::Admin)
+    }
+    
+    /// 所有角色
+    pub fn all() -> [Role; 3] {
+        [Role::Admin, Role::DrawOperator, Role::Observer]
+    }
+}
+
+/// 操作员账户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Operator {
+    /// 操作员ID
+    pub id: String,
+    /// 登录名
+    pub username: String,
+    /// 显示姓名
+    pub display_name: String,
+    /// 角色
+    pub role: Role,
+    /// 密码哈希（十六进制）
+    pub password_hash: String,
+    /// 密码盐（十六进制）
+    pub salt: String,
+    /// 创建时间
+    pub created_at: DateTime<Local>,
+}
+
+impl Operator {
+    /// 带角色的显示文本，如"张三（管理员）"
+    pub fn label(&self) -> String {
+        trf("{}（{}）", &[&self.display_name, &self.role.display_name()])
+    }
+}
+
+/// 修改标记（记录由哪位操作员在何时修改）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeStamp {
+    /// 操作员ID
+    pub operator_id: String,
+    /// 操作员姓名
+    pub operator_name: String,
+    /// 修改时间
+    pub timestamp: DateTime<Local>,
+}
+
+impl ChangeStamp {
+    /// 以当前时间生成修改标记
+    pub fn now(operator: &Operator) -> Self {
+        Self {
+            operator_id: operator.id.clone(),
+            operator_name: operator.display_name.clone(),
+            timestamp: Local::now(),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! 单位（机构）配置模型
+//!
+//! 多个单位共用一套程序时，每个单位有独立的部门、专业、专责、账户和抽签记录。
+
+use serde::{Deserialize, Serialize};
+use super::ChangeStamp;
+use crate::i18n::trf;
+
+/// 默认单位ID（数据保存在数据根目录，兼容旧版本）
+pub const DEFAULT_ORGANIZATION_ID: &str = "default";
+
+/// 抽签规则（按部门抽签模式）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DrawRules {
+    /// 本轮已被抽中的部门不再参与抽取
+    #[serde(default = "enabled")]
+    pub once_per_round: bool,
+    /// 交叉回避：被检部门本轮已去检查过的部门，不能反过来检查被检部门
+    #[serde(default = "enabled")]
+    pub cross_avoidance: bool,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+impl Default for DrawRules {
+    fn default() -> Self {
+        Self {
+            once_per_round: true,
+            cross_avoidance: true,
+        }
+    }
+}
+
+/// 单位配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Organization {
+    /// 单位ID（同时是数据子目录名）
+    pub id: String,
+    /// 单位名称，如"宁夏特检院"
+    pub name: String,
+    /// 单位标志图片路径（打印和 PDF 导出时显示在抬头）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+    /// 报表抬头（为空时使用"<单位名称>质量监督检查抽签结果"）
+    #[serde(default)]
+    pub report_header: String,
+    /// 抽签规则
+    #[serde(default)]
+    pub rules: DrawRules,
+    /// 最后修改人（预置单位为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<ChangeStamp>,
+}
+
+impl Organization {
+    /// 创建新单位
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            logo: None,
+            report_header: String::new(),
+            rules: DrawRules::default(),
+            updated: None,
+        }
+    }
+
+    /// 记录修改人
+    pub fn stamped(mut self, stamp: ChangeStamp) -> Self {
+        self.updated = Some(stamp);
+        self
+    }
+
+    /// 窗口和登录界面的标题
+    pub fn app_title(&self) -> String {
+        trf("{}质量监督检查抽签程序", &[&self.name])
+    }
+
+    /// 导出和打印的报表抬头
+    pub fn report_title(&self) -> String {
+        if self.report_header.trim().is_empty() {
+            trf("{}质量监督检查抽签结果", &[&self.name])
+        } else {
+            self.report_header.trim().to_string()
+        }
+    }
+
+    /// 是否为默认单位
+    pub fn is_default(&self) -> bool {
+        self.id == DEFAULT_ORGANIZATION_ID
+    }
+}
+
+/// 默认单位（未配置单位列表时使用）
+pub fn default_organization() -> Organization {
+    Organization::new(DEFAULT_ORGANIZATION_ID, "宁夏特检院")
+}
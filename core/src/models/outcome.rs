@@ -0,0 +1,126 @@
+//! 检查结果模型
+//!
+//! 抽签确定"谁检查谁"之后，为每条有效记录登记检查进度、发现问题数、评分、报告和结案日期。
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use super::ChangeStamp;
+use crate::i18n::tr;
+
+/// 检查状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum InspectionStatus {
+    /// 计划中（尚未登记结果的记录也视为计划中）
+    #[default]
+    Planned,
+    /// 检查中
+    InProgress,
+    /// 已完成
+    Done,
+}
+
+impl InspectionStatus {
+    /// 所有状态
+    pub fn all() -> [InspectionStatus; 3] {
+        [InspectionStatus::Planned, InspectionStatus::InProgress, InspectionStatus::Done]
+    }
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            InspectionStatus::Planned => tr("计划中"),
+            InspectionStatus::InProgress => tr("检查中"),
+            InspectionStatus::Done => tr("已完成"),
+        }
+    }
+}
+
+/// 按严重程度统计的问题数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FindingCounts {
+    /// 严重问题
+    #[serde(default)]
+    pub major: u32,
+    /// 一般问题
+    #[serde(default)]
+    pub general: u32,
+    /// 轻微问题
+    #[serde(default)]
+    pub minor: u32,
+}
+
+impl FindingCounts {
+    /// 问题总数
+    pub fn total(&self) -> u32 {
+        self.major + self.general + self.minor
+    }
+
+    /// 累加
+    pub fn add(&mut self, other: &FindingCounts) {
+        self.major += other.major;
+        self.general += other.general;
+        self.minor += other.minor;
+    }
+}
+
+/// 一条抽签记录的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectionOutcome {
+    /// 抽签记录ID
+    pub record_id: String,
+    /// 检查状态
+    #[serde(default)]
+    pub status: InspectionStatus,
+    /// 发现的问题数
+    #[serde(default)]
+    pub findings: FindingCounts,
+    /// 评分（0~100，未评分为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
+    /// 检查报告附件路径
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report_path: Option<String>,
+    /// 结案日期（已完成时填写）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_on: Option<NaiveDate>,
+    /// 最后修改人
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<ChangeStamp>,
+}
+
+impl InspectionOutcome {
+    /// 创建计划中的检查结果
+    pub fn new(record_id: impl Into<String>) -> Self {
+        Self {
+            record_id: record_id.into(),
+            status: InspectionStatus::Planned,
+            findings: FindingCounts::default(),
+            score: None,
+            report_path: None,
+            closed_on: None,
+            updated: None,
+        }
+    }
+
+    /// 记录修改人
+    pub fn stamped(mut self, stamp: ChangeStamp) -> Self {
+        self.updated = Some(stamp);
+        self
+    }
+
+    /// 检查内容是否有效：评分在 0~100 之间，已完成的必须填写结案日期，未完成的不能有结案日期
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(score) = self.score {
+            if !(0.0..=100.0).contains(&score) {
+                return Err(tr("评分应在 0~100 之间").to_string());
+            }
+        }
+        match (self.status, self.closed_on) {
+            (InspectionStatus::Done, None) => Err(tr("已完成的检查需要填写结案日期").to_string()),
+            (InspectionStatus::Planned | InspectionStatus::InProgress, Some(_)) => {
+                Err(tr("未完成的检查不能填写结案日期").to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
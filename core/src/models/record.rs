@@ -0,0 +1,241 @@
+//! 抽签记录相关数据模型
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use super::{Easing, Operator, Specialty};
+use super::specialist::legacy_specialty_name;
+use crate::i18n::tr;
+
+/// 作废信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoidInfo {
+    /// 作废原因
+    pub reason: String,
+    /// 操作人
+    pub operator: String,
+    /// 操作员ID（旧版本记录为 None）
+    #[serde(default)]
+    pub operator_id: Option<String>,
+    /// 作废时间
+    pub timestamp: DateTime<Local>,
+}
+
+/// 见证确认信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WitnessInfo {
+    /// 见证人账户ID
+    pub operator_id: String,
+    /// 见证人姓名
+    pub name: String,
+    /// 确认时间
+    pub confirmed_at: DateTime<Local>,
+    /// 通过局域网设备确认时为设备地址，本机确认为 None
+    #[serde(default)]
+    pub remote_addr: Option<String>,
+}
+
+impl WitnessInfo {
+    /// 由见证人账户创建确认信息
+    pub fn new(witness: &Operator, remote_addr: Option<String>) -> Self {
+        Self {
+            operator_id: witness.id.clone(),
+            name: witness.display_name.clone(),
+            confirmed_at: Local::now(),
+            remote_addr,
+        }
+    }
+}
+
+/// 检查组信息（一次抽取多个检查部门时，每个部门一条记录）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamInfo {
+    /// 同一次抽取的记录共用的组ID
+    pub group_id: String,
+    /// 在本次抽取结果中的位置（从0开始，用于复核）
+    pub position: usize,
+    /// 本次抽取的部门数
+    pub size: usize,
+}
+
+/// 转盘回放数据
+///
+/// 保存重现转盘动画所需的全部参数：转盘上的候选顺序、中签位置、转速和开始减速的时刻。
+/// 回放按这些参数重新计算每一帧，停下的位置与抽签时完全相同
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawReplay {
+    /// 转盘上的候选顺序（按随机种子打乱后）
+    pub order: Vec<String>,
+    /// 中签位置（检查组为第一个成员的位置）
+    pub final_index: usize,
+    /// 本次抽取的部门数
+    pub team_size: usize,
+    /// 转速（每秒滚过的项目数）
+    pub speed: f32,
+    /// 从开始转动到开始减速的毫秒数
+    pub rolling_ms: u64,
+    /// 减速持续的毫秒数
+    pub slowdown_ms: u64,
+    /// 减速曲线
+    #[serde(default)]
+    pub easing: Easing,
+}
+
+/// 抽签记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawRecord {
+    /// 记录ID
+    pub id: String,
+    /// 抽签时间
+    pub timestamp: DateTime<Local>,
+    /// 被检查部门ID
+    pub target_department_id: String,
+    /// 被检查部门名称
+    pub target_department_name: String,
+    /// 抽取的专业ID
+    pub specialty_type: String,
+    /// 抽签时的专业名称（旧版本记录为空）
+    #[serde(default)]
+    pub specialty_name: String,
+    /// 抽中的人员ID
+    pub selected_specialist_id: String,
+    /// 抽中的人员姓名
+    pub selected_specialist_name: String,
+    /// 抽中人员所属部门ID
+    pub selected_from_department_id: String,
+    /// 抽中人员所属部门名称
+    pub selected_from_department_name: String,
+    /// 抽签轮次（旧版本记录为0）
+    #[serde(default)]
+    pub round: u32,
+    /// 本次抽取使用的随机种子
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// 参与本次抽取的候选名单
+    #[serde(default)]
+    pub candidates: Vec<String>,
+    /// 规则说明（被排除的部门及原因）
+    #[serde(default)]
+    pub rule_notes: Vec<String>,
+    /// 作废信息（未作废为 None）
+    #[serde(default)]
+    pub voided: Option<VoidInfo>,
+    /// 是否已归档
+    #[serde(default)]
+    pub archived: bool,
+    /// 执行抽签的操作员ID（旧版本记录为 None）
+    #[serde(default)]
+    pub operator_id: Option<String>,
+    /// 执行抽签的操作员姓名
+    #[serde(default)]
+    pub operator_name: Option<String>,
+    /// 见证确认信息（旧版本记录为 None）
+    #[serde(default)]
+    pub witness: Option<WitnessInfo>,
+    /// 检查组信息（只抽取一个部门时为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamInfo>,
+    /// 转盘回放数据（旧版本记录和命令行抽签为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replay: Option<DrawReplay>,
+}
+
+impl DrawRecord {
+    /// 创建新的抽签记录
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        target_department_id: impl Into<String>,
+        target_department_name: impl Into<String>,
+        specialty: &Specialty,
+        selected_specialist_id: impl Into<String>,
+        selected_specialist_name: impl Into<String>,
+        selected_from_department_id: impl Into<String>,
+        selected_from_department_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Local::now(),
+            target_department_id: target_department_id.into(),
+            target_department_name: target_department_name.into(),
+            specialty_type: specialty.id.clone(),
+            specialty_name: specialty.name.clone(),
+            selected_specialist_id: selected_specialist_id.into(),
+            selected_specialist_name: selected_specialist_name.into(),
+            selected_from_department_id: selected_from_department_id.into(),
+            selected_from_department_name: selected_from_department_name.into(),
+            round: 0,
+            seed: None,
+            candidates: Vec::new(),
+            rule_notes: Vec::new(),
+            voided: None,
+            archived: false,
+            operator_id: None,
+            operator_name: None,
+            witness: None,
+            team: None,
+            replay: None,
+        }
+    }
+    
+    /// 附加抽签过程信息（轮次、种子、候选名单和规则说明）
+    pub fn with_draw_info(
+        mut self,
+        round: u32,
+        seed: u64,
+        candidates: Vec<String>,
+        rule_notes: Vec<String>,
+    ) -> Self {
+        self.round = round;
+        self.seed = Some(seed);
+        self.candidates = candidates;
+        self.rule_notes = rule_notes;
+        self
+    }
+    
+    /// 记录执行抽签的操作员
+    pub fn with_operator(mut self, operator_id: impl Into<String>, operator_name: impl Into<String>) -> Self {
+        self.operator_id = Some(operator_id.into());
+        self.operator_name = Some(operator_name.into());
+        self
+    }
+    
+    /// 记录检查组信息
+    pub fn with_team(mut self, team: TeamInfo) -> Self {
+        self.team = Some(team);
+        self
+    }
+    
+    /// 记录转盘回放数据
+    pub fn with_replay(mut self, replay: DrawReplay) -> Self {
+        self.replay = Some(replay);
+        self
+    }
+    
+    /// 记录见证人确认
+    pub fn with_witness(mut self, witness: WitnessInfo) -> Self {
+        self.witness = Some(witness);
+        self
+    }
+    
+    /// 专业显示名称（旧版本记录按固定专业类型显示，预置专业按当前界面语言显示）
+    pub fn specialty_label(&self) -> &str {
+        if self.specialty_name.is_empty() {
+            tr(legacy_specialty_name(&self.specialty_type))
+        } else {
+            tr(&self.specialty_name)
+        }
+    }
+    
+    /// 是否已作废
+    pub fn is_voided(&self) -> bool {
+        self.voided.is_some()
+    }
+}
+
+#[cfg(test)]
+impl DrawRecord {
+    /// 测试用记录：第 `round` 轮由 `selected` 部门检查 `target` 部门（部门ID与名称相同，种子为1）
+    pub(crate) fn test_record(target: &str, specialty: &Specialty, selected: &str, round: u32) -> Self {
+        DrawRecord::new(target, target, specialty, selected, selected, selected, selected)
+            .with_draw_info(round, 1, Vec::new(), Vec::new())
+    }
+}
@@ -0,0 +1,81 @@
+//! 检查日程安排模型
+//!
+//! 抽签完成后为每条有效记录（被检部门、专业、检查部门）安排检查日期和时段。
+
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use super::ChangeStamp;
+use crate::i18n::tr;
+
+/// 检查时段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TimeSlot {
+    /// 上午
+    Morning,
+    /// 下午
+    Afternoon,
+    /// 全天
+    AllDay,
+}
+
+impl TimeSlot {
+    /// 所有时段
+    pub fn all() -> [TimeSlot; 3] {
+        [TimeSlot::Morning, TimeSlot::Afternoon, TimeSlot::AllDay]
+    }
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TimeSlot::Morning => tr("上午"),
+            TimeSlot::Afternoon => tr("下午"),
+            TimeSlot::AllDay => tr("全天"),
+        }
+    }
+
+    /// 起止时间（全天为 None，日历中按全天事件导出）
+    pub fn hours(&self) -> Option<(NaiveTime, NaiveTime)> {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).expect("有效的时间");
+        match self {
+            TimeSlot::Morning => Some((time(9, 0), time(12, 0))),
+            TimeSlot::Afternoon => Some((time(14, 30), time(17, 30))),
+            TimeSlot::AllDay => None,
+        }
+    }
+}
+
+/// 一条抽签记录的检查安排
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleEntry {
+    /// 抽签记录ID
+    pub record_id: String,
+    /// 检查日期
+    pub date: NaiveDate,
+    /// 检查时段
+    pub slot: TimeSlot,
+    /// 备注（如带队人员、集合地点）
+    #[serde(default)]
+    pub note: String,
+    /// 最后修改人
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<ChangeStamp>,
+}
+
+impl ScheduleEntry {
+    /// 创建检查安排
+    pub fn new(record_id: impl Into<String>, date: NaiveDate, slot: TimeSlot) -> Self {
+        Self {
+            record_id: record_id.into(),
+            date,
+            slot,
+            note: String::new(),
+            updated: None,
+        }
+    }
+
+    /// 记录修改人
+    pub fn stamped(mut self, stamp: ChangeStamp) -> Self {
+        self.updated = Some(stamp);
+        self
+    }
+}
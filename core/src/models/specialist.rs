@@ -0,0 +1,101 @@
+//! 专业和质量专责相关数据模型
+
+use serde::{Deserialize, Serialize};
+use super::ChangeStamp;
+
+/// 专业（如承压类、机电类），可在数据管理中增删
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Specialty {
+    /// 专业ID
+    pub id: String,
+    /// 专业名称
+    pub name: String,
+    /// 最后修改人（预置专业为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<ChangeStamp>,
+}
+
+impl Specialty {
+    /// 创建新专业
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            updated: None,
+        }
+    }
+    
+    /// 记录修改人
+    pub fn stamped(mut self, stamp: ChangeStamp) -> Self {
+        self.updated = Some(stamp);
+        self
+    }
+}
+
+/// 预置专业列表
+/// 
+/// ID 与旧版本固定专业类型的保存值一致，旧数据无需转换
+pub fn default_specialties() -> Vec<Specialty> {
+    vec![
+        Specialty::new("Pressure", "承压类"),
+        Specialty::new("Mechanical", "机电类"),
+    ]
+}
+
+/// 按专业ID查找专业名称，未找到时返回旧版本的固定名称或ID本身
+pub fn specialty_name<'a>(specialties: &'a [Specialty], id: &'a str) -> &'a str {
+    specialties
+        .iter()
+        .find(|s| s.id == id)
+        .map(|s| s.name.as_str())
+        .unwrap_or_else(|| legacy_specialty_name(id))
+}
+
+/// 旧版本固定专业类型的显示名称
+pub(crate) fn legacy_specialty_name(id: &str) -> &str {
+    match id {
+        "Pressure" => "承压类",
+        "Mechanical" => "机电类",
+        other => other,
+    }
+}
+
+/// 质量专责
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualitySpecialist {
+    /// 专责ID
+    pub id: String,
+    /// 姓名
+    pub name: String,
+    /// 所属部门ID
+    pub department_id: String,
+    /// 专业ID
+    pub specialty: String,
+    /// 最后修改人
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<ChangeStamp>,
+}
+
+impl QualitySpecialist {
+    /// 创建新的质量专责
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        department_id: impl Into<String>,
+        specialty: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            department_id: department_id.into(),
+            specialty: specialty.into(),
+            updated: None,
+        }
+    }
+    
+    /// 记录修改人
+    pub fn stamped(mut self, stamp: ChangeStamp) -> Self {
+        self.updated = Some(stamp);
+        self
+    }
+}
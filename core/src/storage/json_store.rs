@@ -0,0 +1,602 @@
+//! JSON数据存储
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use chrono::Local;
+use crate::i18n::{Locale, tr, trf};
+use crate::logging;
+use crate::models::{
+    AnimationSettings, AuditEntry, Department, DisplaySettings, QualitySpecialist, DrawRecord, Operator, Organization, Role, ScheduleEntry, Specialty, VoidInfo,
+    InspectionOutcome,
+    DEFAULT_ORGANIZATION_ID, default_departments, default_organization, default_specialties,
+};
+
+/// 数据存储管理器
+/// 
+/// 默认单位的数据保存在数据根目录，其他单位保存在 `orgs/<单位ID>` 子目录，互不可见
+pub struct DataStore {
+    /// 数据根目录（单位列表保存在此）
+    root_dir: PathBuf,
+    /// 当前单位的数据目录
+    data_dir: PathBuf,
+    /// 当前单位ID
+    organization_id: String,
+}
+
+impl DataStore {
+    /// 创建数据存储管理器
+    pub fn new() -> Self {
+        // 获取可执行文件所在目录（使用多种回退方式确保可靠性）
+        let data_dir = Self::get_data_dir();
+        
+        // 确保数据目录存在
+        if !data_dir.exists() {
+            fs::create_dir_all(&data_dir).ok();
+        }
+        
+        Self::at_root(data_dir)
+    }
+    
+    /// 使用指定目录创建数据存储管理器（命令行 `--data-dir` 参数）
+    pub fn with_dir(data_dir: impl Into<PathBuf>) -> Self {
+        let data_dir = data_dir.into();
+        fs::create_dir_all(&data_dir).ok();
+        Self::at_root(data_dir)
+    }
+    
+    /// 以数据根目录创建默认单位的存储
+    fn at_root(root_dir: PathBuf) -> Self {
+        Self {
+            data_dir: root_dir.clone(),
+            root_dir,
+            organization_id: DEFAULT_ORGANIZATION_ID.to_string(),
+        }
+    }
+    
+    /// 数据目录
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+    
+    /// 是否为默认单位（只有默认单位允许在首次运行时从登录界面创建管理员）
+    pub fn is_default_organization(&self) -> bool {
+        self.organization_id == DEFAULT_ORGANIZATION_ID
+    }
+    
+    /// 切换到指定单位的数据目录
+    pub fn for_organization(&self, organization_id: &str) -> Self {
+        let data_dir = if organization_id == DEFAULT_ORGANIZATION_ID {
+            self.root_dir.clone()
+        } else {
+            self.root_dir.join("orgs").join(organization_id)
+        };
+        fs::create_dir_all(&data_dir).ok();
+        Self {
+            root_dir: self.root_dir.clone(),
+            data_dir,
+            organization_id: organization_id.to_string(),
+        }
+    }
+    
+    /// 获取数据存储目录（exe同目录下的data文件夹）
+    fn get_data_dir() -> PathBuf {
+        // 方法1：使用current_exe获取可执行文件路径
+        if let Ok(exe_path) = std::env::current_exe() {
+            if let Some(parent) = exe_path.parent() {
+                let data_dir = parent.join("data");
+                // 测试是否可以创建/访问此目录
+                if fs::create_dir_all(&data_dir).is_ok() {
+                    return data_dir;
+                }
+            }
+        }
+        
+        // 方法2：使用当前工作目录
+        if let Ok(cwd) = std::env::current_dir() {
+            let data_dir = cwd.join("data");
+            if fs::create_dir_all(&data_dir).is_ok() {
+                return data_dir;
+            }
+        }
+        
+        // 方法3：使用相对路径作为最后回退
+        PathBuf::from("data")
+    }
+    
+    /// 获取界面语言设置文件路径（所有单位共用）
+    fn locale_path(&self) -> PathBuf {
+        self.root_dir.join("locale.json")
+    }
+    
+    /// 获取显示设置文件路径（所有单位共用）
+    fn display_path(&self) -> PathBuf {
+        self.root_dir.join("display.json")
+    }
+    
+    /// 获取转盘动画设置文件路径（所有单位共用）
+    fn animation_path(&self) -> PathBuf {
+        self.root_dir.join("animation.json")
+    }
+    
+    /// 获取单位列表文件路径（所有单位共用）
+    fn organizations_path(&self) -> PathBuf {
+        self.root_dir.join("organizations.json")
+    }
+    
+    /// 获取部门数据文件路径
+    fn departments_path(&self) -> PathBuf {
+        self.data_dir.join("departments.json")
+    }
+    
+    /// 获取专业数据文件路径
+    fn specialties_path(&self) -> PathBuf {
+        self.data_dir.join("specialties.json")
+    }
+    
+    /// 获取专责数据文件路径
+    fn specialists_path(&self) -> PathBuf {
+        self.data_dir.join("specialists.json")
+    }
+    
+    /// 获取记录数据文件路径
+    fn records_path(&self) -> PathBuf {
+        self.data_dir.join("records.json")
+    }
+    
+    /// 获取操作员账户文件路径
+    fn operators_path(&self) -> PathBuf {
+        self.data_dir.join("operators.json")
+    }
+    
+    /// 获取当前轮次文件路径
+    fn round_path(&self) -> PathBuf {
+        self.data_dir.join("round.json")
+    }
+    
+    /// 获取检查日程文件路径
+    fn schedule_path(&self) -> PathBuf {
+        self.data_dir.join("schedule.json")
+    }
+    
+    /// 获取检查结果文件路径
+    fn outcomes_path(&self) -> PathBuf {
+        self.data_dir.join("outcomes.json")
+    }
+    
+    /// 获取审计日志文件路径
+    fn audit_path(&self) -> PathBuf {
+        self.data_dir.join("audit.jsonl")
+    }
+    
+    /// 写入数据文件，失败时记录错误日志
+    fn write_file(path: &Path, content: impl AsRef<[u8]>) {
+        if let Err(e) = fs::write(path, content) {
+            logging::error("storage", "保存数据文件失败", &[("path", &path.display()), ("error", &e)]);
+        }
+    }
+    
+    /// 加载单位列表（未配置时只有默认单位）
+    pub fn load_organizations(&self) -> Vec<Organization> {
+        let path = self.organizations_path();
+        if path.exists() {
+            if let Ok(content) = fs::read_to_string(&path) {
+                if let Ok(organizations) = serde_json::from_str::<Vec<Organization>>(&content) {
+                    if !organizations.is_empty() {
+                        return organizations;
+                    }
+                }
+            }
+        }
+        vec![default_organization()]
+    }
+    
+    /// 保存单位列表
+    pub fn save_organizations(&self, organizations: &[Organization]) {
+        if let Ok(content) = serde_json::to_string_pretty(organizations) {
+            Self::write_file(&self.organizations_path(), content);
+        }
+    }
+    
+    /// 当前单位的配置
+    pub fn organization(&self) -> Organization {
+        self.load_organizations()
+            .into_iter()
+            .find(|o| o.id == self.organization_id)
+            .unwrap_or_else(|| Organization::new(self.organization_id.clone(), self.organization_id.clone()))
+    }
+    
+    /// 保存单位配置（新增或覆盖同ID的单位）
+    pub fn save_organization(&self, organization: &Organization) {
+        let mut organizations = self.load_organizations();
+        match organizations.iter_mut().find(|o| o.id == organization.id) {
+            Some(existing) => *existing = organization.clone(),
+            None => organizations.push(organization.clone()),
+        }
+        self.save_organizations(&organizations);
+    }
+    
+    /// 新增单位，同时保存该单位的首个管理员账户
+    /// 
+    /// 新单位必须带管理员创建：只有默认单位允许在登录界面初始化管理员，其他单位没有账户时无法登录，
+    /// 以免任何人在登录时选择新单位即可成为其管理员
+    pub fn create_organization(&self, mut organization: Organization, admin: &Operator) -> Result<Organization, String> {
+        if admin.role != Role::Admin {
+            return Err(tr("新单位的首个账户必须是管理员").to_string());
+        }
+        organization.id = organization.id.trim().to_string();
+        organization.name = organization.name.trim().to_string();
+        if organization.id.is_empty()
+            || !organization.id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(tr("单位ID只能包含字母、数字、- 和 _").to_string());
+        }
+        if organization.name.is_empty() {
+            return Err(tr("请输入单位名称").to_string());
+        }
+        let organizations = self.load_organizations();
+        if organizations.iter().any(|o| o.id == organization.id) {
+            return Err(trf("单位ID {} 已存在", &[&organization.id]));
+        }
+        if organizations.iter().any(|o| o.name == organization.name) {
+            return Err(trf("单位 {} 已存在", &[&organization.name]));
+        }
+        
+        self.save_organization(&organization);
+        self.for_organization(&organization.id).save_operators(std::slice::from_ref(admin));
+        Ok(organization)
+    }
+    
+    /// 加载部门数据
+    pub fn load_departments(&self) -> Vec<Department> {
+        let path = self.departments_path();
+        if path.exists() {
+            if let Ok(content) = fs::read_to_string(&path) {
+                if let Ok(departments) = serde_json::from_str(&content) {
+                    return departments;
+                }
+            }
+        }
+        // 返回默认部门并保存（预置部门只用于默认单位，其他单位从空列表开始）
+        let departments = if self.organization_id == DEFAULT_ORGANIZATION_ID {
+            default_departments()
+        } else {
+            Vec::new()
+        };
+        self.save_departments(&departments);
+        departments
+    }
+    
+    /// 保存部门数据
+    pub fn save_departments(&self, departments: &[Department]) {
+        if let Ok(content) = serde_json::to_string_pretty(departments) {
+            Self::write_file(&self.departments_path(), content);
+        }
+    }
+    
+    /// 加载专业数据
+    pub fn load_specialties(&self) -> Vec<Specialty> {
+        let path = self.specialties_path();
+        if path.exists() {
+            if let Ok(content) = fs::read_to_string(&path) {
+                if let Ok(specialties) = serde_json::from_str(&content) {
+                    return specialties;
+                }
+            }
+        }
+        // 返回默认专业并保存
+        let specialties = default_specialties();
+        self.save_specialties(&specialties);
+        specialties
+    }
+    
+    /// 保存专业数据
+    pub fn save_specialties(&self, specialties: &[Specialty]) {
+        if let Ok(content) = serde_json::to_string_pretty(specialties) {
+            Self::write_file(&self.specialties_path(), content);
+        }
+    }
+    
+    /// 加载专责数据
+    pub fn load_specialists(&self) -> Vec<QualitySpecialist> {
+        let path = self.specialists_path();
+        if path.exists() {
+            if let Ok(content) = fs::read_to_string(&path) {
+                if let Ok(specialists) = serde_json::from_str(&content) {
+                    return specialists;
+                }
+            }
+        }
+        Vec::new()
+    }
+    
+    /// 保存专责数据
+    pub fn save_specialists(&self, specialists: &[QualitySpecialist]) {
+        if let Ok(content) = serde_json::to_string_pretty(specialists) {
+            Self::write_file(&self.specialists_path(), content);
+        }
+    }
+    
+    /// 加载操作员账户
+    /// 
+    /// 账户文件不存在时（首次运行）返回空列表，需要先创建管理员；文件存在但无法读取或解析时返回错误，
+    /// 调用方应拒绝登录和创建管理员，不能把损坏的账户文件当作首次运行
+    pub fn load_operators(&self) -> Result<Vec<Operator>, String> {
+        let path = self.operators_path();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                logging::error("storage", "账户文件无法读取", &[("path", &path.display()), ("error", &e)]);
+                return Err(trf("无法读取账户文件 {}: {}", &[&path.display(), &e]));
+            }
+        };
+        serde_json::from_str(&content).map_err(|e| {
+            logging::error("storage", "账户文件无法解析", &[("path", &path.display()), ("error", &e)]);
+            trf("账户文件 {} 已损坏: {}", &[&path.display(), &e])
+        })
+    }
+    
+    /// 保存操作员账户
+    pub fn save_operators(&self, operators: &[Operator]) {
+        if let Ok(content) = serde_json::to_string_pretty(operators) {
+            Self::write_file(&self.operators_path(), content);
+        }
+    }
+    
+    /// 加载抽签记录
+    /// 
+    /// 记录文件不存在时返回空列表；文件存在但无法读取或解析时返回错误，调用方不能再写入记录文件，
+    /// 否则会用空列表覆盖全部抽签历史
+    pub fn load_records(&self) -> Result<Vec<DrawRecord>, String> {
+        let path = self.records_path();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                logging::error("storage", "抽签记录文件无法读取", &[("path", &path.display()), ("error", &e)]);
+                return Err(trf("无法读取抽签记录文件 {}: {}", &[&path.display(), &e]));
+            }
+        };
+        serde_json::from_str(&content).map_err(|e| {
+            logging::error("storage", "抽签记录文件无法解析", &[("path", &path.display()), ("error", &e)]);
+            trf("抽签记录文件 {} 已损坏: {}", &[&path.display(), &e])
+        })
+    }
+    
+    /// 保存抽签记录（写入失败时返回错误，调用方不能当作已保存）
+    pub fn save_records(&self, records: &[DrawRecord]) -> Result<(), String> {
+        let path = self.records_path();
+        let content = serde_json::to_string_pretty(records).map_err(|e| e.to_string())?;
+        fs::write(&path, content).map_err(|e| {
+            logging::error("storage", "保存抽签记录失败", &[("path", &path.display()), ("error", &e)]);
+            trf("保存抽签记录失败 {}: {}", &[&path.display(), &e])
+        })
+    }
+    
+    /// 添加抽签记录（记录文件无法读取时不写入）
+    pub fn add_record(&self, record: DrawRecord) -> Result<(), String> {
+        self.add_records(std::slice::from_ref(&record))
+    }
+    
+    /// 一次添加多条抽签记录（检查组的多条记录一起写入，要么全部保存，要么都不保存）
+    pub fn add_records(&self, new_records: &[DrawRecord]) -> Result<(), String> {
+        let mut records = self.load_records()?;
+        records.extend_from_slice(new_records);
+        self.save_records(&records)
+    }
+    
+    /// 作废抽签记录
+    /// 
+    /// 记录不会被删除，作废原因、操作人和时间保存在记录中
+    pub fn void_record(&self, record_id: &str, reason: &str, operator: &Operator) -> Result<DrawRecord, String> {
+        if reason.trim().is_empty() {
+            return Err(tr("请填写作废原因").to_string());
+        }
+        
+        let mut records = self.load_records()?;
+        let record = records.iter_mut()
+            .find(|r| r.id == record_id)
+            .ok_or_else(|| tr("未找到该记录").to_string())?;
+        if record.is_voided() {
+            return Err(tr("该记录已作废").to_string());
+        }
+        
+        record.voided = Some(VoidInfo {
+            reason: reason.trim().to_string(),
+            operator: operator.display_name.clone(),
+            operator_id: Some(operator.id.clone()),
+            timestamp: Local::now(),
+        });
+        let voided = record.clone();
+        self.save_records(&records)?;
+        Ok(voided)
+    }
+    
+    /// 归档指定轮次的抽签记录
+    /// 
+    /// 归档的记录仍保留在数据文件中，只是不再出现在默认的历史列表和导出中。
+    /// 返回本次归档的记录数；记录文件无法读取或写入失败时返回错误
+    pub fn archive_round(&self, round: u32) -> Result<usize, String> {
+        let mut records = self.load_records()?;
+        let mut count = 0;
+        for record in records.iter_mut().filter(|r| r.round == round && !r.archived) {
+            record.archived = true;
+            count += 1;
+        }
+        if count > 0 {
+            self.save_records(&records)?;
+        }
+        Ok(count)
+    }
+    
+    /// 加载保存的当前轮次（重启程序或多次调用命令行时保持轮次）
+    pub fn load_current_round(&self) -> Option<u32> {
+        fs::read_to_string(self.round_path())
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|value| value.get("round_no")?.as_u64())
+            .map(|round| round as u32)
+    }
+    
+    /// 保存当前轮次
+    pub fn save_current_round(&self, round_no: u32) {
+        let content = serde_json::json!({ "round_no": round_no });
+        Self::write_file(&self.round_path(), content.to_string());
+    }
+    
+    /// 当前轮次：保存的轮次与记录中最大轮次取较大者，从未开始过时为下一轮
+    pub fn current_round(&self, records: &[DrawRecord]) -> u32 {
+        let max_recorded = records.iter().map(|r| r.round).max().unwrap_or(0);
+        match self.load_current_round() {
+            Some(saved) => saved.max(max_recorded),
+            None => max_recorded + 1,
+        }
+    }
+    
+    /// 加载保存的界面语言（未设置时为简体中文）
+    pub fn load_locale(&self) -> Locale {
+        fs::read_to_string(self.locale_path())
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|value| Locale::from_code(value.get("locale")?.as_str()?))
+            .unwrap_or_default()
+    }
+    
+    /// 保存界面语言
+    pub fn save_locale(&self, locale: Locale) {
+        let content = serde_json::json!({ "locale": locale.code() });
+        Self::write_file(&self.locale_path(), content.to_string());
+    }
+    
+    /// 加载显示设置（未设置时为深色主题、普通字号）
+    pub fn load_display_settings(&self) -> DisplaySettings {
+        fs::read_to_string(self.display_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+    
+    /// 保存显示设置
+    pub fn save_display_settings(&self, settings: &DisplaySettings) {
+        if let Ok(content) = serde_json::to_string_pretty(settings) {
+            Self::write_file(&self.display_path(), content);
+        }
+    }
+    
+    /// 加载转盘动画设置（未设置时为标准方案、手动停止）
+    pub fn load_animation_settings(&self) -> AnimationSettings {
+        fs::read_to_string(self.animation_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+    
+    /// 保存转盘动画设置
+    pub fn save_animation_settings(&self, settings: &AnimationSettings) {
+        if let Ok(content) = serde_json::to_string_pretty(settings) {
+            Self::write_file(&self.animation_path(), content);
+        }
+    }
+    
+    /// 加载检查日程安排
+    pub fn load_schedule(&self) -> Vec<ScheduleEntry> {
+        fs::read_to_string(self.schedule_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+    
+    /// 保存检查日程安排
+    pub fn save_schedule(&self, schedule: &[ScheduleEntry]) {
+        if let Ok(content) = serde_json::to_string_pretty(schedule) {
+            Self::write_file(&self.schedule_path(), content);
+        }
+    }
+    
+    /// 安排或修改一条记录的检查日期，返回修改前的安排
+    pub fn set_schedule(&self, entry: ScheduleEntry) -> Option<ScheduleEntry> {
+        let mut schedule = self.load_schedule();
+        let old = match schedule.iter_mut().find(|e| e.record_id == entry.record_id) {
+            Some(existing) => Some(std::mem::replace(existing, entry)),
+            None => {
+                schedule.push(entry);
+                None
+            }
+        };
+        self.save_schedule(&schedule);
+        old
+    }
+    
+    /// 取消一条记录的检查安排，返回被取消的安排
+    pub fn remove_schedule(&self, record_id: &str) -> Option<ScheduleEntry> {
+        let mut schedule = self.load_schedule();
+        let index = schedule.iter().position(|e| e.record_id == record_id)?;
+        let removed = schedule.remove(index);
+        self.save_schedule(&schedule);
+        Some(removed)
+    }
+    
+    /// 加载检查结果
+    pub fn load_outcomes(&self) -> Vec<InspectionOutcome> {
+        fs::read_to_string(self.outcomes_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+    
+    /// 保存检查结果
+    pub fn save_outcomes(&self, outcomes: &[InspectionOutcome]) {
+        if let Ok(content) = serde_json::to_string_pretty(outcomes) {
+            Self::write_file(&self.outcomes_path(), content);
+        }
+    }
+    
+    /// 登记或修改一条记录的检查结果，返回修改前的结果
+    pub fn set_outcome(&self, outcome: InspectionOutcome) -> Option<InspectionOutcome> {
+        let mut outcomes = self.load_outcomes();
+        let old = match outcomes.iter_mut().find(|o| o.record_id == outcome.record_id) {
+            Some(existing) => Some(std::mem::replace(existing, outcome)),
+            None => {
+                outcomes.push(outcome);
+                None
+            }
+        };
+        self.save_outcomes(&outcomes);
+        old
+    }
+    
+    /// 追加审计日志
+    /// 
+    /// 审计日志为只追加文件（每行一条JSON），不提供修改和删除接口
+    pub fn append_audit(&self, entry: &AuditEntry) {
+        if let Ok(line) = serde_json::to_string(entry) {
+            let result = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.audit_path())
+                .and_then(|mut file| writeln!(file, "{}", line));
+            if let Err(e) = result {
+                logging::error("storage", "写入审计日志失败", &[("path", &self.audit_path().display()), ("error", &e)]);
+            }
+        }
+    }
+    
+    /// 加载审计日志（按时间先后排列，无法解析的行会被跳过）
+    pub fn load_audit(&self) -> Vec<AuditEntry> {
+        fs::read_to_string(self.audit_path())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Default for DataStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
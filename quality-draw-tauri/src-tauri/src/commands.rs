@@ -1,390 +1,874 @@
 //! Tauri 命令模块 - 按部门抽签版本
 
 use tauri::State;
-use std::sync::Mutex;
-use rand::seq::SliceRandom;
-use crate::models::{Department, DrawRecord, SpecialtyType, DepartmentType, default_departments};
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use serde::Serialize;
+use crate::models::{
+    AuditAction, AuditEntry, ChangeStamp, Department, DrawRecord, Operator, Organization, QualitySpecialist, Role,
+    Specialty, TeamInfo, WitnessInfo, specialty_name,
+};
+use crate::logic::{AuthManager, CatalogManager, DrawEngine, DrawStatistics, StatsManager};
 use crate::storage::DataStore;
+use crate::export::ExportManager;
+use crate::error::AppError;
 
 /// 应用状态
 pub struct AppState {
     pub store: Mutex<DataStore>,
-    /// 本轮已抽中的承压部门列表 (被检部门ID, 抽中部门ID)
-    pub current_round_pressure_depts: Mutex<Vec<(String, String)>>,
-    /// 本轮已抽中的机电部门列表 (被检部门ID, 抽中部门ID)
-    pub current_round_mechanical_depts: Mutex<Vec<(String, String)>>,
+    /// 本轮已抽中的部门，按专业ID分组 (被检部门ID, 抽中部门ID)
+    pub current_round: Mutex<BTreeMap<String, Vec<(String, String)>>>,
+    /// 当前轮次
+    pub round_no: Mutex<u32>,
+    /// 等待见证人确认的抽签结果（确认后才保存并计入本轮）
+    pub pending: Mutex<Vec<DrawRecord>>,
+    /// 当前登录的操作员
+    pub operator: Mutex<Option<Operator>>,
 }
 
 impl Default for AppState {
     fn default() -> Self {
+        // 默认进入第一个单位，登录前可切换
+        let root = DataStore::new();
+        let store = root.for_organization(&root.load_organizations()[0].id);
+        let (round_no, current_round) = saved_round(&store);
         Self {
-            store: Mutex::new(DataStore::new()),
-            current_round_pressure_depts: Mutex::new(Vec::new()),
-            current_round_mechanical_depts: Mutex::new(Vec::new()),
+            store: Mutex::new(store),
+            current_round: Mutex::new(current_round),
+            round_no: Mutex::new(round_no),
+            pending: Mutex::new(Vec::new()),
+            operator: Mutex::new(None),
         }
     }
 }
 
+/// 恢复保存的轮次及该轮已抽中的列表（重启程序后继续未完成的一轮）
+///
+/// 记录文件无法读取时按空记录处理，此时登录会被拒绝，不会在不完整的本轮状态上抽签
+fn saved_round(store: &DataStore) -> (u32, BTreeMap<String, Vec<(String, String)>>) {
+    let records = store.load_records().unwrap_or_default();
+    let round_no = store.current_round(&records);
+    (round_no, DrawEngine::round_pairs_by_specialty(&records, round_no))
+}
+
+/// 返回给前端的操作员信息（不含密码哈希）
+#[derive(Serialize)]
+pub struct OperatorInfo {
+    pub id: String,
+    pub username: String,
+    pub display_name: String,
+    pub role: Role,
+}
+
+impl From<&Operator> for OperatorInfo {
+    fn from(operator: &Operator) -> Self {
+        Self {
+            id: operator.id.clone(),
+            username: operator.username.clone(),
+            display_name: operator.display_name.clone(),
+            role: operator.role,
+        }
+    }
+}
+
+/// 获取状态锁
+/// 
+/// 某个命令中途 panic 会使锁中毒，此后仍继续使用其中的数据，不让之后的命令都失败
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// 检查当前操作员权限，返回当前操作员
+fn require(state: &AppState, allowed: fn(&Role) -> bool) -> Result<Operator, AppError> {
+    match lock(&state.operator).as_ref() {
+        Some(operator) if allowed(&operator.role) => Ok(operator.clone()),
+        Some(operator) => Err(AppError::PermissionDenied(operator.role)),
+        None => Err(AppError::NotLoggedIn),
+    }
+}
+
+/// 加载操作员账户（账户文件损坏时返回错误，不能当作首次运行）
+fn load_operators(store: &DataStore) -> Result<Vec<Operator>, AppError> {
+    store.load_operators().map_err(AppError::AccountsUnavailable)
+}
+
+/// 加载抽签记录（记录文件损坏时返回错误，不能当作没有记录）
+fn load_records(store: &DataStore) -> Result<Vec<DrawRecord>, AppError> {
+    store.load_records().map_err(AppError::RecordsUnavailable)
+}
+
+/// 是否需要创建首个管理员账户（只有默认单位允许在登录界面创建，其他单位的管理员在新增单位时创建）
+#[tauri::command]
+pub fn needs_setup(state: State<AppState>) -> Result<bool, AppError> {
+    let store = lock(&state.store);
+    let empty = load_operators(&store)?.is_empty();
+    Ok(empty && store.is_default_organization())
+}
+
+/// 默认单位首次运行时创建管理员账户并登录
+#[tauri::command]
+pub fn setup_admin(
+    username: String,
+    display_name: String,
+    password: String,
+    state: State<AppState>,
+) -> Result<OperatorInfo, AppError> {
+    let store = lock(&state.store);
+    let mut operators = load_operators(&store)?;
+    if !operators.is_empty() {
+        return Err(AppError::AlreadySetUp);
+    }
+    if !store.is_default_organization() {
+        return Err(AppError::SetupNotAllowed);
+    }
+    let admin = AuthManager::create_operator(&operators, &username, &display_name, Role::Admin, &password)?;
+    operators.push(admin.clone());
+    store.save_operators(&operators);
+    store.append_audit(&AuditEntry::new(&admin, AuditAction::AddOperator, operator_label(&admin)).with_new(&admin.role));
+    
+    let info = OperatorInfo::from(&admin);
+    *lock(&state.operator) = Some(admin);
+    Ok(info)
+}
+
+/// 获取所有单位
+#[tauri::command]
+pub fn list_organizations(state: State<AppState>) -> Vec<Organization> {
+    let store = lock(&state.store);
+    store.load_organizations()
+}
+
+/// 获取当前单位
+#[tauri::command]
+pub fn get_organization(state: State<AppState>) -> Organization {
+    let store = lock(&state.store);
+    store.organization()
+}
+
+/// 切换单位（只能在登录前切换，各单位的数据相互独立）
+#[tauri::command]
+pub fn select_organization(organization_id: String, state: State<AppState>) -> Result<Organization, AppError> {
+    if lock(&state.operator).is_some() {
+        return Err(AppError::LogoutRequired);
+    }
+    let mut store = lock(&state.store);
+    lock(&state.pending).clear();
+    if !store.load_organizations().iter().any(|o| o.id == organization_id) {
+        return Err(AppError::OrganizationNotFound(organization_id));
+    }
+    *store = store.for_organization(&organization_id);
+    let (round_no, current_round) = saved_round(&store);
+    *lock(&state.round_no) = round_no;
+    *lock(&state.current_round) = current_round;
+    Ok(store.organization())
+}
+
+/// 登录
+#[tauri::command]
+pub fn login(username: String, password: String, state: State<AppState>) -> Result<OperatorInfo, AppError> {
+    let store = lock(&state.store);
+    // 抽签记录无法读取时拒绝登录（与桌面版一致），避免在缺少历史的本轮状态上抽签
+    load_records(&store)?;
+    let operator = AuthManager::authenticate(&load_operators(&store)?, &username, &password)?;
+    store.append_audit(&AuditEntry::new(&operator, AuditAction::Login, operator.label()));
+    let info = OperatorInfo::from(&operator);
+    *lock(&state.operator) = Some(operator);
+    Ok(info)
+}
+
+/// 注销（结果确认或取消前不能注销）
+#[tauri::command]
+pub fn logout(state: State<AppState>) -> Result<(), AppError> {
+    if !lock(&state.pending).is_empty() {
+        return Err(AppError::PendingConfirmation);
+    }
+    let operator = lock(&state.operator).take();
+    if let Some(operator) = operator {
+        let store = lock(&state.store);
+        store.append_audit(&AuditEntry::new(&operator, AuditAction::Logout, operator.label()));
+    }
+    Ok(())
+}
+
+/// 获取当前登录的操作员
+#[tauri::command]
+pub fn get_current_operator(state: State<AppState>) -> Option<OperatorInfo> {
+    lock(&state.operator).as_ref().map(OperatorInfo::from)
+}
+
+/// 获取所有操作员账户（管理员）
+#[tauri::command]
+pub fn list_operators(state: State<AppState>) -> Result<Vec<OperatorInfo>, AppError> {
+    require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    Ok(load_operators(&store)?.iter().map(OperatorInfo::from).collect())
+}
+
+/// 添加操作员账户（管理员）
+#[tauri::command]
+pub fn create_operator(
+    username: String,
+    display_name: String,
+    role: Role,
+    password: String,
+    state: State<AppState>,
+) -> Result<OperatorInfo, AppError> {
+    let current = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut operators = load_operators(&store)?;
+    let operator = AuthManager::create_operator(&operators, &username, &display_name, role, &password)?;
+    store.append_audit(&AuditEntry::new(&current, AuditAction::AddOperator, operator_label(&operator)).with_new(&role));
+    let info = OperatorInfo::from(&operator);
+    operators.push(operator);
+    store.save_operators(&operators);
+    Ok(info)
+}
+
+/// 修改操作员角色（管理员，至少保留一个管理员）
+#[tauri::command]
+pub fn set_operator_role(operator_id: String, role: Role, state: State<AppState>) -> Result<(), AppError> {
+    let current = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut operators = load_operators(&store)?;
+    if !AuthManager::keeps_admin(&operators, &operator_id, Some(role)) {
+        return Err(AppError::LastAdmin);
+    }
+    let operator = operators.iter_mut()
+        .find(|o| o.id == operator_id)
+        .ok_or_else(|| AppError::OperatorNotFound(operator_id.clone()))?;
+    store.append_audit(
+        &AuditEntry::new(&current, AuditAction::ChangeRole, operator_label(operator))
+            .with_old(&operator.role)
+            .with_new(&role),
+    );
+    operator.role = role;
+    if operator.id == current.id {
+        *lock(&state.operator) = Some(operator.clone());
+    }
+    store.save_operators(&operators);
+    Ok(())
+}
+
+/// 重设操作员密码（管理员）
+#[tauri::command]
+pub fn reset_operator_password(operator_id: String, password: String, state: State<AppState>) -> Result<(), AppError> {
+    let current = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut operators = load_operators(&store)?;
+    let operator = operators.iter_mut()
+        .find(|o| o.id == operator_id)
+        .ok_or_else(|| AppError::OperatorNotFound(operator_id.clone()))?;
+    AuthManager::set_password(operator, &password)?;
+    store.append_audit(&AuditEntry::new(&current, AuditAction::ResetPassword, operator_label(operator)));
+    store.save_operators(&operators);
+    Ok(())
+}
+
+/// 删除操作员账户（管理员，不能删除自己）
+#[tauri::command]
+pub fn delete_operator(operator_id: String, state: State<AppState>) -> Result<(), AppError> {
+    let current = require(&state, Role::can_manage)?;
+    if operator_id == current.id {
+        return Err(AppError::DeleteSelf);
+    }
+    let store = lock(&state.store);
+    let mut operators = load_operators(&store)?;
+    if !AuthManager::keeps_admin(&operators, &operator_id, None) {
+        return Err(AppError::LastAdmin);
+    }
+    if let Some(removed) = operators.iter().find(|o| o.id == operator_id) {
+        store.append_audit(
+            &AuditEntry::new(&current, AuditAction::DeleteOperator, operator_label(removed)).with_old(&removed.role),
+        );
+    }
+    operators.retain(|o| o.id != operator_id);
+    store.save_operators(&operators);
+    Ok(())
+}
+
+/// 获取审计日志（最新在前）
+#[tauri::command]
+pub fn get_audit_log(state: State<AppState>) -> Result<Vec<AuditEntry>, AppError> {
+    require(&state, |_| true)?;
+    let store = lock(&state.store);
+    let mut entries = store.load_audit();
+    entries.reverse();
+    Ok(entries)
+}
+
+/// 导出审计日志到 Excel
+#[tauri::command]
+pub fn export_audit_to_excel(state: State<AppState>) -> Result<String, AppError> {
+    require(&state, |_| true)?;
+    let store = lock(&state.store);
+    let entries = store.load_audit();
+    if entries.is_empty() {
+        return Err(AppError::NothingToExport);
+    }
+    
+    let path = desktop_file("审计日志", "xlsx");
+    ExportManager::export_audit_to_excel(&entries, &path).map_err(AppError::Export)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
 /// 获取所有部门
 #[tauri::command]
 pub fn get_departments(state: State<AppState>) -> Vec<Department> {
-    let store = state.store.lock().unwrap();
+    let store = lock(&state.store);
     store.load_departments()
 }
 
+/// 获取所有专业
+#[tauri::command]
+pub fn get_specialties(state: State<AppState>) -> Vec<Specialty> {
+    let store = lock(&state.store);
+    store.load_specialties()
+}
+
+/// 获取所有质量专责
+#[tauri::command]
+pub fn get_specialists(state: State<AppState>) -> Vec<QualitySpecialist> {
+    let store = lock(&state.store);
+    store.load_specialists()
+}
+
+/// 添加部门（管理员），需要抽取和可以派出的专业相同，可再单独修改
+#[tauri::command]
+pub fn add_department(
+    name: String,
+    category: String,
+    specialties: Vec<String>,
+    state: State<AppState>,
+) -> Result<Department, AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut departments = store.load_departments();
+    let chosen: Vec<&str> = specialties.iter().map(String::as_str).collect();
+    let department = Department::new(uuid::Uuid::new_v4().to_string(), name.trim(), category.trim(), &chosen)
+        .stamped(ChangeStamp::now(&operator));
+    CatalogManager::validate_department(&departments, &store.load_specialties(), &department)?;
+    
+    store.append_audit(&AuditEntry::new(&operator, AuditAction::AddDepartment, department_label(&department)).with_new(&department));
+    departments.push(department.clone());
+    store.save_departments(&departments);
+    Ok(department)
+}
+
+/// 修改部门（管理员）：名称、分类、需要抽取和可以派出的专业及检查组部门数
+#[tauri::command]
+pub fn update_department(department: Department, state: State<AppState>) -> Result<Department, AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut departments = store.load_departments();
+    let index = departments.iter()
+        .position(|d| d.id == department.id)
+        .ok_or_else(|| AppError::DepartmentNotFound(department.id.clone()))?;
+    
+    let mut updated = department.stamped(ChangeStamp::now(&operator));
+    updated.name = updated.name.trim().to_string();
+    updated.category = updated.category.trim().to_string();
+    // 检查组部门数为1时不单独保存
+    for (specialty_id, size) in std::mem::take(&mut updated.team_sizes) {
+        updated.set_team_size(&specialty_id, size);
+    }
+    CatalogManager::validate_department(&departments, &store.load_specialties(), &updated)?;
+    
+    store.append_audit(
+        &AuditEntry::new(&operator, AuditAction::UpdateDepartment, department_label(&updated))
+            .with_old(&departments[index])
+            .with_new(&updated),
+    );
+    departments[index] = updated.clone();
+    store.save_departments(&departments);
+    Ok(updated)
+}
+
+/// 删除部门（管理员）
+#[tauri::command]
+pub fn delete_department(department_id: String, state: State<AppState>) -> Result<(), AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut departments = store.load_departments();
+    let index = departments.iter()
+        .position(|d| d.id == department_id)
+        .ok_or(AppError::DepartmentNotFound(department_id))?;
+    // 仍有专责或本轮抽签结果引用的部门不能删除，避免留下无效的引用
+    let current_round = lock(&state.current_round);
+    if CatalogManager::department_in_use(&store.load_specialists(), current_round.values().flatten(), &departments[index].id) {
+        return Err(AppError::DepartmentInUse(departments[index].name.clone()));
+    }
+    let removed = departments.remove(index);
+    store.save_departments(&departments);
+    store.append_audit(&AuditEntry::new(&operator, AuditAction::DeleteDepartment, department_label(&removed)).with_old(&removed));
+    Ok(())
+}
+
+/// 添加质量专责（管理员）
+#[tauri::command]
+pub fn add_specialist(
+    name: String,
+    department_id: String,
+    specialty_id: String,
+    state: State<AppState>,
+) -> Result<QualitySpecialist, AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut specialists = store.load_specialists();
+    let departments = store.load_departments();
+    let specialties = store.load_specialties();
+    let specialist = QualitySpecialist::new(uuid::Uuid::new_v4().to_string(), name.trim(), department_id, specialty_id)
+        .stamped(ChangeStamp::now(&operator));
+    CatalogManager::validate_specialist(&specialists, &departments, &specialties, &specialist)?;
+    
+    store.append_audit(
+        &AuditEntry::new(&operator, AuditAction::AddSpecialist, specialist_label(&specialist, &departments, &specialties))
+            .with_new(&specialist),
+    );
+    specialists.push(specialist.clone());
+    store.save_specialists(&specialists);
+    Ok(specialist)
+}
+
+/// 修改质量专责（管理员）：姓名、所属部门和专业
+#[tauri::command]
+pub fn update_specialist(
+    specialist_id: String,
+    name: String,
+    department_id: String,
+    specialty_id: String,
+    state: State<AppState>,
+) -> Result<QualitySpecialist, AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut specialists = store.load_specialists();
+    let departments = store.load_departments();
+    let specialties = store.load_specialties();
+    let index = specialists.iter()
+        .position(|s| s.id == specialist_id)
+        .ok_or_else(|| AppError::SpecialistNotFound(specialist_id.clone()))?;
+    let updated = QualitySpecialist::new(specialist_id, name.trim(), department_id, specialty_id)
+        .stamped(ChangeStamp::now(&operator));
+    CatalogManager::validate_specialist(&specialists, &departments, &specialties, &updated)?;
+    
+    store.append_audit(
+        &AuditEntry::new(&operator, AuditAction::UpdateSpecialist, specialist_label(&updated, &departments, &specialties))
+            .with_old(&specialists[index])
+            .with_new(&updated),
+    );
+    specialists[index] = updated.clone();
+    store.save_specialists(&specialists);
+    Ok(updated)
+}
+
+/// 删除质量专责（管理员）
+#[tauri::command]
+pub fn delete_specialist(specialist_id: String, state: State<AppState>) -> Result<(), AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut specialists = store.load_specialists();
+    let index = specialists.iter()
+        .position(|s| s.id == specialist_id)
+        .ok_or(AppError::SpecialistNotFound(specialist_id))?;
+    let removed = specialists.remove(index);
+    store.save_specialists(&specialists);
+    let label = specialist_label(&removed, &store.load_departments(), &store.load_specialties());
+    store.append_audit(&AuditEntry::new(&operator, AuditAction::DeleteSpecialist, label).with_old(&removed));
+    Ok(())
+}
+
+/// 添加专业（管理员），添加后在部门管理中设置需要和派出该专业的部门
+#[tauri::command]
+pub fn add_specialty(name: String, state: State<AppState>) -> Result<Specialty, AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut specialties = store.load_specialties();
+    let specialty = Specialty::new(uuid::Uuid::new_v4().to_string(), name.trim()).stamped(ChangeStamp::now(&operator));
+    CatalogManager::validate_specialty(&specialties, &specialty)?;
+    
+    store.append_audit(&AuditEntry::new(&operator, AuditAction::AddSpecialty, specialty.name.clone()).with_new(&specialty));
+    specialties.push(specialty.clone());
+    store.save_specialties(&specialties);
+    Ok(specialty)
+}
+
+/// 删除专业（管理员）
+/// 
+/// 仍被部门或专责使用的专业不能删除，历史记录中的名称不受影响
+#[tauri::command]
+pub fn delete_specialty(specialty_id: String, state: State<AppState>) -> Result<(), AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let mut specialties = store.load_specialties();
+    let index = specialties.iter()
+        .position(|s| s.id == specialty_id)
+        .ok_or_else(|| AppError::SpecialtyNotFound(specialty_id.clone()))?;
+    if CatalogManager::specialty_in_use(&store.load_departments(), &store.load_specialists(), &specialty_id) {
+        return Err(AppError::SpecialtyInUse(specialties[index].name.clone()));
+    }
+    let removed = specialties.remove(index);
+    store.save_specialties(&specialties);
+    store.append_audit(&AuditEntry::new(&operator, AuditAction::DeleteSpecialty, removed.name.clone()).with_old(&removed));
+    Ok(())
+}
+
 /// 获取抽签记录
 #[tauri::command]
-pub fn get_records(state: State<AppState>) -> Vec<DrawRecord> {
-    let store = state.store.lock().unwrap();
-    store.load_records()
+pub fn get_records(state: State<AppState>) -> Result<Vec<DrawRecord>, AppError> {
+    let store = lock(&state.store);
+    load_records(&store)
+}
+
+/// 作废抽签记录
+/// 
+/// 作废本轮的记录时同时释放本轮对应的抽签位置，被检部门可以重新抽取
+#[tauri::command]
+pub fn void_record(
+    record_id: String,
+    reason: String,
+    state: State<AppState>,
+) -> Result<DrawRecord, AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let original = load_records(&store)?.into_iter().find(|r| r.id == record_id);
+    let record = store.void_record(&record_id, &reason, &operator)?;
+    let target = format!(
+        "{} {} {}",
+        record.target_department_name,
+        record.specialty_label(),
+        record.selected_specialist_name
+    );
+    let mut entry = AuditEntry::new(&operator, AuditAction::VoidRecord, target).with_new(&record);
+    if let Some(original) = original {
+        entry = entry.with_old(&original);
+    }
+    store.append_audit(&entry);
+    
+    if record.round == *lock(&state.round_no) {
+        if let Some(pairs) = lock(&state.current_round).get_mut(&record.specialty_type) {
+            pairs.retain(|(target, selected)| {
+                !(target == &record.target_department_id && selected == &record.selected_from_department_id)
+            });
+        }
+    }
+    
+    Ok(record)
+}
+
+/// 归档指定轮次的抽签记录，返回归档的记录数
+#[tauri::command]
+pub fn archive_round(round: u32, state: State<AppState>) -> Result<usize, AppError> {
+    let operator = require(&state, Role::can_manage)?;
+    let store = lock(&state.store);
+    let count = store.archive_round(round).map_err(AppError::RecordsUnavailable)?;
+    store.append_audit(&AuditEntry::new(
+        &operator,
+        AuditAction::ArchiveRound,
+        format!("{}（{}条记录）", round_label(round), count),
+    ));
+    Ok(count)
 }
 
-/// 清空抽签记录
+/// 获取当前轮次
 #[tauri::command]
-pub fn clear_records(state: State<AppState>) {
-    let store = state.store.lock().unwrap();
-    store.clear_records();
+pub fn get_round_no(state: State<AppState>) -> u32 {
+    *lock(&state.round_no)
 }
 
 /// 开始新一轮抽签（清空本轮已抽中列表）
 #[tauri::command]
-pub fn start_new_round(state: State<AppState>) {
-    let mut pressure_depts = state.current_round_pressure_depts.lock().unwrap();
-    let mut mechanical_depts = state.current_round_mechanical_depts.lock().unwrap();
-    pressure_depts.clear();
-    mechanical_depts.clear();
+pub fn start_new_round(state: State<AppState>) -> Result<(), AppError> {
+    require(&state, Role::can_draw)?;
+    if !lock(&state.pending).is_empty() {
+        return Err(AppError::PendingConfirmation);
+    }
+    let store = lock(&state.store);
+    lock(&state.current_round).clear();
+    let mut round_no = lock(&state.round_no);
+    *round_no += 1;
+    store.save_current_round(*round_no);
+    Ok(())
 }
 
-/// 获取本轮已抽中的记录
+/// 获取本轮已抽中的记录（专业ID → (被检部门ID, 抽中部门ID) 列表）
 #[tauri::command]
-pub fn get_current_round_status(state: State<AppState>) -> (Vec<(String, String)>, Vec<(String, String)>) {
-    let pressure_depts = state.current_round_pressure_depts.lock().unwrap();
-    let mechanical_depts = state.current_round_mechanical_depts.lock().unwrap();
-    (pressure_depts.clone(), mechanical_depts.clone())
+pub fn get_current_round_status(state: State<AppState>) -> BTreeMap<String, Vec<(String, String)>> {
+    lock(&state.current_round).clone()
+}
+
+/// 按专业ID查找专业
+fn find_specialty(store: &DataStore, specialty_id: &str) -> Result<Specialty, AppError> {
+    store.load_specialties()
+        .into_iter()
+        .find(|s| s.id == specialty_id)
+        .ok_or_else(|| AppError::SpecialtyNotFound(specialty_id.to_string()))
 }
 
 /// 获取可抽取的部门列表（用于动画滚动）
 #[tauri::command]
 pub fn get_candidate_departments(
     target_department_id: String,
-    specialty_type: String,
+    specialty_id: String,
     state: State<AppState>,
-) -> Vec<String> {
-    let store = state.store.lock().unwrap();
+) -> Result<Vec<String>, AppError> {
+    let store = lock(&state.store);
     let departments = store.load_departments();
-    
-    let current_round = if specialty_type == "Pressure" {
-        state.current_round_pressure_depts.lock().unwrap()
-    } else {
-        state.current_round_mechanical_depts.lock().unwrap()
-    };
-    
-    // 本轮已被抽中作为检查员的部门ID
-    let already_selected: Vec<&String> = current_round.iter().map(|(_, selected)| selected).collect();
-    
-    // 交叉回避：如果当前被检部门曾被派去检查其他部门，那些部门不能来检查这个部门
-    let cross_avoidance: Vec<&String> = current_round.iter()
-        .filter(|(_, selected)| selected == &target_department_id)
-        .map(|(target, _)| target)
-        .collect();
-    
-    departments.iter()
-        .filter(|d| {
-            // 根据专业类型筛选
-            let type_match = if specialty_type == "Pressure" {
-                d.department_type == DepartmentType::Comprehensive || 
-                d.department_type == DepartmentType::Pressure
-            } else {
-                d.department_type == DepartmentType::Comprehensive || 
-                d.department_type == DepartmentType::Mechanical
-            };
-            
-            type_match
-            // 排除被检查的部门
-            && d.id != target_department_id
-            // 排除本轮已被抽中的部门
-            && !already_selected.contains(&&d.id)
-            // 排除交叉回避的部门
-            && !cross_avoidance.contains(&&d.id)
-        })
+    let specialty = find_specialty(&store, &specialty_id)?;
+    let current_round = lock(&state.current_round);
+    let pairs = current_round.get(&specialty.id).map(Vec::as_slice).unwrap_or(&[]);
+    let rules = store.organization().rules;
+    
+    Ok(DrawEngine::get_candidate_departments(&departments, &target_department_id, &specialty, pairs, &rules)
+        .departments
+        .iter()
         .map(|d| d.name.clone())
-        .collect()
+        .collect())
 }
 
 /// 执行抽签的结果
 #[derive(serde::Serialize)]
 pub struct DrawResult {
-    pub success: bool,
-    pub department_name: Option<String>,
-    pub department_id: Option<String>,
-    pub specialty_type: Option<String>,
-    pub message: Option<String>,
+    /// 第一个抽中部门名称
+    pub department_name: String,
+    /// 第一个抽中部门ID
+    pub department_id: String,
+    /// 检查组全部抽中部门名称（按抽中顺序）
+    pub team: Vec<String>,
+    /// 专业ID
+    pub specialty_id: String,
 }
 
 /// 执行抽签（抽取部门）
+/// 
+/// 按被检部门设置的检查组部门数一次抽取本轮尚缺的全部部门，每个部门一条记录。
+/// 结果等待见证人确认（`confirm_draw`）后才保存并计入本轮；确认或取消前只能继续抽取同一被检部门的其他专业
 #[tauri::command]
 pub fn execute_draw(
     target_department_id: String,
-    specialty_type: String,
+    specialty_id: String,
     state: State<AppState>,
-) -> DrawResult {
-    let store = state.store.lock().unwrap();
+) -> Result<DrawResult, AppError> {
+    let operator = require(&state, Role::can_draw)?;
+    let store = lock(&state.store);
     let departments = store.load_departments();
+    let mut pending = lock(&state.pending);
+    if pending.iter().any(|r| r.target_department_id != target_department_id) {
+        return Err(AppError::PendingConfirmation);
+    }
     
-    // 获取当前轮次已抽中列表
-    let (mut current_round, dept_type_filter) = if specialty_type == "Pressure" {
-        let round = state.current_round_pressure_depts.lock().unwrap().clone();
-        (round, vec![DepartmentType::Comprehensive, DepartmentType::Pressure])
-    } else if specialty_type == "Mechanical" {
-        let round = state.current_round_mechanical_depts.lock().unwrap().clone();
-        (round, vec![DepartmentType::Comprehensive, DepartmentType::Mechanical])
-    } else {
-        return DrawResult {
-            success: false,
-            department_name: None,
-            department_id: None,
-            specialty_type: None,
-            message: Some("无效的专责类型".to_string()),
-        };
-    };
-    
-    // 找到目标部门
-    let target_department = match departments.iter().find(|d| d.id == target_department_id) {
-        Some(d) => d,
-        None => return DrawResult {
-            success: false,
-            department_name: None,
-            department_id: None,
-            specialty_type: None,
-            message: Some("未找到目标部门".to_string()),
-        },
+    // 获取当前轮次该专业已抽中列表（含待确认的结果）
+    let specialty = find_specialty(&store, &specialty_id)?;
+    let mut current_round = lock(&state.current_round)
+        .get(&specialty.id)
+        .cloned()
+        .unwrap_or_default();
+    current_round.extend(pending.iter()
+        .filter(|r| r.specialty_type == specialty.id)
+        .map(|r| (r.target_department_id.clone(), r.selected_from_department_id.clone())));
+    
+    // 按随机种子抽取本轮尚缺的检查部门（排除被检部门、检查组已有部门、本轮已抽中部门、交叉回避部门）
+    let rules = store.organization().rules;
+    let draw = DrawEngine::draw_department(
+        &departments,
+        &target_department_id,
+        &specialty,
+        &current_round,
+        &rules,
+        rand::random::<u64>(),
+    )?;
+    let target_department = departments.iter()
+        .find(|d| d.id == target_department_id)
+        .ok_or_else(|| AppError::DepartmentNotFound(target_department_id.clone()))?;
+    let selected = &draw.selected;
+    let Some(first) = selected.first() else {
+        return Err(AppError::NoCandidates(specialty.name.clone()));
     };
     
-    // 本轮已被抽中作为检查员的部门ID
-    let already_selected: Vec<&String> = current_round.iter().map(|(_, selected)| selected).collect();
-    
-    // 交叉回避
-    let cross_avoidance: Vec<&String> = current_round.iter()
-        .filter(|(_, selected)| selected == &target_department_id)
-        .map(|(target, _)| target)
-        .collect();
-    
-    // 获取候选部门
-    let candidates: Vec<&Department> = departments.iter()
-        .filter(|d| {
-            dept_type_filter.contains(&d.department_type)
-            && d.id != target_department_id
-            && !already_selected.contains(&&d.id)
-            && !cross_avoidance.contains(&&d.id)
-        })
-        .collect();
-    
-    if candidates.is_empty() {
-        return DrawResult {
-            success: false,
-            department_name: None,
-            department_id: None,
-            specialty_type: None,
-            message: Some("没有符合条件的候选部门".to_string()),
-        };
+    // 生成待确认记录，检查组的记录共用组ID
+    let round_no = *lock(&state.round_no);
+    let group_id = uuid::Uuid::new_v4().to_string();
+    for (position, dept) in selected.iter().enumerate() {
+        let mut record = DrawRecord::new(
+            &target_department.id,
+            &target_department.name,
+            &specialty,
+            &dept.id,        // 用部门ID代替人员ID
+            &dept.name,      // 用部门名称代替人员名称
+            &dept.id,
+            &dept.name,
+        ).with_draw_info(round_no, draw.seed, draw.candidates.clone(), draw.rule_notes.clone())
+        .with_operator(operator.id.clone(), operator.display_name.clone());
+        if selected.len() > 1 {
+            record = record.with_team(TeamInfo { group_id: group_id.clone(), position, size: selected.len() });
+        }
+        pending.push(record);
     }
     
-    // 随机抽取
-    let mut rng = rand::thread_rng();
-    let selected = candidates.choose(&mut rng).unwrap();
-    
-    // 保存到本轮列表
-    if specialty_type == "Pressure" {
-        let mut pressure_depts = state.current_round_pressure_depts.lock().unwrap();
-        pressure_depts.push((target_department_id.clone(), selected.id.clone()));
-    } else {
-        let mut mechanical_depts = state.current_round_mechanical_depts.lock().unwrap();
-        mechanical_depts.push((target_department_id.clone(), selected.id.clone()));
+    Ok(DrawResult {
+        department_name: first.name.clone(),
+        department_id: first.id.clone(),
+        team: selected.iter().map(|d| d.name.clone()).collect(),
+        specialty_id: specialty.id,
+    })
+}
+
+/// 获取等待见证人确认的抽签结果
+#[tauri::command]
+pub fn get_pending_draws(state: State<AppState>) -> Vec<DrawRecord> {
+    lock(&state.pending).clone()
+}
+
+/// 见证人确认待确认的结果：验证见证人（执行抽签的操作员以外的账户）后保存记录并计入本轮
+#[tauri::command]
+pub fn confirm_draw(witness_username: String, witness_password: String, state: State<AppState>) -> Result<usize, AppError> {
+    let operator = require(&state, Role::can_draw)?;
+    let store = lock(&state.store);
+    let mut pending = lock(&state.pending);
+    if pending.is_empty() {
+        return Err(AppError::NothingPending);
     }
-    
-    // 保存历史记录
-    let specialty = if specialty_type == "Pressure" {
-        SpecialtyType::Pressure
-    } else {
-        SpecialtyType::Mechanical
-    };
-    
-    let record = DrawRecord::new(
-        &target_department.id,
-        &target_department.name,
-        specialty,
-        &selected.id,        // 用部门ID代替人员ID
-        &selected.name,      // 用部门名称代替人员名称
-        &selected.id,
-        &selected.name,
-    );
-    store.add_record(record);
-    
-    DrawResult {
-        success: true,
-        department_name: Some(selected.name.clone()),
-        department_id: Some(selected.id.clone()),
-        specialty_type: Some(specialty_type),
-        message: None,
+    let witness = AuthManager::authenticate_witness(
+        &load_operators(&store)?,
+        &operator.id,
+        &witness_username,
+        &witness_password,
+    )?;
+    
+    // 先保存再计入本轮，保存失败时结果仍待确认，不写审计日志
+    let info = WitnessInfo::new(&witness, None);
+    let records: Vec<DrawRecord> = pending.iter().map(|r| r.clone().with_witness(info.clone())).collect();
+    store.add_records(&records).map_err(AppError::SaveFailed)?;
+    pending.clear();
+    let mut current_round = lock(&state.current_round);
+    for record in &records {
+        current_round
+            .entry(record.specialty_type.clone())
+            .or_default()
+            .push((record.target_department_id.clone(), record.selected_from_department_id.clone()));
     }
+    store.save_current_round(*lock(&state.round_no));
+    store.append_audit(&AuditEntry::new(&witness, AuditAction::ConfirmDraw, draw_label(&records)).with_new(&info));
+    Ok(records.len())
 }
 
-/// 导出记录到 Excel
+/// 取消待确认的结果（需填写原因并记入审计日志），被检部门可以重新抽取
 #[tauri::command]
-pub fn export_to_excel(state: State<AppState>) -> Result<String, String> {
-    use rust_xlsxwriter::*;
-    
-    let store = state.store.lock().unwrap();
-    let records = store.load_records();
-    
-    if records.is_empty() {
-        return Err("没有可导出的记录".to_string());
+pub fn cancel_draw(reason: String, state: State<AppState>) -> Result<(), AppError> {
+    let operator = require(&state, Role::can_draw)?;
+    let reason = reason.trim().to_string();
+    if reason.is_empty() {
+        return Err(AppError::Invalid("请填写取消原因".to_string()));
     }
-    
-    let mut workbook = Workbook::new();
-    let worksheet = workbook.add_worksheet();
-    
-    let header_format = Format::new()
-        .set_bold()
-        .set_align(FormatAlign::Center);
-    
-    worksheet.write_with_format(0, 0, "序号", &header_format).map_err(|e| e.to_string())?;
-    worksheet.write_with_format(0, 1, "抽签时间", &header_format).map_err(|e| e.to_string())?;
-    worksheet.write_with_format(0, 2, "被检部门", &header_format).map_err(|e| e.to_string())?;
-    worksheet.write_with_format(0, 3, "专责类型", &header_format).map_err(|e| e.to_string())?;
-    worksheet.write_with_format(0, 4, "抽中部门", &header_format).map_err(|e| e.to_string())?;
-    
-    for (i, record) in records.iter().enumerate() {
-        let row = (i + 1) as u32;
-        worksheet.write(row, 0, (i + 1) as i32).map_err(|e| e.to_string())?;
-        worksheet.write(row, 1, record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string()).map_err(|e| e.to_string())?;
-        worksheet.write(row, 2, &record.target_department_name).map_err(|e| e.to_string())?;
-        worksheet.write(row, 3, record.specialty_type.display_name()).map_err(|e| e.to_string())?;
-        worksheet.write(row, 4, &record.selected_specialist_name).map_err(|e| e.to_string())?; // 实际是部门名称
+    let store = lock(&state.store);
+    let cancelled = std::mem::take(&mut *lock(&state.pending));
+    if cancelled.is_empty() {
+        return Err(AppError::NothingPending);
     }
-    
-    worksheet.set_column_width(0, 8).map_err(|e| e.to_string())?;
-    worksheet.set_column_width(1, 20).map_err(|e| e.to_string())?;
-    worksheet.set_column_width(2, 20).map_err(|e| e.to_string())?;
-    worksheet.set_column_width(3, 12).map_err(|e| e.to_string())?;
-    worksheet.set_column_width(4, 20).map_err(|e| e.to_string())?;
-    
-    let filename = format!("抽签记录_{}.xlsx", chrono::Local::now().format("%Y%m%d_%H%M%S"));
-    let desktop_path = dirs::desktop_dir()
-        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
-    let file_path = desktop_path.join(&filename);
-    
-    workbook.save(&file_path).map_err(|e| e.to_string())?;
-    
-    Ok(file_path.to_string_lossy().to_string())
+    store.append_audit(&AuditEntry::new(&operator, AuditAction::CancelDraw, draw_label(&cancelled))
+        .with_old(&cancelled)
+        .with_new(&reason));
+    Ok(())
 }
 
-/// 导出记录到 PDF
+/// 导出记录到 Excel
 #[tauri::command]
-pub fn export_to_pdf(state: State<AppState>) -> Result<String, String> {
-    use genpdf::{Document, SimplePageDecorator};
-    use genpdf::fonts;
-    use genpdf::elements::{Paragraph, TableLayout, Text, Break};
-    use genpdf::style::{Style, StyledString};
-    
-    let store = state.store.lock().unwrap();
-    let records = store.load_records();
-    
-    if records.is_empty() {
-        return Err("没有可导出的记录".to_string());
+pub fn export_to_excel(state: State<AppState>) -> Result<String, AppError> {
+    require(&state, |_| true)?;
+    let store = lock(&state.store);
+    let records = active_records(&store)?;
+    
+    let path = desktop_file("抽签记录", "xlsx");
+    ExportManager::export_to_excel(&records, &store.organization(), &path).map_err(AppError::Export)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 获取抽签统计（全部有效记录，含已归档）
+#[tauri::command]
+pub fn get_statistics(state: State<AppState>) -> Result<DrawStatistics, AppError> {
+    require(&state, |_| true)?;
+    let store = lock(&state.store);
+    Ok(StatsManager::compute(&load_records(&store)?, &store.load_departments(), &store.load_specialties()))
+}
+
+/// 导出抽签统计到 Excel：部门次数、配对热力图、公平性检验和季度趋势四个工作表
+#[tauri::command]
+pub fn export_statistics_to_excel(state: State<AppState>) -> Result<String, AppError> {
+    require(&state, |_| true)?;
+    let store = lock(&state.store);
+    let stats = StatsManager::compute(&load_records(&store)?, &store.load_departments(), &store.load_specialties());
+    if stats.total_draws == 0 {
+        return Err(AppError::NothingToExport);
     }
     
-    // 尝试加载中文字体，Windows下通常是 simhei.ttf
-    let font_dir = "C:/Windows/Fonts";
-    // 尝试列表：黑体 -> 仿宋 -> 楷体 -> 宋体黑 -> Arial
-    // 注意：msyh.ttc 是 TrueType Collection 格式，genpdf 可能不支持
-    let font_candidates = vec!["simhei.ttf", "simfang.ttf", "simkai.ttf", "simsunb.ttf", "arial.ttf"];
-    
-    let mut font_data = None;
-    for name in font_candidates {
-        let path = std::path::Path::new(font_dir).join(name);
-        if path.exists() {
-            if let Ok(data) = std::fs::read(&path) {
-                font_data = Some(data);
-                break;
-            }
-        }
+    let path = desktop_file("抽签统计", "xlsx");
+    ExportManager::export_statistics_to_excel(&stats, &store.organization(), &path).map_err(AppError::Export)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 导出记录到 PDF（与桌面版相同，没有 HTML 转换工具时用内置排版生成）
+#[tauri::command]
+pub fn export_to_pdf(state: State<AppState>) -> Result<String, AppError> {
+    require(&state, |_| true)?;
+    let store = lock(&state.store);
+    let records = active_records(&store)?;
+    
+    let path = desktop_file("抽签记录", "pdf");
+    ExportManager::export_to_pdf(&records, &store.organization(), &path).map_err(AppError::Export)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 未归档的记录（含作废记录），没有可导出的记录时报错
+fn active_records(store: &DataStore) -> Result<Vec<DrawRecord>, AppError> {
+    let records: Vec<DrawRecord> = load_records(&store)?.into_iter().filter(|r| !r.archived).collect();
+    if records.is_empty() {
+        return Err(AppError::NothingToExport);
     }
-    
-    let font_data = font_data.ok_or("无法找到合适的中文字体(simhei.ttf, simfang.ttf, simkai.ttf, simsunb.ttf, arial.ttf)".to_string())?;
-    
-    // 解析字体数据
-    let font = fonts::FontData::new(font_data, None)
-        .map_err(|e| format!("解析字体数据失败: {}", e))?;
-        
-    // 创建字体族，强制所有样式都使用同一个字体文件
-    // 因为中文字体通常只有一个文件，没有分开的 Bold/Italic 文件
-    let font_family = fonts::FontFamily {
-        regular: font.clone(),
-        bold: font.clone(),
-        italic: font.clone(),
-        bold_italic: font,
-    };
-    
-    let mut doc = Document::new(font_family);
-    doc.set_title("抽签历史记录");
-    
-    // 页面装饰
-    let mut decorator = SimplePageDecorator::new();
-    decorator.set_margins(20);
-    doc.set_page_decorator(decorator);
-    
-    // 标题
-    let title_style = Style::new().bold().with_font_size(18);
-    doc.push(Paragraph::new(StyledString::new("宁夏特检院质量监督检查抽签记录", title_style)));
-    doc.push(Break::new(1));
-    
-    // 导出时间
-    let time_str = format!("导出时间: {}", chrono::Local::now().format("%Y年%m月%d日 %H:%M:%S"));
-    doc.push(Paragraph::new(time_str));
-    doc.push(Break::new(1));
-    
-    // 表格
-    let mut table = TableLayout::new(vec![1, 3, 3, 2, 3]);
-    table.set_cell_decorator(genpdf::elements::FrameCellDecorator::new(true, true, false));
-    
-    // 表头
-    let header_style = Style::new().bold();
-    let row = table.row();
-    row.element(Text::new(StyledString::new("序号", header_style.clone())))
-       .element(Text::new(StyledString::new("抽签时间", header_style.clone())))
-       .element(Text::new(StyledString::new("被检部门", header_style.clone())))
-       .element(Text::new(StyledString::new("专责类型", header_style.clone())))
-       .element(Text::new(StyledString::new("抽中部门", header_style)))
-       .push()
-       .map_err(|e| format!("添加表头失败: {}", e))?;
-    
-    // 数据行
-    for (i, record) in records.iter().enumerate() {
-        let row = table.row();
-        row.element(Text::new(format!("{}", i + 1)))
-           .element(Text::new(record.timestamp.format("%Y-%m-%d %H:%M").to_string()))
-           .element(Text::new(&record.target_department_name))
-           .element(Text::new(record.specialty_type.display_name()))
-           .element(Text::new(&record.selected_specialist_name))
-           .push()
-           .map_err(|e| format!("添加数据行失败: {}", e))?;
+    Ok(records)
+}
+
+/// 桌面上带时间戳的导出文件路径
+fn desktop_file(prefix: &str, extension: &str) -> std::path::PathBuf {
+    let desktop = dirs::desktop_dir().unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+    desktop.join(format!("{}_{}.{}", prefix, chrono::Local::now().format("%Y%m%d_%H%M%S"), extension))
+}
+
+/// 审计日志中的账户说明（不包含密码信息）
+fn operator_label(operator: &Operator) -> String {
+    format!("{}（{}）", operator.display_name, operator.username)
+}
+
+/// 审计日志中的部门说明
+fn department_label(department: &Department) -> String {
+    format!("{}（{}）", department.name, department.category)
+}
+
+/// 审计日志中的专责说明
+fn specialist_label(specialist: &QualitySpecialist, departments: &[Department], specialties: &[Specialty]) -> String {
+    let department = departments.iter()
+        .find(|d| d.id == specialist.department_id)
+        .map(|d| d.name.as_str())
+        .unwrap_or("未知");
+    format!("{}（{} / {}）", specialist.name, department, specialty_name(specialties, &specialist.specialty))
+}
+
+/// 审计日志中的抽签结果说明
+fn draw_label(records: &[DrawRecord]) -> String {
+    records.iter()
+        .map(|r| format!(
+            "第{}轮 {} {} → {}",
+            r.round,
+            r.target_department_name,
+            r.specialty_label(),
+            r.selected_from_department_name
+        ))
+        .collect::<Vec<_>>()
+        .join("；")
+}
+
+/// 轮次显示文本（旧版本记录没有轮次）
+fn round_label(round: u32) -> String {
+    if round == 0 {
+        "旧记录".to_string()
+    } else {
+        format!("第{}轮", round)
     }
-    
-    doc.push(table);
-    
-    // 统计信息
-    doc.push(Break::new(1));
-    let summary = format!("共计 {} 条抽签记录", records.len());
-    doc.push(Paragraph::new(summary));
-    
-    // 保存到桌面
-    let filename = format!("抽签记录_{}.pdf", chrono::Local::now().format("%Y%m%d_%H%M%S"));
-    let desktop_path = dirs::desktop_dir()
-        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
-    let file_path = desktop_path.join(&filename);
-    
-    doc.render_to_file(&file_path).map_err(|e| format!("保存PDF失败: {}", e))?;
-    
-    Ok(file_path.to_string_lossy().to_string())
 }
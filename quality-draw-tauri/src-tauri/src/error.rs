@@ -0,0 +1,222 @@
+//! 命令错误类型
+//!
+//! 命令返回 `Result<_, AppError>`，错误序列化为 `{ code, message, detail }`：
+//! `code` 为固定的错误码，前端按错误码提示或本地化；`message` 为中文说明，
+//! `detail` 为错误涉及的对象（角色名、部门ID、底层错误信息等）。
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use crate::logic::DrawError;
+use crate::models::Role;
+
+/// 命令错误
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppError {
+    /// 尚未登录
+    NotLoggedIn,
+    /// 当前角色没有权限
+    PermissionDenied(Role),
+    /// 管理员账户已存在，不能重复初始化
+    AlreadySetUp,
+    /// 只有默认单位可以在登录界面创建管理员，其他单位的管理员在新增单位时创建
+    SetupNotAllowed,
+    /// 账户文件存在但无法读取或已损坏，拒绝登录和初始化（底层错误信息）
+    AccountsUnavailable(String),
+    /// 抽签记录文件无法读取或已损坏
+    RecordsUnavailable(String),
+    /// 登录状态下不能切换单位
+    LogoutRequired,
+    /// 未找到单位
+    OrganizationNotFound(String),
+    /// 未找到操作员账户
+    OperatorNotFound(String),
+    /// 未找到部门
+    DepartmentNotFound(String),
+    /// 未找到专业
+    SpecialtyNotFound(String),
+    /// 未找到专责
+    SpecialistNotFound(String),
+    /// 专业仍被部门或专责使用，不能删除（专业名称）
+    SpecialtyInUse(String),
+    /// 部门仍有专责或本轮抽签结果，不能删除（部门名称）
+    DepartmentInUse(String),
+    /// 至少需要保留一个管理员
+    LastAdmin,
+    /// 不能删除当前登录的账户
+    DeleteSelf,
+    /// 有等待见证人确认的结果，需先确认或取消
+    PendingConfirmation,
+    /// 没有等待确认的结果
+    NothingPending,
+    /// 抽签结果保存失败（结果仍待确认）
+    SaveFailed(String),
+    /// 被检部门本轮已抽取该专业（部门名称、专业名称）
+    AlreadyDrawn(String, String),
+    /// 没有符合条件的候选部门（专业名称）
+    NoCandidates(String),
+    /// 候选部门不足（专业名称、需要数、候选数）
+    NotEnoughCandidates(String, usize, usize),
+    /// 没有可导出的数据
+    NothingToExport,
+    /// 输入或数据校验失败（账户、记录等业务规则给出的说明）
+    Invalid(String),
+    /// 导出文件失败
+    Export(String),
+}
+
+impl AppError {
+    /// 错误码（前端按错误码处理，不随提示文字变化）
+    ///
+    /// 前端 `types.ts` 的 `errorMessages` 按错误码显示提示，新增错误码时需同步添加
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotLoggedIn => "not_logged_in",
+            AppError::PermissionDenied(_) => "permission_denied",
+            AppError::AlreadySetUp => "already_set_up",
+            AppError::SetupNotAllowed => "setup_not_allowed",
+            AppError::AccountsUnavailable(_) => "accounts_unavailable",
+            AppError::RecordsUnavailable(_) => "records_unavailable",
+            AppError::LogoutRequired => "logout_required",
+            AppError::OrganizationNotFound(_) => "organization_not_found",
+            AppError::OperatorNotFound(_) => "operator_not_found",
+            AppError::DepartmentNotFound(_) => "department_not_found",
+            AppError::SpecialtyNotFound(_) => "specialty_not_found",
+            AppError::SpecialistNotFound(_) => "specialist_not_found",
+            AppError::SpecialtyInUse(_) => "specialty_in_use",
+            AppError::DepartmentInUse(_) => "department_in_use",
+            AppError::LastAdmin => "last_admin",
+            AppError::DeleteSelf => "delete_self",
+            AppError::PendingConfirmation => "pending_confirmation",
+            AppError::NothingPending => "nothing_pending",
+            AppError::SaveFailed(_) => "save_failed",
+            AppError::AlreadyDrawn(..) => "already_drawn",
+            AppError::NoCandidates(_) => "no_candidates",
+            AppError::NotEnoughCandidates(..) => "not_enough_candidates",
+            AppError::NothingToExport => "nothing_to_export",
+            AppError::Invalid(_) => "invalid",
+            AppError::Export(_) => "export_failed",
+        }
+    }
+
+    /// 错误涉及的对象
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            AppError::PermissionDenied(role) => Some(role.display_name().to_string()),
+            AppError::OrganizationNotFound(id)
+            | AppError::OperatorNotFound(id)
+            | AppError::DepartmentNotFound(id)
+            | AppError::SpecialtyNotFound(id)
+            | AppError::SpecialistNotFound(id) => Some(id.clone()),
+            AppError::SpecialtyInUse(specialty) => Some(specialty.clone()),
+            AppError::DepartmentInUse(department) => Some(department.clone()),
+            AppError::AlreadyDrawn(department, specialty) => Some(format!("{}/{}", department, specialty)),
+            AppError::NoCandidates(specialty) => Some(specialty.clone()),
+            AppError::NotEnoughCandidates(specialty, needed, available) => {
+                Some(format!("{}/{}/{}", specialty, needed, available))
+            }
+            AppError::AccountsUnavailable(message)
+            | AppError::RecordsUnavailable(message)
+            | AppError::SaveFailed(message)
+            | AppError::Invalid(message)
+            | AppError::Export(message) => {
+                Some(message.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppError::NotLoggedIn => write!(f, "请先登录"),
+            AppError::PermissionDenied(role) => write!(f, "{}没有执行此操作的权限", role.display_name()),
+            AppError::AlreadySetUp => write!(f, "管理员账户已存在"),
+            AppError::SetupNotAllowed => {
+                write!(f, "该单位还没有任何账户，不能在登录界面创建管理员，请从备份恢复该单位的账户文件")
+            }
+            AppError::AccountsUnavailable(message) => {
+                write!(f, "账户文件无法读取，请从备份恢复后再登录: {}", message)
+            }
+            AppError::RecordsUnavailable(message) => {
+                write!(f, "抽签记录文件无法读取，请从备份恢复后再操作: {}", message)
+            }
+            AppError::LogoutRequired => write!(f, "请先注销再切换单位"),
+            AppError::OrganizationNotFound(id) => write!(f, "未找到单位 {}", id),
+            AppError::OperatorNotFound(_) => write!(f, "未找到该账户"),
+            AppError::DepartmentNotFound(_) => write!(f, "未找到目标部门"),
+            AppError::SpecialtyNotFound(id) => write!(f, "无效的专业 {}", id),
+            AppError::SpecialistNotFound(_) => write!(f, "未找到该专责"),
+            AppError::SpecialtyInUse(specialty) => {
+                write!(f, "专业 {} 仍被部门或专责使用，请先在部门管理和专责管理中移除", specialty)
+            }
+            AppError::DepartmentInUse(department) => {
+                write!(f, "部门 {} 仍有专责或本轮抽签结果，请先移除其专责或开始新一轮", department)
+            }
+            AppError::LastAdmin => write!(f, "至少需要保留一个管理员"),
+            AppError::DeleteSelf => write!(f, "不能删除当前登录的账户"),
+            AppError::PendingConfirmation => write!(f, "请先完成见证确认或取消未确认的结果"),
+            AppError::NothingPending => write!(f, "当前没有待确认的结果"),
+            AppError::SaveFailed(message) => write!(f, "保存失败，结果仍待确认: {}", message),
+            AppError::AlreadyDrawn(department, specialty) => write!(f, "{}本轮已抽取{}", department, specialty),
+            AppError::NoCandidates(specialty) => write!(f, "没有符合条件的{}候选部门", specialty),
+            AppError::NotEnoughCandidates(specialty, needed, available) => {
+                write!(f, "需要抽取{}个{}检查部门，符合条件的候选部门只有{}个", needed, specialty, available)
+            }
+            AppError::NothingToExport => write!(f, "没有可导出的记录"),
+            AppError::Invalid(message) => write!(f, "{}", message),
+            AppError::Export(message) => write!(f, "导出失败: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("detail", &self.detail())?;
+        state.end()
+    }
+}
+
+/// 业务逻辑和存储层返回的中文说明
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        AppError::Invalid(message)
+    }
+}
+
+/// 抽签引擎的失败原因映射为对应的错误码
+impl From<DrawError> for AppError {
+    fn from(error: DrawError) -> Self {
+        match error {
+            DrawError::DepartmentNotFound(id) => AppError::DepartmentNotFound(id),
+            DrawError::AlreadyDrawn(department, specialty) => AppError::AlreadyDrawn(department, specialty),
+            DrawError::NoCandidates(_, specialty) => AppError::NoCandidates(specialty),
+            DrawError::NotEnoughCandidates(_, specialty, needed, available) => {
+                AppError::NotEnoughCandidates(specialty, needed, available)
+            }
+            error @ DrawError::NotNeeded(..) => AppError::Invalid(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let json = serde_json::to_value(AppError::PermissionDenied(Role::Observer)).unwrap();
+        assert_eq!(json["code"], "permission_denied");
+        assert_eq!(json["message"], "观察员没有执行此操作的权限");
+        assert_eq!(json["detail"], "观察员");
+
+        let json = serde_json::to_value(AppError::NotLoggedIn).unwrap();
+        assert_eq!(json["code"], "not_logged_in");
+        assert!(json["detail"].is_null());
+    }
+}
@@ -1,28 +1,97 @@
 //! 宁夏特检院质量监督检查抽签程序 - Tauri 后端
 
-mod models;
-mod storage;
-mod logic;
 mod commands;
+mod error;
 
 pub use commands::*;
+pub use error::AppError;
+
+// 数据模型、存储、抽签逻辑和导出与桌面版共用
+use quality_draw_core::{export, logic, logging, models, storage};
+use logging::{Level, Logger};
+use std::path::PathBuf;
+
+/// 应用标识（与 tauri.conf.json 的 identifier 相同）
+const APP_IDENTIFIER: &str = "com.nxtj.quality-draw";
+
+/// 初始化运行日志（级别可用 QUALITY_DRAW_LOG 环境变量调整），崩溃信息也写入日志
+fn init_logging() {
+    let level = std::env::var("QUALITY_DRAW_LOG")
+        .ok()
+        .and_then(|name| Level::from_name(&name))
+        .unwrap_or(Level::Info);
+    logging::init(Logger::new(log_dir().join("quality_draw.log"), level));
+    logging::install_panic_hook();
+}
+
+/// 日志目录：应用数据目录（与 Tauri 的 app_data_dir 相同，即系统数据目录下的应用标识目录）中的 logs
+///
+/// 日志需要在创建应用状态前初始化，此时还没有 AppHandle，因此按相同规则自行拼接
+fn log_dir() -> PathBuf {
+    let dir = dirs::data_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(APP_IDENTIFIER)
+        .join("logs");
+    std::fs::create_dir_all(&dir).ok();
+    dir
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    init_logging();
+    logging::info("app", "程序启动", &[("version", &env!("CARGO_PKG_VERSION"))]);
+    
+    let result = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .manage(AppState::default())
         .invoke_handler(tauri::generate_handler![
+            needs_setup,
+            setup_admin,
+            login,
+            logout,
+            get_current_operator,
+            list_operators,
+            create_operator,
+            set_operator_role,
+            reset_operator_password,
+            delete_operator,
+            get_audit_log,
+            export_audit_to_excel,
+            list_organizations,
+            get_organization,
+            select_organization,
             get_departments,
+            get_specialties,
+            get_specialists,
+            add_department,
+            update_department,
+            delete_department,
+            add_specialist,
+            update_specialist,
+            delete_specialist,
+            add_specialty,
+            delete_specialty,
             get_records,
-            clear_records,
+            void_record,
+            archive_round,
+            get_round_no,
             start_new_round,
             get_current_round_status,
             get_candidate_departments,
             execute_draw,
+            get_pending_draws,
+            confirm_draw,
+            cancel_draw,
             export_to_excel,
             export_to_pdf,
+            get_statistics,
+            export_statistics_to_excel,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .run(tauri::generate_context!());
+    
+    match &result {
+        Ok(()) => logging::info("app", "程序正常退出", &[]),
+        Err(e) => logging::error("app", "程序错误", &[("error", e)]),
+    }
+    result.expect("error while running tauri application");
 }
@@ -1,5 +0,0 @@
-//! 业务逻辑模块
-
-mod draw;
-
-pub use draw::DrawEngine;
@@ -1,12 +1,47 @@
 // 类型定义 - 按部门抽签版本
 
-export type DepartmentType = 'Comprehensive' | 'Pressure' | 'Mechanical'
-export type SpecialtyType = 'Pressure' | 'Mechanical'
+export interface ChangeStamp {
+    operator_id: string
+    operator_name: string
+    timestamp: string
+}
+
+export interface DrawRules {
+    once_per_round: boolean             // 本轮已被抽中的部门不再参与抽取
+    cross_avoidance: boolean            // 交叉回避
+}
+
+export interface Organization {
+    id: string
+    name: string
+    logo?: string | null                // 标志图片路径
+    report_header: string               // 报表抬头（为空时使用默认抬头）
+    rules: DrawRules
+    updated?: ChangeStamp | null
+}
+
+export interface Specialty {
+    id: string
+    name: string
+    updated?: ChangeStamp | null
+}
+
+export interface QualitySpecialist {
+    id: string
+    name: string
+    department_id: string
+    specialty: string                   // 专业ID
+    updated?: ChangeStamp | null
+}
 
 export interface Department {
     id: string
     name: string
-    department_type: DepartmentType
+    category: string                    // 分类（仅用于分组显示）
+    needs: string[]                     // 需要抽取的专业ID
+    supplies: string[]                  // 可派出检查的专业ID
+    team_sizes?: Record<string, number> // 各专业检查组部门数（未设置为1）
+    updated?: ChangeStamp | null
 }
 
 export interface DrawRecord {
@@ -14,45 +49,254 @@ export interface DrawRecord {
     timestamp: string
     target_department_id: string
     target_department_name: string
-    specialty_type: SpecialtyType
+    specialty_type: string              // 专业ID
+    specialty_name?: string             // 专业名称（旧记录为空）
     selected_specialist_id: string      // 实际是部门ID
     selected_specialist_name: string    // 实际是部门名称
     selected_from_department_id: string
     selected_from_department_name: string
+    round: number                       // 抽签轮次（旧记录为0）
+    seed?: number | null                // 随机种子
+    candidates: string[]                // 候选名单
+    rule_notes: string[]                // 规则说明
+    voided?: VoidInfo | null            // 作废信息
+    archived: boolean                   // 是否已归档
+    operator_id?: string | null         // 执行抽签的操作员ID
+    operator_name?: string | null       // 执行抽签的操作员姓名
+    witness?: WitnessInfo | null        // 见证确认信息
+    team?: TeamInfo | null              // 检查组信息（只抽一个部门时为空）
+    replay?: DrawReplay | null          // 转盘回放数据（桌面版抽签时保存）
+}
+
+export interface DrawReplay {
+    order: string[]                     // 转盘上的候选顺序
+    final_index: number                 // 中签位置
+    team_size: number                   // 本次抽取的部门数
+    speed: number                       // 转速（每秒滚过的项目数）
+    rolling_ms: number                  // 开始转动到开始减速的毫秒数
+    slowdown_ms: number                 // 减速毫秒数
+    easing: 'quadratic' | 'cubic' | 'quintic'
+}
+
+export interface TeamInfo {
+    group_id: string                    // 同一次抽取的记录共用的组ID
+    position: number                    // 在本次抽取结果中的位置
+    size: number                        // 本次抽取的部门数
+}
+
+export interface WitnessInfo {
+    operator_id: string
+    name: string
+    confirmed_at: string
+    remote_addr?: string | null
+}
+
+export interface VoidInfo {
+    reason: string
+    operator: string
+    operator_id?: string | null
+    timestamp: string
+}
+
+export type Role = 'Admin' | 'DrawOperator' | 'Observer'
+
+export interface OperatorInfo {
+    id: string
+    username: string
+    display_name: string
+    role: Role
 }
 
 export interface DrawResult {
-    success: boolean
-    department_name?: string
-    department_id?: string
-    specialty_type?: string
-    message?: string
+    department_name: string
+    department_id: string
+    team: string[]                      // 检查组全部抽中部门名称
+    specialty_id: string
+}
+
+// 命令错误
+export interface AppError {
+    code: string                        // 固定错误码，如 not_logged_in、permission_denied、no_candidates
+    message: string                     // 后端的中文说明（前端不认识该错误码时显示）
+    detail: string | null               // 错误涉及的对象（角色名、部门ID、底层错误信息等）
+}
+
+// 抽签统计
+export interface DepartmentCount {
+    name: string
+    as_target: number                   // 作为被检部门的次数
+    as_inspector: number                // 作为检查部门被抽中的次数
+    expected: number                    // 等概率抽取时的期望检查次数
+}
+
+export interface FairnessTest {
+    draws: number                       // 参与检验的记录数（有候选名单）
+    chi_square: number
+    degrees_of_freedom: number
+    p_value?: number | null             // 自由度为0时无法检验
+    low_expectation: boolean            // 有部门期望次数小于5
+}
+
+export interface SpecialtyStatistics {
+    specialty: string
+    specialty_name: string
+    draws: number
+    departments: DepartmentCount[]
+    pairs: number[][]                   // pairs[被检部门][检查部门]
+    fairness: FairnessTest
+}
+
+export interface QuarterTrend {
+    label: string
+    draws: number
+    by_specialty: number[]              // 与 specialties 顺序一致
+}
+
+export interface DrawStatistics {
+    total_draws: number
+    specialties: SpecialtyStatistics[]
+    quarters: QuarterTrend[]
+}
+
+// 显著性水平
+export const SIGNIFICANCE_LEVEL = 0.05
+
+export function getFairnessConclusion(test: FairnessTest): string {
+    if (test.p_value == null) return '样本不足，无法检验'
+    let text = test.p_value >= SIGNIFICANCE_LEVEL
+        ? '与等概率期望无显著差异'
+        : `与等概率期望存在显著差异（p < ${SIGNIFICANCE_LEVEL}）`
+    if (test.low_expectation) text += '，部分部门期望次数小于 5，仅供参考'
+    return text
 }
 
 // 工具函数
-export function getDepartmentTypeLabel(type: DepartmentType): string {
-    const labels: Record<DepartmentType, string> = {
-        Comprehensive: '综合类',
+// 按专业ID查找专业名称，旧版本的固定专业ID也能显示
+export function getSpecialtyLabel(specialties: Specialty[], id: string): string {
+    const legacy: Record<string, string> = {
         Pressure: '承压类',
         Mechanical: '机电类'
     }
-    return labels[type] || type
+    return specialties.find(s => s.id === id)?.name || legacy[id] || id
 }
 
-export function getSpecialtyTypeLabel(type: SpecialtyType): string {
-    const labels: Record<SpecialtyType, string> = {
-        Pressure: '承压类',
-        Mechanical: '机电类'
+export type AuditAction =
+    | 'Login' | 'Logout'
+    | 'AddSpecialist' | 'DeleteSpecialist' | 'UpdateSpecialist'
+    | 'AddDepartment' | 'DeleteDepartment' | 'UpdateDepartment'
+    | 'AddSpecialty' | 'DeleteSpecialty'
+    | 'AddOrganization' | 'UpdateOrganization'
+    | 'VoidRecord' | 'ArchiveRound'
+    | 'AddOperator' | 'DeleteOperator' | 'ChangeRole' | 'ResetPassword'
+    | 'ConfirmDraw' | 'CancelDraw' | 'UndoDraw'
+    | 'UpdateSchedule' | 'UpdateOutcome'
+
+export interface AuditEntry {
+    id: string
+    timestamp: string
+    operator_id: string
+    operator_name: string
+    action: AuditAction
+    target: string
+    old_value?: string | null
+    new_value?: string | null
+}
+
+export function getAuditActionLabel(action: AuditAction): string {
+    const labels: Record<AuditAction, string> = {
+        Login: '登录',
+        Logout: '注销',
+        AddSpecialist: '添加专责',
+        DeleteSpecialist: '删除专责',
+        UpdateSpecialist: '修改专责',
+        AddDepartment: '添加部门',
+        DeleteDepartment: '删除部门',
+        UpdateDepartment: '修改部门专业',
+        AddSpecialty: '添加专业',
+        DeleteSpecialty: '删除专业',
+        AddOrganization: '新增单位',
+        UpdateOrganization: '修改单位信息',
+        VoidRecord: '作废记录',
+        ArchiveRound: '归档轮次',
+        AddOperator: '添加账户',
+        DeleteOperator: '删除账户',
+        ChangeRole: '修改角色',
+        ResetPassword: '重设密码',
+        ConfirmDraw: '见证确认',
+        CancelDraw: '取消抽签结果',
+        UndoDraw: '撤销抽签',
+        UpdateSchedule: '安排检查日程',
+        UpdateOutcome: '登记检查结果'
+    }
+    return labels[action] || action
+}
+
+export function getRoleLabel(role: Role): string {
+    const labels: Record<Role, string> = {
+        Admin: '管理员',
+        DrawOperator: '抽签员',
+        Observer: '观察员'
+    }
+    return labels[role] || role
+}
+
+export function isAppError(e: unknown): e is AppError {
+    return typeof e === 'object' && e !== null && 'code' in e && 'message' in e
+}
+
+// 各错误码的提示文字，参数为错误涉及的对象（格式与后端 AppError::detail 一致，多个值以 / 分隔）
+const errorMessages: Record<string, (detail: string) => string> = {
+    not_logged_in: () => '请先登录',
+    permission_denied: role => `${role}没有执行此操作的权限`,
+    already_set_up: () => '管理员账户已存在',
+    setup_not_allowed: () => '该单位还没有任何账户，不能在登录界面创建管理员，请从备份恢复该单位的账户文件',
+    accounts_unavailable: detail => `账户文件无法读取，请从备份恢复后再登录: ${detail}`,
+    records_unavailable: detail => `抽签记录文件无法读取，请从备份恢复后再操作: ${detail}`,
+    logout_required: () => '请先注销再切换单位',
+    organization_not_found: id => `未找到单位 ${id}`,
+    operator_not_found: () => '未找到该账户',
+    department_not_found: () => '未找到目标部门',
+    specialty_not_found: id => `无效的专业 ${id}`,
+    specialist_not_found: () => '未找到该专责',
+    specialty_in_use: specialty => `专业 ${specialty} 仍被部门或专责使用，请先在部门管理和专责管理中移除`,
+    department_in_use: department => `部门 ${department} 仍有专责或本轮抽签结果，请先移除其专责或开始新一轮`,
+    last_admin: () => '至少需要保留一个管理员',
+    delete_self: () => '不能删除当前登录的账户',
+    pending_confirmation: () => '请先完成见证确认或取消未确认的结果',
+    nothing_pending: () => '当前没有待确认的结果',
+    save_failed: detail => `保存失败，结果仍待确认: ${detail}`,
+    already_drawn: detail => {
+        const [department, specialty] = detail.split('/')
+        return `${department}本轮已抽取${specialty}`
+    },
+    no_candidates: specialty => `没有符合条件的${specialty}候选部门`,
+    not_enough_candidates: detail => {
+        const [specialty, needed, available] = detail.split('/')
+        return `需要抽取${needed}个${specialty}检查部门，符合条件的候选部门只有${available}个`
+    },
+    nothing_to_export: () => '没有可导出的记录',
+    invalid: detail => detail,
+    export_failed: detail => `导出失败: ${detail}`
+}
+
+// 命令错误的提示文字：按错误码显示，未知错误码或缺少所需的 detail 时退回后端的 message
+export function getErrorMessage(e: unknown): string {
+    if (!isAppError(e)) {
+        return `${e}`
+    }
+    const format = errorMessages[e.code]
+    if (!format || (format.length > 0 && e.detail === null)) {
+        return e.message
     }
-    return labels[type] || type
+    return format(e.detail ?? '')
 }
 
-export function needsPressure(type: DepartmentType): boolean {
-    return type === 'Comprehensive' || type === 'Pressure'
+export function canDraw(role: Role): boolean {
+    return role === 'Admin' || role === 'DrawOperator'
 }
 
-export function needsMechanical(type: DepartmentType): boolean {
-    return type === 'Comprehensive' || type === 'Mechanical'
+export function canManage(role: Role): boolean {
+    return role === 'Admin'
 }
 
 export function formatDateTime(isoString: string): string {
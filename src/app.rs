@@ -1,70 +1,189 @@
 //! 主应用程序
 
 use eframe::egui;
-use crate::models::{Department, QualitySpecialist, DrawRecord};
+use crate::models::{AuditAction, AuditEntry, Department, DisplaySettings, Role, Theme, QualitySpecialist, DrawRecord, Operator, Organization, Specialty, WitnessInfo};
+use crate::i18n::{Locale, locale, set_locale, tr, trf};
+use crate::logging;
+use crate::logic::{AuthManager, DrawEngine};
 use crate::storage::DataStore;
-use crate::ui::{MainPanel, SettingsPanel, HistoryPanel, ExportManager};
+use crate::server::LiveServer;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use crate::ui::{apply_theme, AnimationDialog, AuditPanel, KeyCommand, MainPanel, SettingsPanel, HistoryPanel, HistoryAction, ExportManager, LoginPanel, LogViewer, OrganizationPanel, PrintDialog, PrintOptions, OutcomePanel, PrintOutcome, PresentationView, ReplayViewer, SchedulePanel, StatisticsPanel, ServerAction, ServerPanel, UndoDialog, WitnessAction, WitnessDialog};
 
 /// 应用程序状态
 pub struct QualityDrawApp {
-    /// 数据存储
+    /// 数据存储（当前单位）
     store: DataStore,
+    /// 当前单位
+    organization: Organization,
+    /// 所有单位
+    organizations: Vec<Organization>,
+    /// 已设置的窗口标题
+    window_title: String,
     /// 部门列表
     departments: Vec<Department>,
+    /// 专业列表
+    specialties: Vec<Specialty>,
     /// 质量专责列表
     specialists: Vec<QualitySpecialist>,
     /// 抽签记录
     records: Vec<DrawRecord>,
+    /// 操作员账户
+    operators: Vec<Operator>,
+    /// 账户或抽签记录文件无法读取时的错误（此时拒绝登录和创建管理员）
+    load_error: Option<String>,
+    /// 当前登录的操作员（未登录为 None）
+    operator: Option<Operator>,
+    /// 单位选择界面
+    organization_panel: OrganizationPanel,
+    /// 登录界面
+    login_panel: LoginPanel,
     /// 主面板
     main_panel: MainPanel,
     /// 设置面板
     settings_panel: SettingsPanel,
     /// 历史记录面板
     history_panel: HistoryPanel,
+    /// 审计日志面板
+    audit_panel: AuditPanel,
+    /// 检查日程安排面板
+    schedule_panel: SchedulePanel,
+    /// 检查结果面板
+    outcome_panel: OutcomePanel,
+    /// 抽签统计面板
+    statistics_panel: StatisticsPanel,
+    /// 打印设置对话框
+    print_dialog: PrintDialog,
+    /// 转盘动画设置对话框
+    animation_dialog: AnimationDialog,
+    /// 转盘回放窗口
+    replay_viewer: ReplayViewer,
+    /// 运行日志面板
+    log_viewer: LogViewer,
+    /// 见证确认对话框
+    witness_dialog: WitnessDialog,
+    /// 撤销上一次抽签对话框
+    undo_dialog: UndoDialog,
+    /// 投影窗口
+    presentation: PresentationView,
+    /// 投屏服务设置对话框
+    server_panel: ServerPanel,
+    /// 投屏服务（未启动为 None）
+    live_server: Option<LiveServer>,
+    /// 后台打印任务的结果通道（没有进行中的打印任务时为 None）
+    print_job: Option<Receiver<Result<PrintOutcome, String>>>,
     /// 状态消息
     status_message: Option<String>,
 }
 
 impl QualityDrawApp {
     /// 创建新应用
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
-        let store = DataStore::new();
+    /// 
+    /// 只有一个单位时直接进入，多个单位时先显示单位选择界面
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let root = DataStore::new();
+        set_locale(root.load_locale());
+        apply_theme(&cc.egui_ctx, root.load_display_settings());
+        let organizations = root.load_organizations();
+        let mut app = Self::open(root.for_organization(&organizations[0].id));
+        app.organization_panel.visible = organizations.len() > 1;
+        app
+    }
+    
+    /// 打开单位的数据
+    fn open(store: DataStore) -> Self {
+        let organization = store.organization();
+        let organizations = store.load_organizations();
         let departments = store.load_departments();
+        let specialties = store.load_specialties();
         let specialists = store.load_specialists();
-        let records = store.load_records();
+        let (records, records_error) = match store.load_records() {
+            Ok(records) => (records, None),
+            Err(e) => (Vec::new(), Some(e)),
+        };
+        let (operators, operators_error) = match store.load_operators() {
+            Ok(operators) => (operators, None),
+            Err(e) => (Vec::new(), Some(e)),
+        };
+        
+        // 继续保存的轮次，恢复该轮已抽中的结果
+        let round_no = store.current_round(&records);
+        let main_panel = MainPanel {
+            current_round: DrawEngine::round_pairs_by_specialty(&records, round_no),
+            round_no,
+            rules: organization.rules,
+            display: store.load_display_settings(),
+            animation: store.load_animation_settings(),
+            ..MainPanel::default()
+        };
         
         Self {
             store,
+            organization,
+            organizations,
+            window_title: String::new(),
             departments,
+            specialties,
             specialists,
             records,
-            main_panel: MainPanel::default(),
+            operators,
+            load_error: operators_error.or(records_error),
+            operator: None,
+            organization_panel: OrganizationPanel::default(),
+            login_panel: LoginPanel::default(),
+            main_panel,
             settings_panel: SettingsPanel::default(),
             history_panel: HistoryPanel::default(),
+            audit_panel: AuditPanel::default(),
+            schedule_panel: SchedulePanel::default(),
+            outcome_panel: OutcomePanel::default(),
+            statistics_panel: StatisticsPanel::default(),
+            print_dialog: PrintDialog::default(),
+            animation_dialog: AnimationDialog::default(),
+            replay_viewer: ReplayViewer::default(),
+            log_viewer: LogViewer::default(),
+            witness_dialog: WitnessDialog::default(),
+            undo_dialog: UndoDialog::default(),
+            presentation: PresentationView::default(),
+            server_panel: ServerPanel::default(),
+            live_server: None,
+            print_job: None,
             status_message: None,
         }
     }
     
+    /// 重新读取抽签记录（读取失败时保留当前列表并提示）
+    fn reload_records(&mut self) {
+        match self.store.load_records() {
+            Ok(records) => self.records = records,
+            Err(e) => self.status_message = Some(e),
+        }
+    }
+    
+    /// 未归档的记录（用于导出和打印）
+    fn active_records(&self) -> Vec<DrawRecord> {
+        self.records.iter().filter(|r| !r.archived).cloned().collect()
+    }
+    
     /// 导出到Excel
     fn export_to_excel(&mut self) {
-        if self.records.is_empty() {
-            self.status_message = Some("没有可导出的记录".to_string());
+        let records = self.active_records();
+        if records.is_empty() {
+            self.status_message = Some(tr("没有可导出的记录").to_string());
             return;
         }
         
         // 生成文件名
-        let filename = format!(
-            "抽签结果_{}.xlsx",
-            chrono::Local::now().format("%Y%m%d_%H%M%S")
-        );
+        let filename = trf("抽签结果_{}.xlsx", &[&chrono::Local::now().format("%Y%m%d_%H%M%S")]);
         
         // 保存到桌面
         let desktop = dirs::desktop_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
         let path = desktop.join(&filename);
         
-        match ExportManager::export_to_excel(&self.records, &path) {
+        match ExportManager::export_to_excel(&records, &self.organization, &path) {
             Ok(_) => {
-                self.status_message = Some(format!("已导出到: {}", path.display()));
+                logging::info("app", "导出Excel", &[("path", &path.display()), ("records", &records.len())]);
+                self.status_message = Some(trf("已导出到: {}", &[&path.display()]));
                 // 打开文件位置
                 #[cfg(target_os = "windows")]
                 {
@@ -74,24 +193,217 @@ impl QualityDrawApp {
                 }
             }
             Err(e) => {
-                self.status_message = Some(format!("导出失败: {}", e));
+                logging::error("app", "导出Excel失败", &[("path", &path.display()), ("error", &e)]);
+                self.status_message = Some(trf("导出失败: {}", &[&e]));
+            }
+        }
+    }
+    
+    /// 见证人确认后保存待确认的抽签结果
+    /// 
+    /// 保存失败时结果仍待确认，不写审计日志，返回错误
+    fn confirm_draw(&mut self, witness: &Operator, remote_addr: Option<String>) -> Result<String, String> {
+        let info = WitnessInfo::new(witness, remote_addr);
+        let records = match self.main_panel.confirm_pending(info.clone(), &self.store) {
+            Ok(records) => records,
+            Err(e) => {
+                logging::error("draw", "保存见证确认的结果失败", &[("witness", &witness.display_name), ("error", &e)]);
+                self.status_message = Some(trf("保存失败，结果仍待确认: {}", &[&e]));
+                return Err(e);
+            }
+        };
+        self.store.append_audit(&AuditEntry::new(
+            witness,
+            AuditAction::ConfirmDraw,
+            Self::draw_label(&records),
+        ).with_new(&info));
+        let message = trf("{} 已确认抽签结果", &[&witness.display_name]);
+        self.status_message = Some(message.clone());
+        self.records.extend(records);
+        Ok(message)
+    }
+    
+    /// 当前操作员可以撤销的本轮最近一次抽签（抽签员只能撤销自己的抽签）
+    fn undoable_draw(&self, operator: &Operator) -> Vec<DrawRecord> {
+        if !operator.role.can_draw() || self.main_panel.is_drawing || self.main_panel.has_pending() {
+            return Vec::new();
+        }
+        let last = DrawEngine::last_draw(&self.records, self.main_panel.round_no);
+        let own = last.iter().all(|r| r.operator_id.as_deref() == Some(operator.id.as_str()));
+        if !own && !operator.role.can_manage() {
+            return Vec::new();
+        }
+        last.into_iter().cloned().collect()
+    }
+    
+    /// 撤销本轮最近一次抽签：作废记录并恢复本轮状态
+    fn undo_last_draw(&mut self, reason: &str, operator: &Operator) {
+        let records = self.undoable_draw(operator);
+        if records.is_empty() {
+            self.status_message = Some(tr("没有可撤销的抽签").to_string());
+            return;
+        }
+        
+        for record in &records {
+            match self.store.void_record(&record.id, &format!("撤销：{}", reason), operator) {
+                Ok(voided) => {
+                    self.main_panel.release_voided(&voided);
+                    self.store.append_audit(&AuditEntry::new(
+                        operator,
+                        AuditAction::UndoDraw,
+                        Self::draw_label(std::slice::from_ref(record)),
+                    ).with_old(record).with_new(&voided));
+                }
+                Err(e) => {
+                    logging::error("draw", "撤销抽签失败", &[("record", &record.id), ("error", &e)]);
+                    self.status_message = Some(trf("撤销失败: {}", &[&e]));
+                    self.reload_records();
+                    return;
+                }
             }
         }
+        self.reload_records();
+        logging::info("draw", "撤销抽签", &[
+            ("draw", &Self::draw_label(&records)),
+            ("reason", &reason),
+            ("operator", &operator.display_name),
+        ]);
+        self.status_message = Some(trf("已撤销：{}", &[&Self::draw_label(&records)]));
+    }
+    
+    /// 审计日志中的抽签结果描述
+    fn draw_label(records: &[DrawRecord]) -> String {
+        records.iter()
+            .map(|r| format!(
+                "第{}轮 {} {} → {}",
+                r.round,
+                r.target_department_name,
+                r.specialty_label(),
+                r.selected_from_department_name
+            ))
+            .collect::<Vec<_>>()
+            .join("；")
+    }
+    
+    /// 界面语言选择（切换后立即生效并保存）
+    fn show_locale_selector(&mut self, ui: &mut egui::Ui) {
+        let mut selected = locale();
+        egui::ComboBox::from_id_salt("locale")
+            .selected_text(format!("🌐 {}", selected.display_name()))
+            .show_ui(ui, |ui| {
+                for option in Locale::all() {
+                    ui.selectable_value(&mut selected, option, option.display_name());
+                }
+            });
+        if selected != locale() {
+            set_locale(selected);
+            self.store.save_locale(selected);
+        }
+    }
+    
+    /// 显示主题选择（切换后立即生效并保存）
+    fn show_theme_selector(&mut self, ui: &mut egui::Ui) {
+        let mut selected = self.main_panel.display;
+        egui::ComboBox::from_id_salt("theme")
+            .selected_text(format!("🎨 {}", selected.theme.display_name()))
+            .show_ui(ui, |ui| {
+                for option in Theme::all() {
+                    ui.selectable_value(&mut selected.theme, option, option.display_name());
+                }
+                ui.separator();
+                ui.checkbox(&mut selected.large_font, tr("投影大字体"));
+            });
+        if selected != self.main_panel.display {
+            self.apply_display(ui.ctx(), selected);
+        }
+    }
+    
+    /// 应用并保存显示设置
+    fn apply_display(&mut self, ctx: &egui::Context, settings: DisplaySettings) {
+        apply_theme(ctx, settings);
+        self.main_panel.display = settings;
+        self.store.save_display_settings(&settings);
+    }
+    
+    /// 是否有弹窗打开（弹窗打开时不响应抽签快捷键）
+    fn dialog_open(&self) -> bool {
+        self.settings_panel.visible
+            || self.history_panel.visible
+            || self.audit_panel.visible
+            || self.schedule_panel.visible
+            || self.outcome_panel.visible
+            || self.statistics_panel.visible
+            || self.print_dialog.visible
+            || self.animation_dialog.visible
+            || self.replay_viewer.visible
+            || self.log_viewer.visible
+            || self.server_panel.visible
+            || self.undo_dialog.visible
+            || self.main_panel.has_pending()
+    }
+    
+    /// 执行抽签快捷键
+    fn handle_key(&mut self, command: KeyCommand, role: Role) {
+        if self.dialog_open() {
+            return;
+        }
+        self.main_panel.handle_key(
+            command,
+            role.can_draw(),
+            &self.specialists,
+            &self.departments,
+            &self.specialties,
+            &self.records,
+        );
     }
     
     /// 打印记录
-    fn print_records(&mut self) {
-        if self.records.is_empty() {
-            self.status_message = Some("没有可打印的记录".to_string());
+    ///
+    /// HTML 转 PDF 和提交打印机可能耗时数秒，放到后台线程执行，结果由 `poll_print_job` 取回
+    fn print_records(&mut self, options: PrintOptions) {
+        let records = self.active_records();
+        if records.is_empty() {
+            self.status_message = Some(tr("没有可打印的记录").to_string());
+            return;
+        }
+        if self.print_job.is_some() {
             return;
         }
         
-        match ExportManager::print_records(&self.records) {
-            Ok(_) => {
-                self.status_message = Some("已在浏览器中打开打印预览".to_string());
+        let organization = self.organization.clone();
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(ExportManager::print_records(&records, &organization, &options));
+        });
+        self.print_job = Some(receiver);
+        self.status_message = Some(tr("正在打印…").to_string());
+    }
+    
+    /// 取回后台打印任务的结果
+    fn poll_print_job(&mut self, ctx: &egui::Context) {
+        let Some(receiver) = &self.print_job else {
+            return;
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(200));
+                return;
+            }
+            Err(TryRecvError::Disconnected) => Err(tr("打印任务意外中止").to_string()),
+        };
+        self.print_job = None;
+        
+        match result {
+            Ok(PrintOutcome::Submitted { printer, copies }) => {
+                self.status_message = Some(trf("已提交到 {} 打印 {} 份", &[&printer, &copies]));
+            }
+            Ok(PrintOutcome::OpenedInBrowser) => {
+                self.status_message = Some(tr("已在浏览器中打开打印预览").to_string());
             }
             Err(e) => {
-                self.status_message = Some(format!("打印失败: {}", e));
+                logging::error("app", "打印失败", &[("error", &e)]);
+                self.status_message = Some(trf("打印失败: {}", &[&e]));
             }
         }
     }
@@ -99,31 +411,129 @@ impl QualityDrawApp {
 
 impl eframe::App for QualityDrawApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // 选择单位
+        if self.organization_panel.visible {
+            if let Some(id) = self.organization_panel.show(ctx, &self.organizations) {
+                *self = Self::open(self.store.for_organization(&id));
+            }
+            return;
+        }
+        
+        // 窗口标题随单位变化
+        let title = self.organization.app_title();
+        if self.window_title != title {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title.clone()));
+            self.window_title = title;
+        }
+        
+        // 未登录时只显示登录界面（多个单位时可切换单位）
+        let Some(operator) = self.operator.clone() else {
+            egui::TopBottomPanel::bottom("organization_bar").show(ctx, |ui| {
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    if self.organizations.len() > 1 {
+                        ui.label(trf("当前单位: {}", &[&self.organization.name]));
+                        if ui.button(tr("🏛 切换单位")).clicked() {
+                            self.organization_panel.visible = true;
+                        }
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        self.show_locale_selector(ui);
+                        self.show_theme_selector(ui);
+                    });
+                });
+                ui.add_space(5.0);
+            });
+            if let Some(operator) = self.login_panel.show(
+                ctx,
+                &mut self.operators,
+                self.load_error.as_deref(),
+                &self.store,
+                &self.window_title,
+            ) {
+                self.store.append_audit(&AuditEntry::new(&operator, AuditAction::Login, operator.label()));
+                logging::info("app", "登录", &[("operator", &operator.label()), ("organization", &self.organization.name)]);
+                self.status_message = Some(trf("欢迎，{}", &[&operator.label()]));
+                self.operator = Some(operator);
+            }
+            return;
+        };
+        let role = operator.role;
+        
+        // 键盘操作（空格开始/停止，方向键切换被检部门），在绘制按钮前读取以免重复触发
+        if let Some(command) = KeyCommand::read(ctx) {
+            self.handle_key(command, role);
+        }
+        
         // 请求持续重绘（用于动画）
-        if self.main_panel.pressure_animation.is_running() || 
-           self.main_panel.mechanical_animation.is_running() {
+        if self.main_panel.is_running() {
             ctx.request_repaint();
         }
         
         // 更新动画状态
-        let new_records = self.main_panel.update(
+        self.main_panel.update(
             &self.specialists,
             &self.departments,
+            &self.specialties,
             &self.records,
-            &self.store,
+            &operator,
         );
-        self.records.extend(new_records);
+        
+        // 推送投屏状态，处理局域网设备提交的见证确认
+        if let Some(server) = &self.live_server {
+            server.publish(self.main_panel.live_snapshot(&self.organization, &self.departments, &self.specialties));
+            for request in server.confirm_requests() {
+                let result = if self.main_panel.has_pending() {
+                    AuthManager::check_witness(&operator.id, &request.witness)
+                } else {
+                    Err(tr("当前没有待确认的结果").to_string())
+                };
+                let result = result.and_then(|()| self.confirm_draw(&request.witness, request.remote_addr.clone()));
+                request.reply(result);
+            }
+            // 待确认期间定时检查局域网确认请求
+            if self.main_panel.has_pending() {
+                ctx.request_repaint_after(std::time::Duration::from_millis(200));
+            }
+        }
         
         // 顶部标题栏
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             ui.add_space(5.0);
             ui.horizontal(|ui| {
-                ui.heading(egui::RichText::new("宁夏特检院质量监督检查抽签程序")
+                ui.heading(egui::RichText::new(&self.window_title)
                     .size(24.0)
                     .color(egui::Color32::from_rgb(50, 100, 180)));
                 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    ui.label(format!("专责: {} 人", self.specialists.len()));
+                    // 抽签过程中和结果确认前不允许注销
+                    let can_logout = !self.main_panel.is_drawing && !self.main_panel.has_pending();
+                    if ui.add_enabled(can_logout, egui::Button::new(tr("🔓 注销"))).clicked() {
+                        self.store.append_audit(&AuditEntry::new(&operator, AuditAction::Logout, operator.label()));
+                        logging::info("app", "退出登录", &[("operator", &operator.label())]);
+                        self.operator = None;
+                        self.settings_panel.visible = false;
+                        self.history_panel.visible = false;
+                        self.audit_panel.visible = false;
+                        self.schedule_panel.visible = false;
+                        self.outcome_panel.visible = false;
+                        self.statistics_panel.visible = false;
+                        self.print_dialog.visible = false;
+                        self.animation_dialog.visible = false;
+                        self.replay_viewer.visible = false;
+                        self.log_viewer.visible = false;
+                        self.server_panel.visible = false;
+                        self.presentation.visible = false;
+                        self.undo_dialog.visible = false;
+                        self.status_message = None;
+                        self.organizations = self.store.load_organizations();
+                    }
+                    self.show_locale_selector(ui);
+                    self.show_theme_selector(ui);
+                    ui.separator();
+                    ui.label(trf("操作员: {}", &[&operator.label()]));
+                    ui.separator();
+                    ui.label(trf("专责: {} 人", &[&self.specialists.len()]));
                 });
             });
             ui.add_space(5.0);
@@ -133,39 +543,82 @@ impl eframe::App for QualityDrawApp {
         egui::TopBottomPanel::bottom("bottom_panel").show(ctx, |ui| {
             ui.add_space(5.0);
             ui.horizontal(|ui| {
-                if ui.button("📜 查看历史").clicked() {
+                if ui.button(tr("📜 查看历史")).clicked() {
                     self.history_panel.visible = true;
                 }
                 
-                if ui.button("⚙ 数据管理").clicked() {
+                if ui.add_enabled(role.can_manage(), egui::Button::new(tr("⚙ 数据管理"))).clicked() {
                     self.settings_panel.visible = true;
                 }
                 
+                if ui.button(tr("🧾 审计日志")).clicked() {
+                    self.audit_panel.open(&self.store);
+                }
+                
+                if ui.button(tr("📝 运行日志")).clicked() {
+                    self.log_viewer.open();
+                }
+                
+                if ui.button(tr("📅 检查日程")).clicked() {
+                    self.schedule_panel.open(&self.store, &self.records, self.main_panel.round_no);
+                }
+                
+                if ui.button(tr("📋 检查结果")).clicked() {
+                    self.outcome_panel.open(&self.store);
+                }
+                
+                if ui.button(tr("📈 统计")).clicked() {
+                    self.statistics_panel.open(&self.records, &self.departments, &self.specialties);
+                }
+                
+                let presentation_label = if self.presentation.visible { tr("🖥 关闭投影") } else { tr("🖥 投影模式") };
+                if ui.button(presentation_label).clicked() {
+                    self.presentation.visible = !self.presentation.visible;
+                }
+                
+                let server_label = if self.live_server.is_some() { tr("📡 投屏中") } else { tr("📡 投屏") };
+                if ui.add_enabled(role.can_draw(), egui::Button::new(server_label)).clicked() {
+                    self.server_panel.visible = true;
+                }
+                
+                if ui.add_enabled(role.can_draw(), egui::Button::new(tr("🎞 动画"))).clicked() {
+                    self.animation_dialog.open(self.main_panel.animation);
+                }
+                
                 ui.separator();
                 
-                if ui.button("📊 导出Excel").clicked() {
+                if ui.button(tr("📊 导出Excel")).clicked() {
                     self.export_to_excel();
                 }
                 
-                if ui.button("🖨 打印").clicked() {
-                    self.print_records();
+                if ui.add_enabled(self.print_job.is_none(), egui::Button::new(tr("🖨 打印"))).clicked() {
+                    if self.records.iter().all(|r| r.archived) {
+                        self.status_message = Some(tr("没有可打印的记录").to_string());
+                    } else {
+                        self.print_dialog.open();
+                    }
                 }
                 
                 ui.separator();
                 
                 // 显示本轮已抽中数量
-                let round_count = self.main_panel.current_round_pressure_depts.len() 
-                    + self.main_panel.current_round_mechanical_depts.len();
+                let round_count = self.main_panel.round_count();
                 if round_count > 0 {
-                    ui.label(format!("本轮已抽: {}", round_count));
+                    ui.label(trf("本轮已抽: {}", &[&round_count]));
                 }
                 
-                if ui.button("🔄 开始新一轮").clicked() {
-                    self.main_panel.current_round_pressure_depts.clear();
-                    self.main_panel.current_round_mechanical_depts.clear();
-                    self.main_panel.pressure_result = None;
-                    self.main_panel.mechanical_result = None;
-                    self.status_message = Some("已开始新一轮抽签".to_string());
+                let undoable = self.undoable_draw(&operator);
+                if ui.add_enabled(!undoable.is_empty(), egui::Button::new(tr("↩ 撤销上一次")))
+                    .on_hover_text(tr("撤销本轮最近一次抽签（抽签员只能撤销自己的抽签）"))
+                    .clicked()
+                {
+                    self.undo_dialog.open(undoable);
+                }
+                
+                let can_start_round = role.can_draw() && !self.main_panel.is_drawing && !self.main_panel.has_pending();
+                if ui.add_enabled(can_start_round, egui::Button::new(tr("🔄 开始新一轮"))).clicked() {
+                    self.main_panel.start_new_round(&self.store);
+                    self.status_message = Some(trf("已开始第{}轮抽签", &[&self.main_panel.round_no]));
                 }
                 
                 // 状态消息
@@ -186,7 +639,7 @@ impl eframe::App for QualityDrawApp {
             .default_width(180.0)
             .show(ctx, |ui| {
                 egui::ScrollArea::vertical().show(ui, |ui| {
-                    self.main_panel.show_department_selector(ui, &self.departments);
+                    self.main_panel.show_department_selector(ui, &self.departments, &self.specialties);
                 });
             });
         
@@ -196,23 +649,131 @@ impl eframe::App for QualityDrawApp {
                 ui.add_space(30.0);
                 
                 // 抽签动画区域
-                self.main_panel.show_draw_area(ui, &self.departments);
+                self.main_panel.show_draw_area(ui);
                 
                 ui.add_space(30.0);
                 
-                // 控制按钮
-                self.main_panel.show_controls(ui, &self.specialists, &self.departments, &self.records);
+                // 控制按钮（观察员只能查看）
+                if role.can_draw() {
+                    self.main_panel.show_controls(ui, &self.specialists, &self.departments, &self.records);
+                } else {
+                    ui.label(egui::RichText::new(tr("观察员账户只能查看，不能执行抽签")).color(egui::Color32::GRAY));
+                }
                 
                 ui.add_space(30.0);
                 
                 // 结果显示
-                self.main_panel.show_results(ui, &self.departments);
+                self.main_panel.show_results(ui, &self.departments, &self.specialties);
             });
         });
         
+        // 见证确认
+        let witness_action = self.witness_dialog.show(
+            ctx,
+            &self.main_panel.pending_records,
+            &self.operators,
+            &operator,
+            self.live_server.is_some(),
+        );
+        match witness_action {
+            Some(WitnessAction::Confirm(witness)) => {
+                // 保存失败时结果仍待确认，错误已显示在状态栏
+                let _ = self.confirm_draw(&witness, None);
+            }
+            Some(WitnessAction::Cancel(reason)) => {
+                let cancelled = self.main_panel.cancel_pending();
+                self.store.append_audit(&AuditEntry::new(
+                    &operator,
+                    AuditAction::CancelDraw,
+                    Self::draw_label(&cancelled),
+                ).with_old(&cancelled).with_new(&reason));
+                self.status_message = Some(trf("已取消未确认的结果（{}），可重新抽取", &[&reason]));
+            }
+            None => {}
+        }
+        
+        if let Some(reason) = self.undo_dialog.show(ctx) {
+            self.undo_last_draw(&reason, &operator);
+        }
+        
+        // 投影窗口
+        if let Some(command) = self.presentation.show(ctx, &mut self.main_panel, &self.organization, &self.departments, &self.specialties) {
+            self.handle_key(command, role);
+        }
+        
         // 弹窗
-        self.settings_panel.show(ctx, &mut self.specialists, &mut self.departments, &self.store);
-        self.history_panel.show(ctx, &mut self.records, &self.store);
+        self.settings_panel.show(
+            ctx,
+            &mut self.specialists,
+            &mut self.departments,
+            &mut self.specialties,
+            &mut self.operators,
+            &mut self.organization,
+            &self.main_panel.current_round,
+            &self.store,
+            &operator,
+        );
+        self.main_panel.rules = self.organization.rules;
+        // 账户管理中修改了当前账户的角色时同步
+        if let Some(updated) = self.operators.iter().find(|o| o.id == operator.id) {
+            self.operator = Some(updated.clone());
+        }
+        let history_action = self.history_panel.show(
+            ctx,
+            &mut self.records,
+            &self.store,
+            self.main_panel.round_no,
+            &operator,
+        );
+        match history_action {
+            Some(HistoryAction::Voided(record)) => {
+                self.main_panel.release_voided(&record);
+                self.status_message = Some(trf(
+                    "已作废 {} 的{}抽签结果",
+                    &[&record.target_department_name, &record.specialty_label()],
+                ));
+            }
+            Some(HistoryAction::Redraw { target_department_id, specialty }) => {
+                self.history_panel.visible = false;
+                self.main_panel.redraw(&target_department_id, &self.specialists, &self.departments, &self.specialties, &self.records);
+                self.status_message = Some(trf("正在重新抽取{}", &[&specialty]));
+            }
+            Some(HistoryAction::Replay(record)) => self.replay_viewer.open(&record),
+            None => {}
+        }
+        self.audit_panel.show(ctx, &self.store);
+        self.log_viewer.show(ctx);
+        self.schedule_panel.show(ctx, &self.records, &self.organization, &self.store, &operator);
+        self.outcome_panel.show(ctx, &self.records, &self.organization, &self.store, &operator);
+        self.statistics_panel.show(ctx, &self.organization);
+        if let Some(options) = self.print_dialog.show(ctx) {
+            self.print_records(options);
+        }
+        self.poll_print_job(ctx);
+        self.replay_viewer.show(ctx, self.main_panel.display);
+        if let Some(settings) = self.animation_dialog.show(ctx) {
+            self.main_panel.animation = settings;
+            self.store.save_animation_settings(&settings);
+        }
+        match self.server_panel.show(ctx, self.live_server.as_ref()) {
+            Some(ServerAction::Start(addr)) => match LiveServer::start(addr, self.store.for_organization(&self.organization.id)) {
+                Ok(server) => {
+                    logging::info("app", "投屏服务已启动", &[("urls", &server.viewer_urls().join(" "))]);
+                    self.status_message = Some(trf("投屏服务已启动: {}", &[&server.viewer_urls().join("  ")]));
+                    self.live_server = Some(server);
+                }
+                Err(e) => {
+                    logging::error("app", "投屏服务启动失败", &[("addr", &addr), ("error", &e)]);
+                    self.status_message = Some(e);
+                }
+            },
+            Some(ServerAction::Stop) => {
+                logging::info("app", "投屏服务已停止", &[]);
+                self.live_server = None;
+                self.status_message = Some(tr("投屏服务已停止").to_string());
+            }
+            None => {}
+        }
         
         // 清除状态消息（5秒后）
         // 注意：简化实现，实际可以使用计时器
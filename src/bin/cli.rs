@@ -0,0 +1,1339 @@
+//! 质量监督检查抽签程序 - 命令行版本
+//!
+//! 不依赖图形界面完成抽签、导出和复核，与图形界面共用数据目录和抽签规则。
+//!
+//! 示例：
+//! ```text
+//! quality_draw-cli departments
+//! quality_draw-cli --org yc departments
+//! quality_draw-cli --user admin start-round
+//! quality_draw-cli --user admin --witness zhang draw --target nd
+//! quality_draw-cli --user admin --witness zhang draw-round --seed 42
+//! quality_draw-cli export --format csv --output 抽签结果.csv
+//! quality_draw-cli --lang en-US export --format xlsx --output draw_results.xlsx
+//! quality_draw-cli --user admin schedule set --record 3f2a --date 2026-11-03 --slot morning
+//! quality_draw-cli schedule export --format ics --output 检查日程.ics
+//! quality_draw-cli --user admin outcome set --record 3f2a --status done --general 2 --score 92
+//! quality_draw-cli outcome export --year 2026 --output 年度质量报告.xlsx
+//! quality_draw-cli stats --output 抽签统计.xlsx
+//! quality_draw-cli simulate --rounds 5000 --seed 1 --cross-avoidance false
+//! quality_draw-cli --json verify
+//! ```
+//!
+//! 需要登录的命令从环境变量 `QUALITY_DRAW_PASSWORD` 读取密码，未设置时在终端提示输入（不回显）。
+//! 密码不接受命令行参数，以免出现在进程列表和命令历史中。
+//!
+//! 抽签需要执行人以外的账户见证：抽签结果先输出到标准错误，再由 `--witness` 指定的见证人确认，
+//! 见证人密码从环境变量 `QUALITY_DRAW_WITNESS_PASSWORD` 读取或在终端输入。确认通过后才保存记录并
+//! 保存见证信息；确认未通过时丢弃结果，并在审计日志中记录取消。
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use chrono::{Datelike, NaiveDate};
+use clap::{Parser, Subcommand, ValueEnum};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::Serialize;
+
+use quality_draw::export::ExportManager;
+use quality_draw::i18n::{Locale, set_locale, tr};
+use quality_draw::logic::{
+    AuthManager, DepartmentDraw, DrawEngine, OutcomeItem, OutcomeManager, ScheduleManager, SimulationReport, Simulator,
+    StatsManager,
+};
+use quality_draw::models::{
+    AuditAction, AuditEntry, ChangeStamp, Department, DrawRecord, DrawRules, FindingCounts, InspectionOutcome, InspectionStatus,
+    Operator, Organization, QualitySpecialist, Role, ScheduleEntry, Specialty, TeamInfo, TimeSlot, WitnessInfo, specialty_name,
+};
+use quality_draw::storage::DataStore;
+
+/// 质量监督检查抽签程序（命令行版本）
+#[derive(Parser)]
+#[command(name = "quality_draw-cli", version)]
+struct Cli {
+    /// 以 JSON 格式输出，便于脚本处理
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// 数据目录（默认与图形界面相同：程序所在目录下的 data 文件夹）
+    #[arg(long, global = true, env = "QUALITY_DRAW_DATA_DIR")]
+    data_dir: Option<PathBuf>,
+
+    /// 单位ID或名称（配置了多个单位时使用，默认为第一个单位）
+    #[arg(long, global = true, env = "QUALITY_DRAW_ORG")]
+    org: Option<String>,
+
+    /// 登录名（抽签和导入需要登录）
+    #[arg(long, global = true, env = "QUALITY_DRAW_USER")]
+    user: Option<String>,
+
+    /// 见证人登录名（抽签需要执行人以外的账户见证）
+    #[arg(long, global = true, env = "QUALITY_DRAW_WITNESS")]
+    witness: Option<String>,
+
+    /// 输出和导出文件的语言（zh-CN 或 en-US，默认与图形界面的设置相同）
+    #[arg(long, global = true, env = "QUALITY_DRAW_LANG")]
+    lang: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 列出所有单位
+    Organizations,
+    /// 列出所有部门
+    Departments,
+    /// 列出所有专业
+    Specialties,
+    /// 查看当前轮次及本轮已抽取情况
+    Status,
+    /// 开始新一轮抽签
+    StartRound,
+    /// 为指定被检部门抽签
+    Draw {
+        /// 被检部门ID
+        #[arg(long)]
+        target: String,
+        /// 只抽取指定专业（专业ID或名称，默认抽取该部门需要的全部专业）
+        #[arg(long)]
+        specialty: Option<String>,
+        /// 随机种子（用于复现，默认随机）
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// 为所有尚未抽取的部门完成本轮抽签
+    DrawRound {
+        /// 随机种子（用于复现，默认随机）
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// 导出抽签记录
+    Export {
+        /// 导出格式
+        #[arg(long, value_enum)]
+        format: ExportFormat,
+        /// 输出文件路径
+        #[arg(long)]
+        output: PathBuf,
+        /// 包含已归档的记录
+        #[arg(long)]
+        include_archived: bool,
+    },
+    /// 用记录中的种子和候选名单复核全部抽签结果
+    Verify,
+    /// 从 CSV 文件导入质量专责（每行：姓名,部门名称或ID,专业）
+    ImportSpecialists {
+        /// CSV 文件路径
+        file: PathBuf,
+    },
+    /// 检查日程安排
+    Schedule {
+        #[command(subcommand)]
+        command: ScheduleCommand,
+    },
+    /// 抽签统计：各部门次数、配对频次、公平性检验和季度趋势
+    Stats {
+        /// 同时导出到 Excel 文件
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// 用当前部门配置模拟大量虚拟轮次，评估候选抽签规则（死局率、任务均衡和配对重复率）
+    Simulate {
+        /// 模拟轮数
+        #[arg(long, default_value_t = 1000)]
+        rounds: usize,
+        /// 随机种子（相同种子结果相同）
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// 候选规则：本轮已抽中的部门不再参与抽取（默认与单位当前规则相同）
+        #[arg(long)]
+        once_per_round: Option<bool>,
+        /// 候选规则：交叉回避（默认与单位当前规则相同）
+        #[arg(long)]
+        cross_avoidance: Option<bool>,
+    },
+    /// 检查结果跟踪
+    Outcome {
+        #[command(subcommand)]
+        command: OutcomeCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScheduleCommand {
+    /// 列出某一轮的检查安排和冲突
+    List {
+        /// 轮次（默认为当前轮次）
+        #[arg(long)]
+        round: Option<u32>,
+    },
+    /// 为一条抽签记录安排检查日期和时段
+    Set {
+        /// 抽签记录ID（可只写前几位）
+        #[arg(long)]
+        record: String,
+        /// 检查日期，如 2026-11-03
+        #[arg(long)]
+        date: NaiveDate,
+        /// 检查时段
+        #[arg(long, value_enum, default_value = "all-day")]
+        slot: SlotArg,
+        /// 备注（如带队人员、集合地点）
+        #[arg(long)]
+        note: Option<String>,
+    },
+    /// 取消一条抽签记录的检查安排
+    Remove {
+        /// 抽签记录ID（可只写前几位）
+        #[arg(long)]
+        record: String,
+    },
+    /// 导出日历或检查日程安排表
+    Export {
+        /// 导出格式
+        #[arg(long, value_enum)]
+        format: ScheduleFormat,
+        /// 输出文件路径
+        #[arg(long)]
+        output: PathBuf,
+        /// 轮次（默认为当前轮次）
+        #[arg(long)]
+        round: Option<u32>,
+    },
+}
+
+#[derive(Subcommand)]
+enum OutcomeCommand {
+    /// 列出未完成的检查（计划中和检查中）
+    List {
+        /// 列出指定年度的全部检查（含已完成）
+        #[arg(long)]
+        year: Option<i32>,
+    },
+    /// 登记或修改一条抽签记录的检查结果（未指定的项保持不变）
+    Set {
+        /// 抽签记录ID（可只写前几位）
+        #[arg(long)]
+        record: String,
+        /// 检查状态
+        #[arg(long, value_enum)]
+        status: Option<StatusArg>,
+        /// 严重问题数
+        #[arg(long)]
+        major: Option<u32>,
+        /// 一般问题数
+        #[arg(long)]
+        general: Option<u32>,
+        /// 轻微问题数
+        #[arg(long)]
+        minor: Option<u32>,
+        /// 评分（0~100）
+        #[arg(long)]
+        score: Option<f32>,
+        /// 检查报告附件路径
+        #[arg(long)]
+        report: Option<String>,
+        /// 结案日期（状态为已完成且未填写时默认为今天）
+        #[arg(long)]
+        closed_on: Option<NaiveDate>,
+    },
+    /// 导出年度质量报告（Excel：检查结果明细和汇总）
+    Export {
+        /// 年度（默认为今年）
+        #[arg(long)]
+        year: Option<i32>,
+        /// 输出文件路径
+        #[arg(long)]
+        output: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum StatusArg {
+    Planned,
+    InProgress,
+    Done,
+}
+
+impl From<StatusArg> for InspectionStatus {
+    fn from(status: StatusArg) -> Self {
+        match status {
+            StatusArg::Planned => InspectionStatus::Planned,
+            StatusArg::InProgress => InspectionStatus::InProgress,
+            StatusArg::Done => InspectionStatus::Done,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    Xlsx,
+    Pdf,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SlotArg {
+    Morning,
+    Afternoon,
+    AllDay,
+}
+
+impl From<SlotArg> for TimeSlot {
+    fn from(slot: SlotArg) -> Self {
+        match slot {
+            SlotArg::Morning => TimeSlot::Morning,
+            SlotArg::Afternoon => TimeSlot::Afternoon,
+            SlotArg::AllDay => TimeSlot::AllDay,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ScheduleFormat {
+    Ics,
+    Xlsx,
+}
+
+/// 命令执行上下文
+struct Context {
+    store: DataStore,
+    organization: Organization,
+    json: bool,
+    user: Option<String>,
+    witness: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let root = cli.data_dir.map(DataStore::with_dir).unwrap_or_default();
+    let locale = match cli.lang.as_deref() {
+        Some(code) => Locale::from_code(code).ok_or_else(|| format!("不支持的语言 {}（可选 zh-CN、en-US）", code)),
+        None => Ok(root.load_locale()),
+    };
+    let organization = locale.and_then(|locale| {
+        set_locale(locale);
+        find_organization(&root.load_organizations(), cli.org.as_deref())
+    });
+    let organization = match organization {
+        Ok(organization) => organization,
+        Err(e) => {
+            if cli.json {
+                println!("{}", serde_json::json!({ "error": e }));
+            } else {
+                eprintln!("错误: {}", e);
+            }
+            return ExitCode::FAILURE;
+        }
+    };
+    let ctx = Context {
+        store: root.for_organization(&organization.id),
+        organization,
+        json: cli.json,
+        user: cli.user,
+        witness: cli.witness,
+    };
+
+    let result = match cli.command {
+        Command::Organizations => organizations(&ctx),
+        Command::Departments => departments(&ctx),
+        Command::Specialties => specialties(&ctx),
+        Command::Status => status(&ctx),
+        Command::StartRound => start_round(&ctx),
+        Command::Draw { target, specialty, seed } => draw(&ctx, &target, specialty.as_deref(), seed),
+        Command::DrawRound { seed } => draw_round(&ctx, seed),
+        Command::Export { format, output, include_archived } => export(&ctx, format, &output, include_archived),
+        Command::Verify => verify(&ctx),
+        Command::ImportSpecialists { file } => import_specialists(&ctx, &file),
+        Command::Schedule { command } => match command {
+            ScheduleCommand::List { round } => schedule_list(&ctx, round),
+            ScheduleCommand::Set { record, date, slot, note } => schedule_set(&ctx, &record, date, slot.into(), note),
+            ScheduleCommand::Remove { record } => schedule_remove(&ctx, &record),
+            ScheduleCommand::Export { format, output, round } => schedule_export(&ctx, format, &output, round),
+        },
+        Command::Stats { output } => stats(&ctx, output.as_deref()),
+        Command::Simulate { rounds, seed, once_per_round, cross_avoidance } => {
+            let current = ctx.organization.rules;
+            let candidate = DrawRules {
+                once_per_round: once_per_round.unwrap_or(current.once_per_round),
+                cross_avoidance: cross_avoidance.unwrap_or(current.cross_avoidance),
+            };
+            simulate(&ctx, &candidate, rounds, seed)
+        }
+        Command::Outcome { command } => match command {
+            OutcomeCommand::List { year } => outcome_list(&ctx, year),
+            OutcomeCommand::Set { record, status, major, general, minor, score, report, closed_on } => {
+                let findings = [major, general, minor];
+                outcome_set(&ctx, &record, status.map(Into::into), findings, score, report, closed_on)
+            }
+            OutcomeCommand::Export { year, output } => outcome_export(&ctx, year, &output),
+        },
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            if ctx.json {
+                println!("{}", serde_json::json!({ "error": e }));
+            } else {
+                eprintln!("错误: {}", e);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 读取密码：优先取环境变量 `env`，未设置时在终端提示输入（不回显）
+fn read_password(env: &str, user: &str) -> Result<String, String> {
+    if let Ok(password) = std::env::var(env) {
+        return Ok(password);
+    }
+    rpassword::prompt_password(format!("{} 的密码: ", user))
+        .map_err(|e| format!("无法读取密码（{}），请在终端中运行或设置环境变量 {}", e, env))
+}
+
+impl Context {
+    /// 登录并检查权限
+    fn login(&self, allowed: fn(&Role) -> bool) -> Result<Operator, String> {
+        let Some(user) = &self.user else {
+            return Err("该命令需要登录，请提供 --user".to_string());
+        };
+        let password = read_password("QUALITY_DRAW_PASSWORD", user)?;
+        let operator = AuthManager::authenticate(&self.store.load_operators()?, user, &password)?;
+        if !allowed(&operator.role) {
+            return Err(format!("{}没有执行此操作的权限", operator.role.display_name()));
+        }
+        Ok(operator)
+    }
+
+    /// 验证见证人：必须是执行抽签的操作员以外的账户，在抽签结果显示后验证
+    fn witness(&self, operator: &Operator) -> Result<Operator, String> {
+        let Some(witness) = &self.witness else {
+            return Err("抽签需要见证人确认，请提供 --witness".to_string());
+        };
+        let password = read_password("QUALITY_DRAW_WITNESS_PASSWORD", witness)?;
+        AuthManager::authenticate_witness(&self.store.load_operators()?, &operator.id, witness, &password)
+    }
+
+    /// 按格式输出：JSON 模式输出 `value`，否则输出 `text`
+    ///
+    /// 输出被管道提前关闭（如 `| head`）时忽略写入错误
+    fn print<T: Serialize>(&self, value: &T, text: impl FnOnce() -> String) {
+        let output = if self.json {
+            serde_json::to_string_pretty(value).unwrap_or_default()
+        } else {
+            text()
+        };
+        writeln!(std::io::stdout(), "{}", output).ok();
+    }
+}
+
+/// 随机种子来源：指定种子时依次派生每次抽签的种子，保证整轮可复现
+struct SeedSource(Option<ChaCha8Rng>);
+
+impl SeedSource {
+    fn new(seed: Option<u64>) -> Self {
+        Self(seed.map(ChaCha8Rng::seed_from_u64))
+    }
+
+    fn next(&mut self) -> u64 {
+        match &mut self.0 {
+            Some(rng) => rng.gen(),
+            None => rand::random(),
+        }
+    }
+}
+
+/// 单次抽签结果输出
+#[derive(Serialize)]
+struct DrawOutput {
+    round: u32,
+    target_department_id: String,
+    target_department_name: String,
+    specialty: String,
+    specialty_name: String,
+    /// 检查组有多个部门时按抽中顺序排列
+    selected_department_ids: Vec<String>,
+    selected_department_names: Vec<String>,
+    seed: u64,
+    candidates: Vec<String>,
+    rule_notes: Vec<String>,
+    record_ids: Vec<String>,
+    /// 见证人姓名（确认前为空）
+    witness: String,
+}
+
+impl DrawOutput {
+    fn text(&self) -> String {
+        let witness = if self.witness.is_empty() {
+            "待见证人确认".to_string()
+        } else {
+            format!("见证人 {}", self.witness)
+        };
+        format!(
+            "第{}轮  {} {} → {}（种子 {}，候选 {}，{}）",
+            self.round,
+            self.target_department_name,
+            self.specialty_name,
+            self.selected_department_names.join("、"),
+            self.seed,
+            self.candidates.join("、"),
+            witness
+        )
+    }
+
+    /// 审计日志中的抽签说明
+    fn label(&self) -> String {
+        format!(
+            "第{}轮 {} {} → {}",
+            self.round,
+            self.target_department_name,
+            self.specialty_name,
+            self.selected_department_names.join("、")
+        )
+    }
+}
+
+/// 按单位ID或名称查找单位，未指定时为第一个单位
+fn find_organization(organizations: &[Organization], key: Option<&str>) -> Result<Organization, String> {
+    match key {
+        None => Ok(organizations[0].clone()),
+        Some(key) => organizations.iter()
+            .find(|o| o.id.eq_ignore_ascii_case(key) || o.name == key)
+            .cloned()
+            .ok_or_else(|| format!("未找到单位 {}", key)),
+    }
+}
+
+fn organizations(ctx: &Context) -> Result<ExitCode, String> {
+    let organizations = ctx.store.load_organizations();
+    ctx.print(&organizations, || {
+        organizations.iter()
+            .map(|o| {
+                let current = if o.id == ctx.organization.id { "  *" } else { "" };
+                format!("{:<12} {}{}", o.id, o.name, current)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn departments(ctx: &Context) -> Result<ExitCode, String> {
+    let departments = ctx.store.load_departments();
+    let specialties = ctx.store.load_specialties();
+    let names = |ids: &[String]| ids.iter().map(|id| tr(specialty_name(&specialties, id))).collect::<Vec<_>>().join(tr("、"));
+    ctx.print(&departments, || {
+        departments.iter()
+            .map(|d| format!("{:<8} {}（{}）需要: {}  派出: {}", d.id, d.name, d.category, names(&d.needs), names(&d.supplies)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn specialties(ctx: &Context) -> Result<ExitCode, String> {
+    let specialties = ctx.store.load_specialties();
+    ctx.print(&specialties, || {
+        specialties.iter()
+            .map(|s| format!("{:<12} {}", s.id, s.name))
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn status(ctx: &Context) -> Result<ExitCode, String> {
+    #[derive(Serialize)]
+    struct StatusOutput {
+        round: u32,
+        /// 专业ID → 本轮已抽中的 (被检部门ID, 抽中部门ID)
+        drawn: BTreeMap<String, Vec<(String, String)>>,
+        remaining: Vec<(String, String)>,
+    }
+
+    let departments = ctx.store.load_departments();
+    let specialties = ctx.store.load_specialties();
+    let records = ctx.store.load_records()?;
+    let round = ctx.store.current_round(&records);
+    let drawn = specialties.iter()
+        .map(|s| (s.id.clone(), DrawEngine::round_pairs(&records, round, &s.id)))
+        .filter(|(_, pairs)| !pairs.is_empty())
+        .collect();
+    let remaining = remaining_draws(&departments, &records, round);
+
+    let name = |id: &str| departments.iter().find(|d| d.id == id).map(|d| d.name.clone()).unwrap_or_else(|| id.to_string());
+    let output = StatusOutput { round, drawn, remaining };
+    ctx.print(&output, || {
+        let mut lines = vec![format!("当前第{}轮", output.round)];
+        for (specialty, pairs) in &output.drawn {
+            for (target, selected) in pairs {
+                lines.push(format!("  {} {} → {}", name(target), tr(specialty_name(&specialties, specialty)), name(selected)));
+            }
+        }
+        lines.push(format!("尚未抽取: {} 项", output.remaining.len()));
+        lines.join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn start_round(ctx: &Context) -> Result<ExitCode, String> {
+    ctx.login(Role::can_draw)?;
+    let records = ctx.store.load_records()?;
+    let round = ctx.store.current_round(&records);
+    // 当前轮次还没有任何记录时不再递增，避免产生空轮次
+    let new_round = if records.iter().any(|r| r.round == round) { round + 1 } else { round };
+    ctx.store.save_current_round(new_round);
+    ctx.print(&serde_json::json!({ "round": new_round }), || format!("已开始第{}轮抽签", new_round));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn draw(ctx: &Context, target: &str, specialty: Option<&str>, seed: Option<u64>) -> Result<ExitCode, String> {
+    let operator = ctx.login(Role::can_draw)?;
+    let departments = ctx.store.load_departments();
+    let specialties = ctx.store.load_specialties();
+    let department = departments.iter()
+        .find(|d| d.id == target)
+        .ok_or_else(|| format!("未找到部门 {}", target))?;
+
+    let to_draw = match specialty {
+        Some(key) => vec![find_specialty(&specialties, key)?.id.clone()],
+        None => department.needs.clone(),
+    };
+
+    let mut records = ctx.store.load_records()?;
+    let round = ctx.store.current_round(&records);
+    let saved = records.len();
+    let mut seeds = SeedSource::new(seed);
+    let mut outputs = Vec::new();
+    for specialty in to_draw {
+        let specialty = specialty_or_legacy(&specialties, &specialty);
+        outputs.push(draw_one(&operator, &departments, &mut records, round, target, &specialty, seeds.next(), &ctx.organization.rules)?);
+    }
+    confirm_and_save(ctx, &operator, round, records.split_off(saved), &mut outputs)?;
+
+    ctx.print(&outputs, || outputs.iter().map(DrawOutput::text).collect::<Vec<_>>().join("\n"));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn draw_round(ctx: &Context, seed: Option<u64>) -> Result<ExitCode, String> {
+    let operator = ctx.login(Role::can_draw)?;
+    let departments = ctx.store.load_departments();
+    let specialties = ctx.store.load_specialties();
+    let mut records = ctx.store.load_records()?;
+    let round = ctx.store.current_round(&records);
+    let saved = records.len();
+
+    let mut seeds = SeedSource::new(seed);
+    let mut outputs = Vec::new();
+    let mut failures = Vec::new();
+    for (target, specialty) in remaining_draws(&departments, &records, round) {
+        let specialty = specialty_or_legacy(&specialties, &specialty);
+        match draw_one(&operator, &departments, &mut records, round, &target, &specialty, seeds.next(), &ctx.organization.rules) {
+            Ok(output) => outputs.push(output),
+            Err(e) => failures.push(e),
+        }
+    }
+    confirm_and_save(ctx, &operator, round, records.split_off(saved), &mut outputs)?;
+
+    #[derive(Serialize)]
+    struct RoundOutput {
+        draws: Vec<DrawOutput>,
+        failures: Vec<String>,
+    }
+    let output = RoundOutput { draws: outputs, failures };
+    ctx.print(&output, || {
+        let mut lines: Vec<String> = output.draws.iter().map(DrawOutput::text).collect();
+        lines.extend(output.failures.iter().map(|e| format!("未能抽取: {}", e)));
+        if lines.is_empty() {
+            lines.push("本轮已全部抽取完成".to_string());
+        }
+        lines.join("\n")
+    });
+    Ok(if output.failures.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+/// 执行一次抽签，生成的记录追加到 `records` 末尾等待见证确认（不保存）
+///
+/// `records` 包含本次命令中之前抽出的待确认记录，保证本轮规则包含刚刚抽出的结果
+#[allow(clippy::too_many_arguments)]
+fn draw_one(
+    operator: &Operator,
+    departments: &[Department],
+    records: &mut Vec<DrawRecord>,
+    round: u32,
+    target: &str,
+    specialty: &Specialty,
+    seed: u64,
+    rules: &DrawRules,
+) -> Result<DrawOutput, String> {
+    let current_round = DrawEngine::round_pairs(records, round, &specialty.id);
+    let DepartmentDraw { selected, seed, candidates, rule_notes } =
+        DrawEngine::draw_department(departments, target, specialty, &current_round, rules, seed)?;
+    let target_department = departments.iter()
+        .find(|d| d.id == target)
+        .ok_or_else(|| format!("未找到部门 {}", target))?;
+
+    // 检查组的每个部门一条记录，同一次抽取的记录共用组ID
+    let group_id = uuid::Uuid::new_v4().to_string();
+    let mut record_ids = Vec::new();
+    for (position, dept) in selected.iter().enumerate() {
+        let mut record = DrawRecord::new(
+            &target_department.id,
+            &target_department.name,
+            specialty,
+            &dept.id,
+            &dept.name,
+            &dept.id,
+            &dept.name,
+        )
+        .with_draw_info(round, seed, candidates.clone(), rule_notes.clone())
+        .with_operator(operator.id.clone(), operator.display_name.clone());
+        if selected.len() > 1 {
+            record = record.with_team(TeamInfo { group_id: group_id.clone(), position, size: selected.len() });
+        }
+        record_ids.push(record.id.clone());
+        records.push(record);
+    }
+
+    Ok(DrawOutput {
+        round,
+        target_department_id: target_department.id.clone(),
+        target_department_name: target_department.name.clone(),
+        specialty: specialty.id.clone(),
+        specialty_name: specialty.name.clone(),
+        selected_department_ids: selected.iter().map(|d| d.id.clone()).collect(),
+        selected_department_names: selected.into_iter().map(|d| d.name).collect(),
+        seed,
+        candidates,
+        rule_notes,
+        record_ids,
+        witness: String::new(),
+    })
+}
+
+/// 见证确认后保存抽签结果
+///
+/// 先把结果输出到标准错误，再验证见证人；验证通过后保存记录并记入审计日志，
+/// 验证未通过时丢弃结果并记录取消，保存失败时不写确认的审计日志
+fn confirm_and_save(
+    ctx: &Context,
+    operator: &Operator,
+    round: u32,
+    pending: Vec<DrawRecord>,
+    outputs: &mut [DrawOutput],
+) -> Result<(), String> {
+    if pending.is_empty() {
+        return Ok(());
+    }
+    eprintln!("抽签结果（待见证人确认）:");
+    for output in outputs.iter() {
+        eprintln!("  {}", output.text());
+    }
+    let label = outputs.iter().map(DrawOutput::label).collect::<Vec<_>>().join("；");
+
+    let witness = match ctx.witness(operator) {
+        Ok(witness) => witness,
+        Err(e) => {
+            ctx.store.append_audit(&AuditEntry::new(operator, AuditAction::CancelDraw, label)
+                .with_old(&pending)
+                .with_new(&e));
+            return Err(format!("见证确认未通过，抽签结果已取消: {}", e));
+        }
+    };
+
+    let info = WitnessInfo::new(&witness, None);
+    let records: Vec<DrawRecord> = pending.into_iter().map(|r| r.with_witness(info.clone())).collect();
+    ctx.store.add_records(&records)?;
+    ctx.store.save_current_round(round);
+    ctx.store.append_audit(&AuditEntry::new(&witness, AuditAction::ConfirmDraw, label).with_new(&info));
+    for output in outputs.iter_mut() {
+        output.witness = witness.display_name.clone();
+    }
+    Ok(())
+}
+
+/// 按专业ID或名称查找专业（ID不区分大小写）
+fn find_specialty<'a>(specialties: &'a [Specialty], key: &str) -> Result<&'a Specialty, String> {
+    specialties.iter()
+        .find(|s| s.id.eq_ignore_ascii_case(key) || s.name == key || s.name.trim_end_matches('类') == key)
+        .ok_or_else(|| format!("未找到专业 {}", key))
+}
+
+/// 部门引用的专业（专业已被删除时用旧版本名称或ID代替）
+fn specialty_or_legacy(specialties: &[Specialty], id: &str) -> Specialty {
+    specialties.iter()
+        .find(|s| s.id == id)
+        .cloned()
+        .unwrap_or_else(|| Specialty::new(id, specialty_name(specialties, id)))
+}
+
+/// 本轮尚未抽齐的 (被检部门ID, 专业ID) 列表
+fn remaining_draws(departments: &[Department], records: &[DrawRecord], round: u32) -> Vec<(String, String)> {
+    departments.iter()
+        .flat_map(|d| d.needs.iter().map(move |s| (d, s)))
+        .filter(|(d, specialty)| {
+            let drawn = DrawEngine::round_pairs(records, round, specialty).iter().filter(|(target, _)| target == &d.id).count();
+            drawn < d.team_size(specialty)
+        })
+        .map(|(d, s)| (d.id.clone(), s.clone()))
+        .collect()
+}
+
+fn export(ctx: &Context, format: ExportFormat, output: &PathBuf, include_archived: bool) -> Result<ExitCode, String> {
+    let records: Vec<DrawRecord> = ctx.store.load_records()?
+        .into_iter()
+        .filter(|r| include_archived || !r.archived)
+        .collect();
+    if records.is_empty() {
+        return Err("没有可导出的记录".to_string());
+    }
+
+    match format {
+        ExportFormat::Xlsx => ExportManager::export_to_excel(&records, &ctx.organization, output)?,
+        ExportFormat::Pdf => ExportManager::export_to_pdf(&records, &ctx.organization, output)?,
+        ExportFormat::Csv => ExportManager::export_to_csv(&records, output)?,
+    }
+
+    ctx.print(
+        &serde_json::json!({ "path": output, "records": records.len() }),
+        || format!("已导出 {} 条记录到 {}", records.len(), output.display()),
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(ctx: &Context) -> Result<ExitCode, String> {
+    #[derive(Serialize)]
+    struct VerifyOutput {
+        record_id: String,
+        round: u32,
+        target_department_name: String,
+        specialty: String,
+        selected: String,
+        /// true 一致，false 不一致，null 旧记录无法复核
+        verified: Option<bool>,
+    }
+
+    let results: Vec<VerifyOutput> = ctx.store.load_records()?
+        .iter()
+        .map(|r| VerifyOutput {
+            record_id: r.id.clone(),
+            round: r.round,
+            target_department_name: r.target_department_name.clone(),
+            specialty: r.specialty_label().to_string(),
+            selected: r.selected_specialist_name.clone(),
+            verified: DrawEngine::verify_record(r),
+        })
+        .collect();
+    let mismatches = results.iter().filter(|r| r.verified == Some(false)).count();
+
+    ctx.print(&results, || {
+        let mut lines: Vec<String> = results.iter()
+            .map(|r| format!(
+                "[{}] 第{}轮 {} {} → {}",
+                match r.verified {
+                    Some(true) => "一致",
+                    Some(false) => "不一致",
+                    None => "无法复核",
+                },
+                r.round,
+                r.target_department_name,
+                r.specialty,
+                r.selected
+            ))
+            .collect();
+        lines.push(format!("共 {} 条记录，{} 条不一致", results.len(), mismatches));
+        lines.join("\n")
+    });
+    Ok(if mismatches == 0 { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn import_specialists(ctx: &Context, file: &PathBuf) -> Result<ExitCode, String> {
+    let operator = ctx.login(Role::can_manage)?;
+    let content = std::fs::read_to_string(file).map_err(|e| format!("读取 {} 失败: {}", file.display(), e))?;
+    let departments = ctx.store.load_departments();
+    let specialties = ctx.store.load_specialties();
+    let mut specialists = ctx.store.load_specialists();
+
+    let mut imported = Vec::new();
+    let mut skipped = Vec::new();
+    for (line_no, line) in content.trim_start_matches('\u{feff}').lines().enumerate() {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if line.trim().is_empty() || fields[0] == "姓名" {
+            continue;
+        }
+        let row = match parse_specialist_row(&fields, &departments, &specialties) {
+            Ok(row) => row,
+            Err(e) => {
+                skipped.push(format!("第{}行: {}", line_no + 1, e));
+                continue;
+            }
+        };
+        let (name, department, specialty) = row;
+        if specialists.iter().any(|s| s.name == name && s.department_id == department.id && s.specialty == specialty.id) {
+            skipped.push(format!("第{}行: {} 在{}的{}专业已存在", line_no + 1, name, department.name, specialty.name));
+            continue;
+        }
+
+        let specialist = QualitySpecialist::new(uuid::Uuid::new_v4().to_string(), name, &department.id, &specialty.id)
+            .stamped(ChangeStamp::now(&operator));
+        ctx.store.append_audit(&AuditEntry::new(
+            &operator,
+            AuditAction::AddSpecialist,
+            format!("{}（{} / {}）", specialist.name, department.name, specialty.name),
+        ).with_new(&specialist));
+        imported.push(specialist.name.clone());
+        specialists.push(specialist);
+    }
+    ctx.store.save_specialists(&specialists);
+
+    let output = serde_json::json!({ "imported": imported, "skipped": skipped });
+    ctx.print(&output, || {
+        let mut lines = vec![format!("已导入 {} 名专责", imported.len())];
+        lines.extend(skipped.iter().map(|s| format!("跳过 {}", s)));
+        lines.join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+/// 按ID或唯一的ID前缀查找有效的抽签记录
+fn find_record<'a>(records: &'a [DrawRecord], key: &str) -> Result<&'a DrawRecord, String> {
+    let matches: Vec<&DrawRecord> = records.iter().filter(|r| r.id.starts_with(key)).collect();
+    let record = match matches.as_slice() {
+        [] => return Err(format!("未找到抽签记录 {}", key)),
+        [record] => *record,
+        _ => return Err(format!("记录ID前缀 {} 对应多条记录，请写出更多位", key)),
+    };
+    if record.is_voided() {
+        return Err(format!("记录 {} 已作废，不能安排检查", key));
+    }
+    Ok(record)
+}
+
+/// 检查安排输出
+#[derive(Serialize)]
+struct ScheduleOutput {
+    record_id: String,
+    round: u32,
+    target_department_name: String,
+    specialty: String,
+    inspecting_department_name: String,
+    /// 未安排时为 null
+    date: Option<NaiveDate>,
+    slot: Option<TimeSlot>,
+    note: String,
+}
+
+impl ScheduleOutput {
+    fn new(record: &DrawRecord, entry: Option<&ScheduleEntry>) -> Self {
+        Self {
+            record_id: record.id.clone(),
+            round: record.round,
+            target_department_name: record.target_department_name.clone(),
+            specialty: record.specialty_label().to_string(),
+            inspecting_department_name: record.selected_from_department_name.clone(),
+            date: entry.map(|e| e.date),
+            slot: entry.map(|e| e.slot),
+            note: entry.map(|e| e.note.clone()).unwrap_or_default(),
+        }
+    }
+
+    fn text(&self) -> String {
+        let when = match (self.date, self.slot) {
+            (Some(date), Some(slot)) => format!("{} {}", date.format("%Y-%m-%d"), slot.display_name()),
+            _ => "未安排".to_string(),
+        };
+        format!(
+            "{:<8} {}  {} {} → {}{}",
+            &self.record_id[..8.min(self.record_id.len())],
+            when,
+            self.target_department_name,
+            self.specialty,
+            self.inspecting_department_name,
+            if self.note.is_empty() { String::new() } else { format!("（{}）", self.note) }
+        )
+    }
+}
+
+fn schedule_list(ctx: &Context, round: Option<u32>) -> Result<ExitCode, String> {
+    let records = ctx.store.load_records()?;
+    let schedule = ctx.store.load_schedule();
+    let round = round.unwrap_or_else(|| ctx.store.current_round(&records));
+    let items: Vec<ScheduleOutput> = ScheduleManager::round_items(&records, &schedule, round)
+        .iter()
+        .map(|item| ScheduleOutput::new(item.record, item.entry))
+        .collect();
+    let conflicts: Vec<String> = ScheduleManager::conflicts(&records, &schedule)
+        .iter()
+        .filter(|c| items.iter().any(|i| c.record_ids.contains(&i.record_id)))
+        .map(|c| c.description())
+        .collect();
+
+    let output = serde_json::json!({ "round": round, "items": items, "conflicts": conflicts });
+    ctx.print(&output, || {
+        let mut lines = vec![format!("第{}轮检查安排", round)];
+        lines.extend(items.iter().map(ScheduleOutput::text));
+        lines.extend(conflicts.iter().map(|c| format!("冲突: {}", c)));
+        lines.join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn schedule_set(ctx: &Context, key: &str, date: NaiveDate, slot: TimeSlot, note: Option<String>) -> Result<ExitCode, String> {
+    let operator = ctx.login(Role::can_draw)?;
+    let records = ctx.store.load_records()?;
+    let record = find_record(&records, key)?;
+
+    let mut entry = ScheduleEntry::new(&record.id, date, slot).stamped(ChangeStamp::now(&operator));
+    entry.note = note.unwrap_or_default();
+    let old = ctx.store.set_schedule(entry.clone());
+    let mut audit = AuditEntry::new(
+        &operator,
+        AuditAction::UpdateSchedule,
+        format!(
+            "第{}轮 {} {} → {}：{} {}",
+            record.round,
+            record.target_department_name,
+            record.specialty_label(),
+            record.selected_from_department_name,
+            date.format("%Y-%m-%d"),
+            slot.display_name()
+        ),
+    ).with_new(&entry);
+    if let Some(old) = &old {
+        audit = audit.with_old(old);
+    }
+    ctx.store.append_audit(&audit);
+
+    // 冲突只提示，不阻止保存
+    let conflicts: Vec<String> = ScheduleManager::conflicts(&records, &ctx.store.load_schedule())
+        .iter()
+        .filter(|c| c.record_ids.contains(&record.id))
+        .map(|c| c.description())
+        .collect();
+    let output = serde_json::json!({ "entry": ScheduleOutput::new(record, Some(&entry)), "conflicts": conflicts });
+    ctx.print(&output, || {
+        let mut lines = vec![format!("已安排 {}", ScheduleOutput::new(record, Some(&entry)).text())];
+        lines.extend(conflicts.iter().map(|c| format!("冲突: {}", c)));
+        lines.join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn schedule_remove(ctx: &Context, key: &str) -> Result<ExitCode, String> {
+    let operator = ctx.login(Role::can_draw)?;
+    let records = ctx.store.load_records()?;
+    let record = find_record(&records, key)?;
+    let removed = ctx.store.remove_schedule(&record.id)
+        .ok_or_else(|| format!("记录 {} 尚未安排检查", key))?;
+    ctx.store.append_audit(&AuditEntry::new(
+        &operator,
+        AuditAction::UpdateSchedule,
+        format!(
+            "取消安排：第{}轮 {} {} → {}",
+            record.round,
+            record.target_department_name,
+            record.specialty_label(),
+            record.selected_from_department_name
+        ),
+    ).with_old(&removed));
+
+    ctx.print(&serde_json::json!({ "record_id": record.id }), || format!("已取消 {} 的检查安排", record.id));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn schedule_export(ctx: &Context, format: ScheduleFormat, output: &PathBuf, round: Option<u32>) -> Result<ExitCode, String> {
+    let records = ctx.store.load_records()?;
+    let schedule = ctx.store.load_schedule();
+    let round = round.unwrap_or_else(|| ctx.store.current_round(&records));
+    let items = ScheduleManager::round_items(&records, &schedule, round);
+    let scheduled = items.iter().filter(|i| i.entry.is_some()).count();
+    if items.is_empty() {
+        return Err(format!("第{}轮没有有效的抽签记录", round));
+    }
+
+    match format {
+        ScheduleFormat::Ics => {
+            if scheduled == 0 {
+                return Err(format!("第{}轮尚未安排任何检查", round));
+            }
+            ExportManager::export_schedule_to_ics(&items, &ctx.organization, output)?
+        }
+        ScheduleFormat::Xlsx => {
+            let conflicts = ScheduleManager::conflicts(&records, &schedule);
+            ExportManager::export_schedule_to_excel(&items, &conflicts, &ctx.organization, output)?
+        }
+    }
+
+    ctx.print(
+        &serde_json::json!({ "path": output, "round": round, "scheduled": scheduled, "items": items.len() }),
+        || format!("已导出第{}轮检查安排（已安排 {} / 共 {} 项）到 {}", round, scheduled, items.len(), output.display()),
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn stats(ctx: &Context, output: Option<&std::path::Path>) -> Result<ExitCode, String> {
+    let stats = StatsManager::compute(&ctx.store.load_records()?, &ctx.store.load_departments(), &ctx.store.load_specialties());
+    if let Some(path) = output {
+        ExportManager::export_statistics_to_excel(&stats, &ctx.organization, path)?;
+    }
+
+    ctx.print(&stats, || {
+        let mut lines = vec![format!("有效记录 {} 条", stats.total_draws)];
+        for specialty in &stats.specialties {
+            lines.push(format!("{}（{} 条）", specialty.specialty_name, specialty.draws));
+            for d in &specialty.departments {
+                lines.push(format!("  {:<12} 被检 {:>3}  检查 {:>3}  期望 {:>6.2}", d.name, d.as_target, d.as_inspector, d.expected));
+            }
+            let fairness = &specialty.fairness;
+            lines.push(format!(
+                "  卡方 {:.3}  自由度 {}  p值 {}  {}",
+                fairness.chi_square,
+                fairness.degrees_of_freedom,
+                fairness.p_value.map(|p| format!("{:.4}", p)).unwrap_or_else(|| "-".to_string()),
+                fairness.conclusion()
+            ));
+        }
+        for quarter in &stats.quarters {
+            lines.push(format!("{}  {} 条", quarter.label, quarter.draws));
+        }
+        if let Some(path) = output {
+            lines.push(format!("已导出到 {}", path.display()));
+        }
+        lines.join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn simulate(ctx: &Context, candidate: &DrawRules, rounds: usize, seed: u64) -> Result<ExitCode, String> {
+    if rounds == 0 {
+        return Err("模拟轮数至少为 1".to_string());
+    }
+    let departments = ctx.store.load_departments();
+    let specialties = ctx.store.load_specialties();
+
+    // 候选规则与当前规则不同时一并模拟当前规则，便于对比
+    let mut rule_sets = vec![("当前规则", ctx.organization.rules)];
+    if *candidate != ctx.organization.rules {
+        rule_sets.push(("候选规则", *candidate));
+    }
+    let reports: Vec<SimulationReport> = rule_sets.iter()
+        .map(|(_, rules)| Simulator::run(&departments, &specialties, rules, rounds, seed))
+        .collect();
+
+    let on_off = |enabled: bool| if enabled { "开启" } else { "关闭" };
+    ctx.print(&reports, || {
+        let mut lines = Vec::new();
+        for ((label, rules), report) in rule_sets.iter().zip(&reports) {
+            lines.push(format!(
+                "{}（本轮已抽部门不再参与: {}，交叉回避: {}）",
+                label,
+                on_off(rules.once_per_round),
+                on_off(rules.cross_avoidance)
+            ));
+            lines.push(format!(
+                "  模拟 {} 轮（种子 {}），每轮 {} 次抽取",
+                report.rounds, report.seed, report.draws_per_round
+            ));
+            lines.push(format!(
+                "  死局率 {:.2}%（{} 轮无法抽完，共 {} 次抽取失败）",
+                report.dead_end_rate * 100.0, report.dead_end_rounds, report.failed_draws
+            ));
+            for (reason, count) in &report.dead_end_reasons {
+                lines.push(format!("    {} × {}", reason, count));
+            }
+            lines.push(format!("  任务量方差 {:.4}（各部门平均每轮检查次数之间）", report.load_variance));
+            for load in &report.loads {
+                lines.push(format!(
+                    "    {:<12} 平均 {:.2}  方差 {:.3}  最多 {}",
+                    load.name, load.mean, load.variance, load.max
+                ));
+            }
+            lines.push(format!("  相邻轮次配对重复率 {:.2}%", report.pair_repeat_rate * 100.0));
+            for pair in &report.top_pairs {
+                lines.push(format!(
+                    "    {} {} ← {}  {:.1}% 的轮次",
+                    pair.target, pair.specialty, pair.inspector, pair.rate * 100.0
+                ));
+            }
+        }
+        lines.join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+/// 检查结果输出
+#[derive(Serialize)]
+struct OutcomeOutput {
+    record_id: String,
+    round: u32,
+    target_department_name: String,
+    specialty: String,
+    inspecting_department_name: String,
+    status: InspectionStatus,
+    findings: FindingCounts,
+    score: Option<f32>,
+    report_path: Option<String>,
+    closed_on: Option<NaiveDate>,
+}
+
+impl OutcomeOutput {
+    fn new(item: &OutcomeItem) -> Self {
+        Self {
+            record_id: item.record.id.clone(),
+            round: item.record.round,
+            target_department_name: item.record.target_department_name.clone(),
+            specialty: item.record.specialty_label().to_string(),
+            inspecting_department_name: item.record.selected_from_department_name.clone(),
+            status: item.status(),
+            findings: item.outcome.map(|o| o.findings).unwrap_or_default(),
+            score: item.outcome.and_then(|o| o.score),
+            report_path: item.outcome.and_then(|o| o.report_path.clone()),
+            closed_on: item.outcome.and_then(|o| o.closed_on),
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut text = format!(
+            "{:<8} 第{}轮 {} {} → {}  {}  问题 {}/{}/{}",
+            &self.record_id[..8.min(self.record_id.len())],
+            self.round,
+            self.target_department_name,
+            self.specialty,
+            self.inspecting_department_name,
+            self.status.display_name(),
+            self.findings.major,
+            self.findings.general,
+            self.findings.minor
+        );
+        if let Some(score) = self.score {
+            text.push_str(&format!("  评分 {:.1}", score));
+        }
+        if let Some(date) = self.closed_on {
+            text.push_str(&format!("  结案 {}", date.format("%Y-%m-%d")));
+        }
+        text
+    }
+}
+
+fn outcome_list(ctx: &Context, year: Option<i32>) -> Result<ExitCode, String> {
+    let records = ctx.store.load_records()?;
+    let outcomes = ctx.store.load_outcomes();
+    let items = match year {
+        Some(year) => OutcomeManager::year_items(&records, &outcomes, year),
+        None => OutcomeManager::open_items(&records, &outcomes),
+    };
+    let output: Vec<OutcomeOutput> = items.iter().map(OutcomeOutput::new).collect();
+    ctx.print(&output, || {
+        let mut lines: Vec<String> = output.iter().map(OutcomeOutput::text).collect();
+        lines.push(match year {
+            Some(year) => format!("{}年共 {} 项检查", year, output.len()),
+            None => format!("未完成检查 {} 项", output.len()),
+        });
+        lines.join("\n")
+    });
+    Ok(ExitCode::SUCCESS)
+}
+
+fn outcome_set(
+    ctx: &Context,
+    key: &str,
+    status: Option<InspectionStatus>,
+    [major, general, minor]: [Option<u32>; 3],
+    score: Option<f32>,
+    report: Option<String>,
+    closed_on: Option<NaiveDate>,
+) -> Result<ExitCode, String> {
+    let operator = ctx.login(Role::can_draw)?;
+    let records = ctx.store.load_records()?;
+    let record = find_record(&records, key)?;
+
+    let mut outcome = ctx.store.load_outcomes()
+        .into_iter()
+        .find(|o| o.record_id == record.id)
+        .unwrap_or_else(|| InspectionOutcome::new(&record.id));
+    if let Some(status) = status {
+        outcome.status = status;
+        // 重新打开的检查清除结案日期，完成时默认今天结案
+        match status {
+            InspectionStatus::Done => {
+                outcome.closed_on = outcome.closed_on.or_else(|| Some(chrono::Local::now().date_naive()));
+            }
+            InspectionStatus::Planned | InspectionStatus::InProgress => outcome.closed_on = None,
+        }
+    }
+    outcome.findings.major = major.unwrap_or(outcome.findings.major);
+    outcome.findings.general = general.unwrap_or(outcome.findings.general);
+    outcome.findings.minor = minor.unwrap_or(outcome.findings.minor);
+    outcome.score = score.or(outcome.score);
+    outcome.report_path = report.or(outcome.report_path);
+    outcome.closed_on = closed_on.or(outcome.closed_on);
+    outcome.validate()?;
+
+    let outcome = outcome.stamped(ChangeStamp::now(&operator));
+    let old = ctx.store.set_outcome(outcome.clone());
+    let mut audit = AuditEntry::new(
+        &operator,
+        AuditAction::UpdateOutcome,
+        format!(
+            "第{}轮 {} {} → {}：{}",
+            record.round,
+            record.target_department_name,
+            record.specialty_label(),
+            record.selected_from_department_name,
+            outcome.status.display_name()
+        ),
+    ).with_new(&outcome);
+    if let Some(old) = &old {
+        audit = audit.with_old(old);
+    }
+    ctx.store.append_audit(&audit);
+
+    let output = OutcomeOutput::new(&OutcomeItem { record, outcome: Some(&outcome) });
+    ctx.print(&output, || format!("已登记 {}", output.text()));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn outcome_export(ctx: &Context, year: Option<i32>, output: &PathBuf) -> Result<ExitCode, String> {
+    let records = ctx.store.load_records()?;
+    let outcomes = ctx.store.load_outcomes();
+    let year = year.unwrap_or_else(|| chrono::Local::now().year());
+    let items = OutcomeManager::year_items(&records, &outcomes, year);
+    if items.is_empty() {
+        return Err(format!("{}年没有有效的抽签记录", year));
+    }
+
+    let summaries = OutcomeManager::summarize(&items);
+    ExportManager::export_annual_report(&items, &summaries, &ctx.organization, year, output)?;
+
+    let total = &summaries[0];
+    ctx.print(
+        &serde_json::json!({ "path": output, "year": year, "total": total.total, "done": total.done }),
+        || format!("已导出{}年度质量报告（共 {} 项，已完成 {} 项）到 {}", year, total.total, total.done, output.display()),
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+/// 解析一行专责数据：姓名,部门名称或ID,专业名称或ID
+fn parse_specialist_row<'a, 'b>(
+    fields: &[&str],
+    departments: &'a [Department],
+    specialties: &'b [Specialty],
+) -> Result<(String, &'a Department, &'b Specialty), String> {
+    let [name, department, specialty] = fields else {
+        return Err("格式应为：姓名,部门,专业".to_string());
+    };
+    if name.is_empty() {
+        return Err("姓名为空".to_string());
+    }
+    let department = departments.iter()
+        .find(|d| d.name == *department || d.id == *department)
+        .ok_or_else(|| format!("未找到部门 {}", department))?;
+    let specialty = find_specialty(specialties, specialty)?;
+    Ok((name.to_string(), department, specialty))
+}
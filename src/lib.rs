@@ -0,0 +1,6 @@
+//! 宁夏特检院质量监督检查抽签程序 - 公共库
+//! 
+//! 图形界面（`quality_draw`）和命令行（`quality_draw-cli`）共用的
+//! 数据模型、数据存储、抽签逻辑、导出功能、多语言文字和运行日志，由 `quality_draw_core` 提供，与 Tauri 版相同。
+
+pub use quality_draw_core::{export, i18n, logging, logic, models, storage};
@@ -1,4 +1,4 @@
-//! 宁夏特检院质量监督检查抽签程序
+//! 质量监督检查抽签程序
 //! 
 //! 功能：
 //! - 支持部门选择和随机抽签
@@ -7,62 +7,58 @@
 //! - 滚动动画效果
 //! - 结果导出到Excel
 //! - 打印功能
+//! - 局域网投屏
+//! - 多单位数据隔离
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod models;
-mod storage;
-mod logic;
 mod ui;
 mod app;
+mod server;
 
+use quality_draw::{export, i18n, logging, logic, models, storage};
 use app::QualityDrawApp;
 use eframe::egui;
-use std::io::Write;
+use logging::{Level, Logger};
 
 fn main() -> eframe::Result<()> {
-    // 设置panic hook，将崩溃信息写入日志文件
-    std::panic::set_hook(Box::new(|panic_info| {
-        let log_path = get_log_path();
-        if let Ok(mut file) = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&log_path) 
-        {
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            let _ = writeln!(file, "[{}] PANIC: {}", timestamp, panic_info);
-        }
-    }));
+    // 初始化运行日志（级别可用 QUALITY_DRAW_LOG 环境变量调整），崩溃信息也写入日志
+    let level = std::env::var("QUALITY_DRAW_LOG")
+        .ok()
+        .and_then(|name| Level::from_name(&name))
+        .unwrap_or(Level::Info);
+    logging::init(Logger::new(get_log_path(), level));
+    logging::install_panic_hook();
     
     // 记录启动日志
-    log_message("程序启动...");
+    logging::info("app", "程序启动", &[("version", &env!("CARGO_PKG_VERSION"))]);
     
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_inner_size([1024.0, 700.0])
             .with_min_inner_size([800.0, 600.0])
-            .with_title("宁夏特检院质量监督检查抽签程序"),
+            .with_title("质量监督检查抽签程序"),
         ..Default::default()
     };
     
-    log_message("创建窗口...");
+    logging::debug("app", "创建窗口", &[]);
     
     let result = eframe::run_native(
         "质量监督检查抽签程序",
         options,
         Box::new(|cc| {
-            log_message("初始化应用...");
+            logging::debug("app", "初始化应用", &[]);
             // 配置中文字体
             setup_fonts(&cc.egui_ctx);
-            log_message("字体配置完成");
+            logging::debug("app", "字体配置完成", &[]);
             Ok(Box::new(QualityDrawApp::new(cc)))
         }),
     );
     
     // 记录结果
     match &result {
-        Ok(_) => log_message("程序正常退出"),
-        Err(e) => log_message(&format!("程序错误: {:?}", e)),
+        Ok(_) => logging::info("app", "程序正常退出", &[]),
+        Err(e) => logging::error("app", "程序错误", &[("error", &e)]),
     }
     
     result
@@ -77,19 +73,6 @@ fn get_log_path() -> std::path::PathBuf {
         .join("quality_draw.log")
 }
 
-/// 记录日志
-fn log_message(msg: &str) {
-    let log_path = get_log_path();
-    if let Ok(mut file) = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_path) 
-    {
-        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-        let _ = writeln!(file, "[{}] {}", timestamp, msg);
-    }
-}
-
 /// 配置中文字体
 fn setup_fonts(ctx: &egui::Context) {
     let mut fonts = egui::FontDefinitions::default();
@@ -113,21 +96,6 @@ fn setup_fonts(ctx: &egui::Context) {
             .insert(0, "microsoft_yahei".to_owned());
     }
     
+    // 字号随显示主题设置（见 ui::apply_theme）
     ctx.set_fonts(fonts);
-    
-    // 设置默认样式
-    let mut style = (*ctx.style()).clone();
-    style.text_styles.insert(
-        egui::TextStyle::Body,
-        egui::FontId::new(14.0, egui::FontFamily::Proportional),
-    );
-    style.text_styles.insert(
-        egui::TextStyle::Button,
-        egui::FontId::new(14.0, egui::FontFamily::Proportional),
-    );
-    style.text_styles.insert(
-        egui::TextStyle::Heading,
-        egui::FontId::new(18.0, egui::FontFamily::Proportional),
-    );
-    ctx.set_style(style);
 }
@@ -0,0 +1,561 @@
+//! 投屏服务 - 内嵌 HTTP 服务器
+//!
+//! 在局域网内提供抽签状态接口和网页查看器，供大厅投影仪或观察员的电脑实时观看转盘：
+//! - `GET /`：网页查看器
+//! - `GET /api/state`：当前轮次、被检部门、转盘状态、本轮结果和待确认结果
+//! - `GET /api/candidates`：各转盘的候选名单
+//! - `GET /api/results`：本轮抽签结果
+//! - `GET /api/events`：服务器推送事件流（`state` 为完整状态，`phase` 为动画阶段变化）
+//! - `POST /api/confirm`：见证人在局域网设备上确认待确认结果（`{"username", "password"}`）
+//!
+//! 除见证确认外所有接口均为只读。见证确认在单独的线程中逐个验证密码，同一设备连续验证失败后
+//! 需等待的时间逐次加倍，通过验证的确认才交给界面线程。
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::logging;
+use crate::logic::AuthManager;
+use crate::models::Operator;
+use crate::storage::DataStore;
+use crate::ui::AnimationPhase;
+
+/// 网页查看器
+const VIEWER_HTML: &str = include_str!("viewer.html");
+
+/// 转盘滚动时推送状态的最小间隔（阶段变化不受限制）
+const ROLLING_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 事件流保活间隔
+const KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// 等待界面处理见证确认的最长时间
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 排队等待验证的见证确认请求上限（超出时直接拒绝）
+const CONFIRM_QUEUE: usize = 8;
+
+/// 见证确认请求体的最大长度
+const MAX_CONFIRM_BODY: u64 = 4096;
+
+/// 同一设备连续验证失败达到此次数后开始限制
+const FREE_ATTEMPTS: u32 = 3;
+
+/// 验证失败后的最长等待时间
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+
+/// 超过此时间没有新的失败时清除该设备的失败记录
+const FAILURE_RESET: Duration = Duration::from_secs(3600);
+
+/// 单个转盘的状态
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WheelSnapshot {
+    /// 专业ID
+    pub specialty: String,
+    /// 专业名称
+    pub specialty_name: String,
+    /// 动画阶段
+    pub phase: AnimationPhase,
+    /// 候选名单（转盘顺序）
+    pub candidates: Vec<String>,
+    /// 当前指向的候选索引
+    pub current_index: usize,
+    /// 最终结果
+    pub result: Option<String>,
+    /// 检查组全部中签部门（只抽一个部门时即为最终结果）
+    pub team: Vec<String>,
+}
+
+/// 本轮的一条抽签结果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RoundResult {
+    /// 被检部门
+    pub target: String,
+    /// 专业名称
+    pub specialty: String,
+    /// 抽中部门
+    pub selected: String,
+}
+
+/// 投屏状态快照
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LiveSnapshot {
+    /// 单位名称
+    pub organization: String,
+    /// 当前轮次
+    pub round: u32,
+    /// 当前选中的被检部门
+    pub target_department: Option<String>,
+    /// 转盘状态
+    pub wheels: Vec<WheelSnapshot>,
+    /// 本轮结果
+    pub results: Vec<RoundResult>,
+    /// 等待见证人确认的结果
+    pub pending: Vec<RoundResult>,
+}
+
+impl LiveSnapshot {
+    /// 除转盘位置外是否相同（仅转盘滚动时可以降低推送频率）
+    fn same_except_position(&self, other: &Self) -> bool {
+        self.organization == other.organization
+            && self.round == other.round
+            && self.target_department == other.target_department
+            && self.results == other.results
+            && self.pending == other.pending
+            && self.wheels.len() == other.wheels.len()
+            && self.wheels.iter().zip(&other.wheels).all(|(a, b)| {
+                a.specialty == b.specialty
+                    && a.phase == b.phase
+                    && a.candidates == b.candidates
+                    && a.result == b.result
+                    && a.team == b.team
+            })
+    }
+}
+
+/// 动画阶段变化事件
+#[derive(Serialize)]
+struct PhaseEvent<'a> {
+    specialty: &'a str,
+    phase: &'a AnimationPhase,
+    result: &'a Option<String>,
+    team: &'a [String],
+}
+
+/// 局域网设备提交并已通过密码验证的见证确认，由界面线程检查后答复
+pub struct ConfirmRequest {
+    /// 通过验证的见证人账户
+    pub witness: Operator,
+    /// 提交确认的设备地址
+    pub remote_addr: Option<String>,
+    reply: Sender<Result<String, String>>,
+}
+
+impl ConfirmRequest {
+    /// 答复请求：成功时为提示信息，失败时为错误原因
+    pub fn reply(self, result: Result<String, String>) {
+        self.reply.send(result).ok();
+    }
+}
+
+/// 见证确认请求体
+#[derive(serde::Deserialize)]
+struct ConfirmBody {
+    username: String,
+    password: String,
+}
+
+/// 服务线程与界面线程共享的状态
+struct Shared {
+    /// 最新快照
+    snapshot: Mutex<LiveSnapshot>,
+    /// 事件流订阅者
+    subscribers: Mutex<Vec<Sender<String>>>,
+    /// 上次推送状态的时间
+    last_sent: Mutex<Option<Instant>>,
+    /// 见证确认请求排队等待验证线程处理
+    confirm_queue: SyncSender<Request>,
+}
+
+impl Shared {
+    /// 向所有订阅者推送事件，断开的订阅者会被移除
+    fn broadcast(&self, event: &str, data: &impl Serialize) {
+        let Ok(data) = serde_json::to_string(data) else {
+            return;
+        };
+        let message = format!("event: {}\ndata: {}\n\n", event, data);
+        self.subscribers.lock().unwrap().retain(|tx| tx.send(message.clone()).is_ok());
+    }
+}
+
+/// 投屏服务
+pub struct LiveServer {
+    server: Arc<Server>,
+    shared: Arc<Shared>,
+    addr: SocketAddr,
+    thread: Option<JoinHandle<()>>,
+    confirm_rx: Receiver<ConfirmRequest>,
+}
+
+impl LiveServer {
+    /// 在指定地址启动服务，见证确认按 `accounts` 单位的账户验证
+    pub fn start(addr: SocketAddr, accounts: DataStore) -> Result<Self, String> {
+        let server = Arc::new(Server::http(addr).map_err(|e| format!("无法在 {} 启动服务: {}", addr, e))?);
+        let addr = server.server_addr().to_ip().unwrap_or(addr);
+        let (confirm_tx, confirm_rx) = mpsc::channel();
+        let (queue_tx, queue_rx) = mpsc::sync_channel(CONFIRM_QUEUE);
+        let shared = Arc::new(Shared {
+            snapshot: Mutex::default(),
+            subscribers: Mutex::default(),
+            last_sent: Mutex::default(),
+            confirm_queue: queue_tx,
+        });
+
+        // 服务停止后排队通道关闭，验证线程随之退出
+        let mut worker = ConfirmWorker { accounts, ui: confirm_tx, failures: HashMap::new() };
+        std::thread::spawn(move || {
+            for request in queue_rx {
+                worker.handle(request);
+            }
+        });
+
+        let thread = {
+            let server = Arc::clone(&server);
+            let shared = Arc::clone(&shared);
+            std::thread::spawn(move || {
+                // unblock() 后 incoming_requests 结束
+                for request in server.incoming_requests() {
+                    handle_request(request, &shared);
+                }
+            })
+        };
+
+        Ok(Self { server, shared, addr, thread: Some(thread), confirm_rx })
+    }
+
+    /// 监听地址
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 查看器访问地址（监听所有网卡时给出本机局域网地址）
+    pub fn viewer_urls(&self) -> Vec<String> {
+        let port = self.addr.port();
+        if self.addr.ip().is_unspecified() {
+            let mut urls = vec![format!("http://127.0.0.1:{}/", port)];
+            if let Some(ip) = lan_ip() {
+                urls.push(format!("http://{}:{}/", ip, port));
+            }
+            urls
+        } else {
+            vec![format!("http://{}/", self.addr)]
+        }
+    }
+
+    /// 当前连接的查看器数量
+    pub fn viewer_count(&self) -> usize {
+        self.shared.subscribers.lock().unwrap().len()
+    }
+
+    /// 取出局域网设备提交并已通过密码验证的见证确认
+    pub fn confirm_requests(&self) -> Vec<ConfirmRequest> {
+        self.confirm_rx.try_iter().collect()
+    }
+
+    /// 发布最新状态，状态变化时推送给查看器
+    pub fn publish(&self, snapshot: LiveSnapshot) {
+        let previous = {
+            let mut current = self.shared.snapshot.lock().unwrap();
+            if *current == snapshot {
+                return;
+            }
+            std::mem::replace(&mut *current, snapshot.clone())
+        };
+
+        // 动画阶段变化
+        for wheel in &snapshot.wheels {
+            let changed = previous.wheels.iter()
+                .find(|w| w.specialty == wheel.specialty)
+                .is_none_or(|w| w.phase != wheel.phase);
+            if changed {
+                self.shared.broadcast("phase", &PhaseEvent {
+                    specialty: &wheel.specialty,
+                    phase: &wheel.phase,
+                    result: &wheel.result,
+                    team: &wheel.team,
+                });
+            }
+        }
+
+        // 只有转盘位置变化时限制推送频率
+        let mut last_sent = self.shared.last_sent.lock().unwrap();
+        let throttled = snapshot.same_except_position(&previous)
+            && last_sent.is_some_and(|t| t.elapsed() < ROLLING_INTERVAL);
+        if !throttled {
+            *last_sent = Some(Instant::now());
+            self.shared.broadcast("state", &snapshot);
+        }
+    }
+}
+
+impl Drop for LiveServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+        // 关闭事件流，查看器线程随之退出
+        self.shared.subscribers.lock().unwrap().clear();
+    }
+}
+
+/// 处理一个请求
+fn handle_request(request: Request, shared: &Arc<Shared>) {
+    let path = request.url().split('?').next().unwrap_or("/").to_string();
+    if *request.method() == Method::Post && path == "/api/confirm" {
+        if let Err(TrySendError::Full(request) | TrySendError::Disconnected(request)) = shared.confirm_queue.try_send(request) {
+            let value = serde_json::json!({ "error": "确认请求过多，请稍后再试" });
+            request.respond(json_response(&value).with_status_code(503)).ok();
+        }
+        return;
+    }
+    if *request.method() != Method::Get {
+        request.respond(Response::from_string("只读服务，仅支持 GET 请求").with_status_code(405)).ok();
+        return;
+    }
+
+    let snapshot = shared.snapshot.lock().unwrap().clone();
+    let response = match path.as_str() {
+        "/" | "/index.html" => Response::from_string(VIEWER_HTML)
+            .with_header(header("Content-Type", "text/html; charset=utf-8")),
+        "/api/state" => json_response(&snapshot),
+        "/api/candidates" => {
+            #[derive(Serialize)]
+            struct Candidates<'a> {
+                specialty: &'a str,
+                candidates: &'a [String],
+            }
+            let candidates: Vec<Candidates> = snapshot.wheels.iter()
+                .map(|w| Candidates { specialty: &w.specialty, candidates: &w.candidates })
+                .collect();
+            json_response(&candidates)
+        }
+        "/api/results" => json_response(&snapshot.results),
+        "/api/events" => {
+            subscribe(request, shared, &snapshot);
+            return;
+        }
+        _ => Response::from_string("未找到").with_status_code(404),
+    };
+    request.respond(response.with_header(header("Access-Control-Allow-Origin", "*"))).ok();
+}
+
+/// 见证确认验证线程
+struct ConfirmWorker {
+    /// 账户数据（每次验证时重新读取，账户修改立即生效）
+    accounts: DataStore,
+    /// 通过验证的确认发往界面线程
+    ui: Sender<ConfirmRequest>,
+    /// 各设备连续验证失败的次数和最近一次失败的时间
+    failures: HashMap<IpAddr, (u32, Instant)>,
+}
+
+impl ConfirmWorker {
+    /// 处理一个见证确认请求并答复
+    fn handle(&mut self, mut request: Request) {
+        let ip = request.remote_addr().map(|addr| addr.ip());
+        let (status, value) = match self.confirm(&mut request, ip) {
+            Ok(message) => (200, serde_json::json!({ "message": message })),
+            Err((status, error)) => (status, serde_json::json!({ "error": error })),
+        };
+        request.respond(json_response(&value).with_status_code(status)).ok();
+    }
+
+    /// 验证见证人密码，通过后转交界面线程并等待答复；失败时返回状态码和原因
+    fn confirm(&mut self, request: &mut Request, ip: Option<IpAddr>) -> Result<String, (u16, String)> {
+        self.failures.retain(|_, (_, last)| last.elapsed() < FAILURE_RESET);
+        if let Some(wait) = ip.and_then(|ip| self.backoff(ip)) {
+            return Err((429, format!("验证失败次数过多，请 {} 秒后再试", wait.as_secs().max(1))));
+        }
+
+        let mut body = String::new();
+        request.as_reader().take(MAX_CONFIRM_BODY).read_to_string(&mut body).map_err(|e| (400, e.to_string()))?;
+        let body: ConfirmBody = serde_json::from_str(&body).map_err(|_| (400, "请求格式错误".to_string()))?;
+        let operators = self.accounts.load_operators().map_err(|e| (500, e))?;
+        let witness = match AuthManager::authenticate(&operators, &body.username, &body.password) {
+            Ok(witness) => witness,
+            Err(e) => {
+                if let Some(ip) = ip {
+                    let (count, last) = self.failures.entry(ip).or_insert((0, Instant::now()));
+                    *count += 1;
+                    *last = Instant::now();
+                }
+                logging::warn("server", "局域网见证确认验证失败", &[
+                    ("remote", &ip.map(|ip| ip.to_string()).unwrap_or_default()),
+                    ("username", &body.username),
+                ]);
+                return Err((400, e));
+            }
+        };
+        if let Some(ip) = ip {
+            self.failures.remove(&ip);
+        }
+
+        let (reply, answer) = mpsc::channel();
+        let confirm_request = ConfirmRequest {
+            witness,
+            remote_addr: ip.map(|ip| ip.to_string()),
+            reply,
+        };
+        let unavailable = || (503, "抽签程序未响应".to_string());
+        self.ui.send(confirm_request).map_err(|_| unavailable())?;
+        answer.recv_timeout(CONFIRM_TIMEOUT).map_err(|_| unavailable())?.map_err(|e| (400, e))
+    }
+
+    /// 设备还需等待的时间：超过免限制次数后，每多失败一次等待时间加倍
+    fn backoff(&self, ip: IpAddr) -> Option<Duration> {
+        let (count, last) = self.failures.get(&ip)?;
+        let extra = count.checked_sub(FREE_ATTEMPTS)?;
+        let wait = Duration::from_secs(1 << extra.min(9)).min(MAX_BACKOFF);
+        wait.checked_sub(last.elapsed()).filter(|wait| !wait.is_zero())
+    }
+}
+
+/// 建立事件流连接，先发送一次完整状态
+fn subscribe(request: Request, shared: &Arc<Shared>, snapshot: &LiveSnapshot) {
+    let (tx, rx) = mpsc::channel();
+    if let Ok(data) = serde_json::to_string(snapshot) {
+        tx.send(format!("event: state\ndata: {}\n\n", data)).ok();
+    }
+    shared.subscribers.lock().unwrap().push(tx);
+
+    let writer = request.into_writer();
+    std::thread::spawn(move || stream_events(writer, rx));
+}
+
+/// 向查看器持续写入事件，连接断开或服务停止时退出
+fn stream_events(mut writer: Box<dyn Write + Send>, rx: Receiver<String>) {
+    let head = "HTTP/1.1 200 OK\r\n\
+                Content-Type: text/event-stream; charset=utf-8\r\n\
+                Cache-Control: no-cache\r\n\
+                Connection: keep-alive\r\n\
+                Access-Control-Allow-Origin: *\r\n\r\n";
+    if writer.write_all(head.as_bytes()).and_then(|_| writer.flush()).is_err() {
+        return;
+    }
+
+    loop {
+        let message = match rx.recv_timeout(KEEP_ALIVE) {
+            Ok(message) => message,
+            Err(RecvTimeoutError::Timeout) => ": keep-alive\n\n".to_string(),
+            Err(RecvTimeoutError::Disconnected) => return,
+        };
+        if writer.write_all(message.as_bytes()).and_then(|_| writer.flush()).is_err() {
+            return;
+        }
+    }
+}
+
+fn json_response(value: &impl Serialize) -> Response<std::io::Cursor<Vec<u8>>> {
+    Response::from_string(serde_json::to_string(value).unwrap_or_default())
+        .with_header(header("Content-Type", "application/json; charset=utf-8"))
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("响应头均为合法 ASCII")
+}
+
+/// 本机局域网地址（通过 UDP 路由查询，不会实际发送数据）
+fn lan_ip() -> Option<std::net::IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpStream;
+
+    fn get(addr: SocketAddr, path: &str) -> TcpStream {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", path).unwrap();
+        stream
+    }
+
+    #[test]
+    fn test_state_and_events() {
+        let data_dir = std::env::temp_dir().join(format!("quality_draw_server_{}", uuid::Uuid::new_v4()));
+        let server = LiveServer::start("127.0.0.1:0".parse().unwrap(), DataStore::with_dir(&data_dir)).unwrap();
+        server.publish(LiveSnapshot {
+            round: 3,
+            target_department: Some("宁东分院".to_string()),
+            ..LiveSnapshot::default()
+        });
+
+        let mut body = String::new();
+        get(server.addr(), "/api/state").read_to_string(&mut body).unwrap();
+        assert!(body.contains("\"round\":3"));
+        assert!(body.contains("宁东分院"));
+
+        // 事件流连接后立即收到当前状态，之后推送新状态
+        let mut events = BufReader::new(get(server.addr(), "/api/events"));
+        let mut lines = Vec::new();
+        while !lines.iter().any(|l: &String| l.starts_with("data:")) {
+            let mut line = String::new();
+            events.read_line(&mut line).unwrap();
+            lines.push(line);
+        }
+        assert!(lines.iter().any(|l| l.contains("text/event-stream")));
+        assert!(lines.iter().any(|l| l.starts_with("data:") && l.contains("\"round\":3")));
+
+        server.publish(LiveSnapshot { round: 4, ..LiveSnapshot::default() });
+        let mut line = String::new();
+        while !line.starts_with("data:") {
+            line.clear();
+            events.read_line(&mut line).unwrap();
+        }
+        assert!(line.contains("\"round\":4"));
+        std::fs::remove_dir_all(&data_dir).ok();
+    }
+
+    fn post_confirm(addr: SocketAddr, password: &str) -> String {
+        let body = format!(r#"{{"username":"guest","password":"{}"}}"#, password);
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /api/confirm HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_confirm_request() {
+        let data_dir = std::env::temp_dir().join(format!("quality_draw_server_{}", uuid::Uuid::new_v4()));
+        let store = DataStore::with_dir(&data_dir);
+        let guest = AuthManager::create_operator(&[], "guest", "见证人", crate::models::Role::Observer, "secret1").unwrap();
+        store.save_operators(&[guest]);
+
+        let server = LiveServer::start("127.0.0.1:0".parse().unwrap(), store).unwrap();
+        let addr = server.addr();
+        let client = std::thread::spawn(move || post_confirm(addr, "secret1"));
+
+        // 密码在服务线程验证，界面线程只收到通过验证的见证人
+        let request = loop {
+            if let Some(request) = server.confirm_requests().pop() {
+                break request;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        assert_eq!(request.witness.username, "guest");
+        assert_eq!(request.remote_addr.as_deref(), Some("127.0.0.1"));
+        request.reply(Err("当前没有待确认的结果".to_string()));
+
+        let response = client.join().unwrap();
+        assert!(response.starts_with("HTTP/1.1 400"));
+        assert!(response.contains("当前没有待确认的结果"));
+
+        // 密码错误不会转交界面线程，连续失败后暂时拒绝该设备（即使密码正确）
+        for _ in 0..FREE_ATTEMPTS {
+            let response = post_confirm(addr, "wrong");
+            assert!(response.starts_with("HTTP/1.1 400"));
+        }
+        assert!(server.confirm_requests().is_empty());
+        assert!(post_confirm(addr, "secret1").starts_with("HTTP/1.1 429"));
+
+        std::fs::remove_dir_all(&data_dir).ok();
+    }
+}
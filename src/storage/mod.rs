@@ -1,5 +0,0 @@
-//! 数据存储模块
-
-mod json_store;
-
-pub use json_store::DataStore;
@@ -1,10 +1,11 @@
 //! 滚动动画状态管理 - 转盘效果
 
 use std::time::{Duration, Instant};
-use rand::seq::SliceRandom;
+use serde::Serialize;
+use crate::models::{AnimationSettings, DrawReplay, Easing};
 
 /// 动画状态
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum AnimationPhase {
     /// 空闲
     Idle,
@@ -27,16 +28,30 @@ pub struct AnimationState {
     pub scroll_position: f32,
     /// 滚动速度（每秒滚动的项目数）
     pub scroll_speed: f32,
-    /// 上次更新时间
-    last_update: Instant,
     /// 最终结果
     pub final_result: Option<String>,
     /// 开始减速的时间
     pub slowdown_start: Option<Instant>,
     /// 减速持续时间
     pub slowdown_duration: Duration,
+    /// 减速曲线
+    easing: Easing,
+    /// 开始滚动的时间
+    rolling_start: Instant,
+    /// 自动停止前的滚动时间（手动停止时为 None）
+    auto_stop_after: Option<Duration>,
+    /// 实际滚动时间（进入减速时记录，用于保存回放）
+    rolled: Option<Duration>,
     /// 初始滚动速度
     initial_speed: f32,
+    /// 由抽签引擎决定的中签索引
+    target_index: usize,
+    /// 检查组人数（从中签位置起连续的几个部门同时中签）
+    pub team_size: usize,
+    /// 开始减速时的位置
+    slowdown_from: f32,
+    /// 减速阶段需要滚过的距离（项目数）
+    slowdown_distance: f32,
 }
 
 impl Default for AnimationState {
@@ -46,53 +61,123 @@ impl Default for AnimationState {
             candidates: Vec::new(),
             scroll_position: 0.0,
             scroll_speed: 20.0,  // 每秒滚动20个名字
-            last_update: Instant::now(),
             final_result: None,
             slowdown_start: None,
             slowdown_duration: Duration::from_millis(3000), // 减速持续3秒
+            easing: Easing::default(),
+            rolling_start: Instant::now(),
+            auto_stop_after: None,
+            rolled: None,
             initial_speed: 20.0,
+            target_index: 0,
+            team_size: 1,
+            slowdown_from: 0.0,
+            slowdown_distance: 0.0,
         }
     }
 }
 
 impl AnimationState {
     /// 开始滚动动画
-    pub fn start(&mut self, candidates: Vec<String>) {
+    /// 
+    /// `candidates` 为抽签引擎打乱后的名单，`final_index` 为引擎决定的中签位置，
+    /// `team_size` 为从该位置起连续中签的部门数。动画只负责展示，停止时一定落在该位置；
+    /// `settings` 决定转速、减速时长和曲线，开启自动停止时到时自动进入减速
+    pub fn start(&mut self, candidates: Vec<String>, final_index: usize, team_size: usize, settings: &AnimationSettings) {
         if candidates.is_empty() {
             return;
         }
         
-        // 打乱顺序
-        let mut shuffled = candidates;
-        let mut rng = rand::thread_rng();
-        shuffled.shuffle(&mut rng);
-        
         // 根据候选人数量动态调整速度
         // 目标：无论人数多少，转盘都流畅
         // 速度 = 候选人数量 * 每秒圈数
-        let candidate_count = shuffled.len() as f32;
-        let rotations_per_second = 3.0; // 每秒转3圈
-        let calculated_speed = candidate_count * rotations_per_second;
+        let candidate_count = candidates.len() as f32;
+        let calculated_speed = candidate_count * settings.profile.rotations_per_second();
         
-        // 限制最低速度为30，最高速度为80（确保流畅）
-        let speed = calculated_speed.clamp(30.0, 80.0);
+        // 限制在动画方案的转速范围内（确保流畅）
+        let (min_speed, max_speed) = settings.profile.speed_range();
+        let speed = calculated_speed.clamp(min_speed, max_speed);
         
-        self.candidates = shuffled;
+        self.target_index = final_index.min(candidates.len() - 1);
+        self.team_size = team_size.clamp(1, candidates.len());
+        self.candidates = candidates;
         self.phase = AnimationPhase::Rolling;
         self.scroll_position = 0.0;
         self.scroll_speed = speed;
         self.initial_speed = speed;
-        self.last_update = Instant::now();
         self.final_result = None;
         self.slowdown_start = None;
+        self.slowdown_duration = settings.profile.slowdown();
+        self.easing = settings.easing;
+        self.rolling_start = Instant::now();
+        // 随机浮动只影响停止时刻，不影响中签位置
+        self.auto_stop_after = settings.auto_stop_after(rand::random());
+        self.rolled = None;
+    }
+    
+    /// 按保存的回放数据重新播放一次转盘
+    /// 
+    /// 滚动位置只由滚动时间决定，按原来的滚动时间进入减速即可得到与抽签时相同的画面
+    pub fn start_replay(&mut self, replay: &DrawReplay) {
+        if replay.order.is_empty() {
+            return;
+        }
+        self.target_index = replay.final_index.min(replay.order.len() - 1);
+        self.team_size = replay.team_size.clamp(1, replay.order.len());
+        self.candidates = replay.order.clone();
+        self.phase = AnimationPhase::Rolling;
+        self.scroll_position = 0.0;
+        self.scroll_speed = replay.speed;
+        self.initial_speed = replay.speed;
+        self.final_result = None;
+        self.slowdown_start = None;
+        self.slowdown_duration = Duration::from_millis(replay.slowdown_ms);
+        self.easing = replay.easing;
+        self.rolling_start = Instant::now();
+        self.auto_stop_after = Some(Duration::from_millis(replay.rolling_ms));
+        self.rolled = None;
+    }
+    
+    /// 停止后的回放数据（未完整转完一次时为 None）
+    pub fn replay(&self) -> Option<DrawReplay> {
+        if self.phase != AnimationPhase::Stopped {
+            return None;
+        }
+        let rolled = self.rolled?;
+        Some(DrawReplay {
+            order: self.candidates.clone(),
+            final_index: self.target_index,
+            team_size: self.team_size,
+            speed: self.initial_speed,
+            rolling_ms: rolled.as_millis() as u64,
+            slowdown_ms: self.slowdown_duration.as_millis() as u64,
+            easing: self.easing,
+        })
     }
     
     /// 请求停止（进入减速阶段）
     pub fn request_stop(&mut self) {
+        self.stop_at(self.rolling_start.elapsed());
+    }
+    
+    /// 在滚动 `rolled` 时间的位置进入减速
+    fn stop_at(&mut self, rolled: Duration) {
         if self.phase == AnimationPhase::Rolling {
+            // 回放按毫秒保存，这里同样取整到毫秒，保证回放与原画面一致
+            let rolled = Duration::from_millis(rolled.as_millis() as u64);
+            let len = self.candidates.len() as f32;
             self.phase = AnimationPhase::SlowingDown;
-            self.slowdown_start = Some(Instant::now());
-            self.initial_speed = self.scroll_speed;
+            self.rolled = Some(rolled);
+            self.slowdown_start = Some(self.rolling_start + rolled);
+            self.scroll_position = (self.initial_speed * rolled.as_secs_f32()).rem_euclid(len);
+            
+            // 计算减速阶段需要滚过的距离，使转盘恰好停在中签位置
+            // 缓出曲线的初速度为 n * 距离 / 时长，据此反推需要多转的圈数
+            let offset = (self.target_index as f32 - self.scroll_position).rem_euclid(len);
+            let desired = self.initial_speed * self.slowdown_duration.as_secs_f32() / self.easing.velocity(0.0);
+            let laps = ((desired - offset) / len).round().max(1.0);
+            self.slowdown_from = self.scroll_position;
+            self.slowdown_distance = offset + laps * len;
         }
     }
     
@@ -102,18 +187,17 @@ impl AnimationState {
             return false;
         }
         
-        let delta_time = self.last_update.elapsed().as_secs_f32();
-        self.last_update = Instant::now();
-        
         match self.phase {
             AnimationPhase::Idle | AnimationPhase::Stopped => false,
             
             AnimationPhase::Rolling => {
-                // 持续滚动
-                self.scroll_position += self.scroll_speed * delta_time;
-                // 循环
-                while self.scroll_position >= self.candidates.len() as f32 {
-                    self.scroll_position -= self.candidates.len() as f32;
+                // 匀速滚动，位置只由滚动时间决定（循环）
+                let elapsed = self.rolling_start.elapsed();
+                let len = self.candidates.len() as f32;
+                self.scroll_position = (self.scroll_speed * elapsed.as_secs_f32()).rem_euclid(len);
+                // 自动停止（按设定时间进入减速，而不是按检测到的时刻）
+                if let Some(after) = self.auto_stop_after.filter(|after| elapsed >= *after) {
+                    self.stop_at(after);
                 }
                 true
             }
@@ -123,32 +207,27 @@ impl AnimationState {
                     let elapsed = start.elapsed();
                     
                     if elapsed >= self.slowdown_duration {
-                        // 动画结束，对齐到最近的整数索引
-                        let final_index = self.scroll_position.round() as usize % self.candidates.len();
-                        self.scroll_position = final_index as f32;
+                        // 动画结束，停在抽签引擎决定的位置
+                        self.scroll_position = self.target_index as f32;
                         self.phase = AnimationPhase::Stopped;
-                        self.final_result = self.candidates.get(final_index).cloned();
+                        self.final_result = self.candidates.get(self.target_index).cloned();
                         self.scroll_speed = 0.0;
                         return true;
                     }
                     
                     // 计算减速进度 (0.0 - 1.0)
-                    let progress = elapsed.as_secs_f32() / self.slowdown_duration.as_secs_f32();
+                    let duration = self.slowdown_duration.as_secs_f32();
+                    let progress = elapsed.as_secs_f32() / duration;
                     
                     // 使用缓出函数，使减速更加平滑自然
-                    // easeOutCubic: 1 - (1 - progress)^3
-                    let ease_progress = 1.0 - (1.0 - progress).powi(3);
+                    let ease_progress = self.easing.position(progress);
                     
-                    // 速度从初始速度渐变到接近0
-                    self.scroll_speed = self.initial_speed * (1.0 - ease_progress);
+                    // 速度为位置曲线的导数，从初始速度渐变到0
+                    self.scroll_speed = self.slowdown_distance * self.easing.velocity(progress) / duration;
                     
-                    // 更新位置
-                    self.scroll_position += self.scroll_speed * delta_time;
-                    
-                    // 循环
-                    while self.scroll_position >= self.candidates.len() as f32 {
-                        self.scroll_position -= self.candidates.len() as f32;
-                    }
+                    // 更新位置（循环）
+                    let len = self.candidates.len() as f32;
+                    self.scroll_position = (self.slowdown_from + self.slowdown_distance * ease_progress).rem_euclid(len);
                     
                     true
                 } else {
@@ -166,6 +245,17 @@ impl AnimationState {
         (self.scroll_position.round() as usize) % self.candidates.len()
     }
     
+    /// 停止后的全部中签名单（检查组按中签顺序排列）
+    pub fn final_team(&self) -> Vec<String> {
+        if self.phase != AnimationPhase::Stopped || self.candidates.is_empty() {
+            return Vec::new();
+        }
+        let len = self.candidates.len();
+        (0..self.team_size)
+            .map(|offset| self.candidates[(self.target_index + offset) % len].clone())
+            .collect()
+    }
+    
     /// 获取当前显示的名字
     pub fn current_name(&self) -> Option<&str> {
         self.candidates.get(self.current_index()).map(|s| s.as_str())
@@ -0,0 +1,119 @@
+//! 转盘动画设置对话框
+
+use eframe::egui;
+use crate::i18n::tr;
+use crate::models::{AnimationProfile, AnimationSettings, Easing};
+use super::sound::{self, SoundCue};
+
+/// 转盘动画设置对话框
+#[derive(Default)]
+pub struct AnimationDialog {
+    /// 是否显示
+    pub visible: bool,
+    /// 编辑中的设置
+    settings: AnimationSettings,
+}
+
+impl AnimationDialog {
+    /// 以当前设置打开对话框
+    pub fn open(&mut self, current: AnimationSettings) {
+        self.settings = current;
+        self.visible = true;
+    }
+
+    /// 显示对话框，点击"保存"时返回新的设置
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<AnimationSettings> {
+        if !self.visible {
+            return None;
+        }
+
+        let mut open = self.visible;
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new(tr("🎞 动画设置"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                let settings = &mut self.settings;
+                egui::Grid::new("animation_settings_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(tr("动画方案:"));
+                        egui::ComboBox::from_id_salt("animation_profile")
+                            .selected_text(settings.profile.display_name())
+                            .show_ui(ui, |ui| {
+                                for profile in AnimationProfile::all() {
+                                    ui.selectable_value(&mut settings.profile, profile, profile.display_name());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(tr("减速曲线:"));
+                        egui::ComboBox::from_id_salt("animation_easing")
+                            .selected_text(settings.easing.display_name())
+                            .show_ui(ui, |ui| {
+                                for easing in Easing::all() {
+                                    ui.selectable_value(&mut settings.easing, easing, easing.display_name());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(tr("自动停止:"));
+                        ui.checkbox(&mut settings.auto_stop, tr("不需要点击停止"));
+                        ui.end_row();
+
+                        ui.label(tr("转动时间:"));
+                        ui.add_enabled_ui(settings.auto_stop, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.add(egui::DragValue::new(&mut settings.auto_stop_secs)
+                                    .range(AnimationSettings::AUTO_STOP_RANGE)
+                                    .speed(0.1)
+                                    .suffix(tr(" 秒")));
+                                ui.label("±");
+                                ui.add(egui::DragValue::new(&mut settings.jitter_secs)
+                                    .range(AnimationSettings::JITTER_RANGE)
+                                    .speed(0.1)
+                                    .suffix(tr(" 秒")));
+                            });
+                        });
+                        ui.end_row();
+
+                        ui.label(tr("提示音:"));
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut settings.sound, tr("开始、减速和出结果时播放"));
+                            if ui.small_button(tr("🔊 试听")).clicked() {
+                                sound::play(SoundCue::Result);
+                            }
+                        });
+                        ui.end_row();
+                    });
+
+                ui.add_space(6.0);
+                ui.label(egui::RichText::new(tr("动画只影响展示，中签结果仍由抽签引擎按随机种子决定"))
+                    .small()
+                    .color(egui::Color32::GRAY));
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button(tr("💾 保存")).clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button(tr("取消")).clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+
+        self.visible = open && !confirmed && !cancelled;
+
+        if confirmed {
+            Some(self.settings)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! 审计日志面板
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::models::{AuditAction, AuditEntry};
+use crate::storage::DataStore;
+use crate::export::ExportManager;
+
+/// 审计日志面板
+#[derive(Default)]
+pub struct AuditPanel {
+    /// 是否显示
+    pub visible: bool,
+    /// 已加载的日志（按时间先后排列）
+    entries: Vec<AuditEntry>,
+    /// 操作类型筛选
+    filter_action: Option<AuditAction>,
+    /// 搜索文本（操作员、操作对象、修改前后的值）
+    search: String,
+    /// 正在展开查看的条目ID
+    expanded_id: Option<String>,
+    /// 提示消息
+    message: Option<String>,
+}
+
+impl AuditPanel {
+    /// 打开面板并重新加载日志
+    pub fn open(&mut self, store: &DataStore) {
+        self.entries = store.load_audit();
+        self.message = None;
+        self.visible = true;
+    }
+
+    /// 显示审计日志面板
+    pub fn show(&mut self, ctx: &egui::Context, store: &DataStore) {
+        if !self.visible {
+            return;
+        }
+
+        let mut open = self.visible;
+
+        egui::Window::new(tr("🧾 审计日志"))
+            .open(&mut open)
+            .default_width(820.0)
+            .default_height(520.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("🔍 搜索:"));
+                    ui.text_edit_singleline(&mut self.search);
+
+                    egui::ComboBox::from_id_salt("audit_filter_action")
+                        .selected_text(self.filter_action.map(|a| a.display_name()).unwrap_or(tr("所有操作")))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.filter_action, None, tr("所有操作"));
+                            for action in AuditAction::all() {
+                                ui.selectable_value(&mut self.filter_action, Some(action), action.display_name());
+                            }
+                        });
+
+                    if ui.button(tr("🔄 刷新")).clicked() {
+                        self.entries = store.load_audit();
+                    }
+                });
+
+                let filtered: Vec<&AuditEntry> = self.entries.iter()
+                    .rev()
+                    .filter(|e| self.filter_action.is_none_or(|a| e.action == a))
+                    .filter(|e| self.search.is_empty() || Self::matches_search(e, &self.search))
+                    .collect();
+
+                ui.horizontal(|ui| {
+                    ui.label(trf("显示 {} / 共 {} 条", &[&filtered.len(), &self.entries.len()]));
+                    if ui.add_enabled(!filtered.is_empty(), egui::Button::new(tr("📊 导出Excel"))).clicked() {
+                        // 导出顺序与显示一致（最新在前）
+                        let entries: Vec<AuditEntry> = filtered.iter().map(|e| (*e).clone()).collect();
+                        self.message = Some(Self::export(&entries));
+                    }
+                    if let Some(msg) = &self.message {
+                        ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+                    }
+                });
+
+                ui.separator();
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    egui::Grid::new("audit_grid")
+                        .num_columns(4)
+                        .striped(true)
+                        .spacing([16.0, 6.0])
+                        .show(ui, |ui| {
+                            ui.strong(tr("时间"));
+                            ui.strong(tr("操作员"));
+                            ui.strong(tr("操作"));
+                            ui.strong(tr("操作对象"));
+                            ui.end_row();
+
+                            for entry in &filtered {
+                                ui.label(entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string());
+                                ui.label(&entry.operator_name);
+                                ui.label(entry.action.display_name());
+                                let has_values = entry.old_value.is_some() || entry.new_value.is_some();
+                                if has_values {
+                                    if ui.link(&entry.target).clicked() {
+                                        self.expanded_id = if self.expanded_id.as_ref() == Some(&entry.id) {
+                                            None
+                                        } else {
+                                            Some(entry.id.clone())
+                                        };
+                                    }
+                                } else {
+                                    ui.label(&entry.target);
+                                }
+                                ui.end_row();
+
+                                if self.expanded_id.as_ref() == Some(&entry.id) {
+                                    for (label, value) in [(tr("修改前"), &entry.old_value), (tr("修改后"), &entry.new_value)] {
+                                        if let Some(value) = value {
+                                            ui.label("");
+                                            ui.label("");
+                                            ui.label(egui::RichText::new(label).color(egui::Color32::GRAY));
+                                            ui.label(egui::RichText::new(value).monospace().small());
+                                            ui.end_row();
+                                        }
+                                    }
+                                }
+                            }
+                        });
+                });
+            });
+
+        self.visible = open;
+    }
+
+    /// 搜索匹配（操作员、操作对象、修改前后的值）
+    fn matches_search(entry: &AuditEntry, search: &str) -> bool {
+        entry.operator_name.contains(search)
+            || entry.target.contains(search)
+            || entry.old_value.as_deref().is_some_and(|v| v.contains(search))
+            || entry.new_value.as_deref().is_some_and(|v| v.contains(search))
+    }
+
+    /// 导出到桌面，返回提示消息
+    fn export(entries: &[AuditEntry]) -> String {
+        let filename = trf(
+            "审计日志_{}.xlsx",
+            &[&chrono::Local::now().format("%Y%m%d_%H%M%S")],
+        );
+        let desktop = dirs::desktop_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
+        let path = desktop.join(&filename);
+
+        match ExportManager::export_audit_to_excel(entries, &path) {
+            Ok(_) => trf("已导出到: {}", &[&path.display()]),
+            Err(e) => trf("导出失败: {}", &[&e]),
+        }
+    }
+}
@@ -1,89 +1,575 @@
 //! 历史记录面板
 
 use eframe::egui;
-use crate::models::DrawRecord;
+use crate::i18n::{tr, trf};
+use chrono::NaiveDate;
+use crate::logic::{query_records, RecordFilter, SortColumn};
+use crate::models::{AuditAction, AuditEntry, DrawRecord, Operator};
 use crate::storage::DataStore;
 
+/// 每页显示的记录数
+const PAGE_SIZE: usize = 50;
+
+/// 历史记录面板中需要主界面处理的操作
+#[derive(Debug, Clone)]
+pub enum HistoryAction {
+    /// 记录已作废（需释放本轮对应的抽签位置）
+    Voided(Box<DrawRecord>),
+    /// 重新抽取作废记录对应的位置
+    Redraw {
+        target_department_id: String,
+        /// 专业名称
+        specialty: String,
+    },
+    /// 回放该记录的转盘动画
+    Replay(Box<DrawRecord>),
+}
+
 /// 历史记录面板
 pub struct HistoryPanel {
     /// 是否显示
     pub visible: bool,
+
+    // --- 筛选状态 ---
+    /// 起始日期输入（YYYY-MM-DD）
+    date_from_text: String,
+    /// 截止日期输入（YYYY-MM-DD）
+    date_to_text: String,
+    /// 筛选条件
+    filter: RecordFilter,
+
+    // --- 排序和分页 ---
+    /// 排序列
+    sort_column: SortColumn,
+    /// 是否升序
+    sort_ascending: bool,
+    /// 当前页（从0开始）
+    page: usize,
+
+    /// 正在查看详情的记录ID
+    detail_record_id: Option<String>,
+    /// 作废原因输入
+    void_reason: String,
+    /// 作废失败提示
+    void_error: Option<String>,
+    /// 待归档的轮次
+    archive_round: Option<u32>,
+    /// 是否显示归档确认
+    confirm_archive: bool,
+    /// 归档失败提示
+    archive_error: Option<String>,
 }
 
 impl Default for HistoryPanel {
     fn default() -> Self {
-        Self { visible: false }
+        Self {
+            visible: false,
+            date_from_text: String::new(),
+            date_to_text: String::new(),
+            filter: RecordFilter::default(),
+            sort_column: SortColumn::Time,
+            sort_ascending: false,
+            page: 0,
+            detail_record_id: None,
+            void_reason: String::new(),
+            void_error: None,
+            archive_round: None,
+            confirm_archive: false,
+            archive_error: None,
+        }
     }
 }
 
 impl HistoryPanel {
     /// 显示历史记录面板
+    /// 
+    /// `current_round` 为主界面当前轮次，只有本轮作废的记录可以重新抽取；
+    /// 作废和归档需要管理员权限，重新抽取需要抽签权限
     pub fn show(
         &mut self,
         ctx: &egui::Context,
         records: &mut Vec<DrawRecord>,
         store: &DataStore,
-    ) {
+        current_round: u32,
+        operator: &Operator,
+    ) -> Option<HistoryAction> {
         if !self.visible {
-            return;
+            return None;
         }
-        
-        egui::Window::new("📜 抽签历史记录")
-            .open(&mut self.visible)
-            .default_width(700.0)
-            .default_height(500.0)
+
+        let mut open = self.visible;
+
+        egui::Window::new(tr("📜 抽签历史记录"))
+            .open(&mut open)
+            .default_width(860.0)
+            .default_height(560.0)
             .resizable(true)
             .show(ctx, |ui| {
                 ui.horizontal(|ui| {
-                    ui.heading(format!("共 {} 条记录", records.len()));
-                    
+                    ui.heading(trf("共 {} 条记录", &[&records.len()]));
+
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        if ui.button("🗑 清空记录").clicked() {
-                            records.clear();
-                            store.clear_records();
+                        if operator.role.can_manage() {
+                            self.show_archive_controls(ui, records, store, operator);
+                        } else {
+                            ui.checkbox(&mut self.filter.include_archived, tr("显示已归档"));
                         }
                     });
                 });
-                
+
                 ui.separator();
-                
+
                 if records.is_empty() {
                     ui.centered_and_justified(|ui| {
-                        ui.label("暂无抽签记录");
+                        ui.label(tr("暂无抽签记录"));
                     });
                     return;
                 }
-                
+
+                self.show_filters(ui, records);
+
+                ui.separator();
+
+                let filtered = query_records(records, &self.filter, self.sort_column, self.sort_ascending);
+                let page_count = filtered.len().div_ceil(PAGE_SIZE).max(1);
+                self.page = self.page.min(page_count - 1);
+
+                ui.label(trf("筛选结果 {} / 共 {} 条", &[&filtered.len(), &records.len()]));
+
                 egui::ScrollArea::vertical()
-                    .max_height(420.0)
+                    .max_height(360.0)
                     .show(ui, |ui| {
                         egui::Grid::new("history_grid")
-                            .num_columns(5)
+                            .num_columns(7)
                             .spacing([20.0, 8.0])
                             .striped(true)
-                            .min_col_width(60.0)
+                            .min_col_width(40.0)
                             .show(ui, |ui| {
-                                // 表头
-                                ui.label(egui::RichText::new("时间").strong().size(14.0));
-                                ui.label(egui::RichText::new("被检部门").strong().size(14.0));
-                                ui.label(egui::RichText::new("专责类型").strong().size(14.0));
-                                ui.label(egui::RichText::new("抽中人员").strong().size(14.0));
-                                ui.label(egui::RichText::new("所属部门").strong().size(14.0));
+                                // 表头（点击排序）
+                                self.sort_header(ui, tr("轮次"), SortColumn::Round);
+                                self.sort_header(ui, tr("时间"), SortColumn::Time);
+                                self.sort_header(ui, tr("被检部门"), SortColumn::Target);
+                                self.sort_header(ui, tr("专业"), SortColumn::Specialty);
+                                ui.label(egui::RichText::new(tr("抽中人员")).strong().size(14.0));
+                                self.sort_header(ui, tr("所属部门"), SortColumn::Inspecting);
+                                ui.label("");
                                 ui.end_row();
-                                
-                                // 数据行 - 按时间倒序显示
-                                for record in records.iter().rev() {
-                                    ui.label(record.timestamp.format("%m-%d %H:%M").to_string());
-                                    ui.label(&record.target_department_name);
-                                    ui.label(record.specialty_type.display_name());
-                                    ui.label(egui::RichText::new(&record.selected_specialist_name)
-                                        .color(egui::Color32::from_rgb(0, 150, 255))
-                                        .strong());
-                                    ui.label(&record.selected_from_department_name);
+
+                                for record in filtered.iter().skip(self.page * PAGE_SIZE).take(PAGE_SIZE) {
+                                    // 作废记录显示删除线
+                                    let cell = |text: String| {
+                                        let text = egui::RichText::new(text);
+                                        if record.is_voided() {
+                                            text.strikethrough().color(egui::Color32::GRAY)
+                                        } else {
+                                            text
+                                        }
+                                    };
+                                    ui.label(cell(Self::round_label(record.round)));
+                                    ui.label(cell(record.timestamp.format("%Y-%m-%d %H:%M").to_string()));
+                                    ui.label(cell(record.target_department_name.clone()));
+                                    ui.label(cell(record.specialty_label().to_string()));
+                                    if record.is_voided() {
+                                        ui.label(cell(record.selected_specialist_name.clone()));
+                                    } else {
+                                        ui.label(egui::RichText::new(&record.selected_specialist_name)
+                                            .color(egui::Color32::from_rgb(0, 150, 255))
+                                            .strong());
+                                    }
+                                    ui.label(cell(record.selected_from_department_name.clone()));
+                                    if ui.small_button(tr("🔍 详情")).clicked() {
+                                        self.detail_record_id = Some(record.id.clone());
+                                    }
                                     ui.end_row();
                                 }
                             });
                     });
+
+                // 分页
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(self.page > 0, egui::Button::new(tr("◀ 上一页"))).clicked() {
+                        self.page -= 1;
+                    }
+                    ui.label(trf("第 {} / {} 页", &[&(self.page + 1), &page_count]));
+                    if ui.add_enabled(self.page + 1 < page_count, egui::Button::new(tr("下一页 ▶"))).clicked() {
+                        self.page += 1;
+                    }
+                });
+            });
+
+        self.visible = open;
+
+        self.show_detail(ctx, records, store, current_round, operator)
+    }
+
+    /// 归档控件：选择轮次并确认后归档（替代原来的清空全部记录）
+    fn show_archive_controls(
+        &mut self,
+        ui: &mut egui::Ui,
+        records: &mut Vec<DrawRecord>,
+        store: &DataStore,
+        operator: &Operator,
+    ) {
+        let mut rounds: Vec<u32> = records.iter()
+            .filter(|r| !r.archived)
+            .map(|r| r.round)
+            .collect();
+        rounds.sort_unstable();
+        rounds.dedup();
+        if self.archive_round.is_some_and(|r| !rounds.contains(&r)) {
+            self.archive_round = None;
+            self.confirm_archive = false;
+        }
+
+        if self.confirm_archive {
+            if ui.button(tr("取消")).clicked() {
+                self.confirm_archive = false;
+            }
+            if ui.button(egui::RichText::new(tr("确认归档")).color(egui::Color32::from_rgb(200, 120, 0))).clicked() {
+                if let Some(round) = self.archive_round {
+                    match store.archive_round(round) {
+                        Ok(count) => {
+                            store.append_audit(&AuditEntry::new(
+                                operator,
+                                AuditAction::ArchiveRound,
+                                trf("{}（{}条记录）", &[&Self::round_label(round), &count]),
+                            ));
+                            if let Ok(loaded) = store.load_records() {
+                                *records = loaded;
+                            }
+                            self.archive_error = None;
+                        }
+                        Err(e) => self.archive_error = Some(e),
+                    }
+                }
+                self.confirm_archive = false;
+                self.archive_round = None;
+            }
+            ui.label(trf(
+                "归档{}后，这些记录将不再出现在列表和导出中",
+                &[&self.archive_round.map(Self::round_label).unwrap_or_default()],
+            ));
+            return;
+        }
+
+        if ui.add_enabled(self.archive_round.is_some(), egui::Button::new(tr("📦 归档"))).clicked() {
+            self.confirm_archive = true;
+        }
+        egui::ComboBox::from_id_salt("history_archive_round")
+            .selected_text(self.archive_round.map(Self::round_label).unwrap_or_else(|| tr("选择轮次").to_string()))
+            .show_ui(ui, |ui| {
+                for round in &rounds {
+                    ui.selectable_value(&mut self.archive_round, Some(*round), Self::round_label(*round));
+                }
+            });
+        ui.checkbox(&mut self.filter.include_archived, tr("显示已归档"));
+        if let Some(error) = &self.archive_error {
+            ui.label(egui::RichText::new(error).color(egui::Color32::RED));
+        }
+    }
+
+    /// 显示筛选工具栏
+    fn show_filters(&mut self, ui: &mut egui::Ui, records: &[DrawRecord]) {
+        let before = self.filter.clone();
+
+        ui.horizontal(|ui| {
+            ui.label(tr("日期:"));
+            ui.add(egui::TextEdit::singleline(&mut self.date_from_text)
+                .hint_text(tr("起始 YYYY-MM-DD"))
+                .desired_width(100.0));
+            ui.label(tr("至"));
+            ui.add(egui::TextEdit::singleline(&mut self.date_to_text)
+                .hint_text(tr("截止 YYYY-MM-DD"))
+                .desired_width(100.0));
+
+            self.filter.date_from = Self::parse_date(&self.date_from_text);
+            self.filter.date_to = Self::parse_date(&self.date_to_text);
+            let invalid_date = (!self.date_from_text.trim().is_empty() && self.filter.date_from.is_none())
+                || (!self.date_to_text.trim().is_empty() && self.filter.date_to.is_none());
+            if invalid_date {
+                ui.label(egui::RichText::new(tr("日期格式应为 YYYY-MM-DD")).color(egui::Color32::RED));
+            }
+
+            ui.label(tr("轮次:"));
+            let mut rounds: Vec<u32> = records.iter().map(|r| r.round).collect();
+            rounds.sort_unstable();
+            rounds.dedup();
+            egui::ComboBox::from_id_salt("history_filter_round")
+                .selected_text(self.filter.round.map(Self::round_label).unwrap_or_else(|| tr("所有轮次").to_string()))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.filter.round, None, tr("所有轮次"));
+                    for round in rounds {
+                        ui.selectable_value(&mut self.filter.round, Some(round), Self::round_label(round));
+                    }
+                });
+
+            ui.label(tr("专业:"));
+            let mut specialties: Vec<(&str, &str)> = Vec::new();
+            for record in records {
+                if !specialties.iter().any(|(id, _)| *id == record.specialty_type) {
+                    specialties.push((&record.specialty_type, record.specialty_label()));
+                }
+            }
+            let selected = self.filter.specialty.as_deref()
+                .and_then(|id| specialties.iter().find(|(s, _)| *s == id))
+                .map_or(tr("所有专业"), |(_, name)| *name);
+            egui::ComboBox::from_id_salt("history_filter_specialty")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.filter.specialty, None, tr("所有专业"));
+                    for (id, name) in &specialties {
+                        ui.selectable_value(&mut self.filter.specialty, Some(id.to_string()), *name);
+                    }
+                });
+        });
+
+        ui.horizontal(|ui| {
+            // 部门选项取自记录本身，已删除的部门也能筛选
+            let mut targets: Vec<(&str, &str)> = records.iter()
+                .map(|r| (r.target_department_id.as_str(), r.target_department_name.as_str()))
+                .collect();
+            targets.sort_by(|a, b| a.1.cmp(b.1));
+            targets.dedup();
+            let mut inspectors: Vec<(&str, &str)> = records.iter()
+                .map(|r| (r.selected_from_department_id.as_str(), r.selected_from_department_name.as_str()))
+                .collect();
+            inspectors.sort_by(|a, b| a.1.cmp(b.1));
+            inspectors.dedup();
+
+            ui.label(tr("被检部门:"));
+            Self::department_combo(ui, "history_filter_target", &mut self.filter.target_department_id, &targets);
+
+            ui.label(tr("检查部门:"));
+            Self::department_combo(ui, "history_filter_inspecting", &mut self.filter.inspecting_department_id, &inspectors);
+
+            ui.label("🔍");
+            ui.add(egui::TextEdit::singleline(&mut self.filter.search)
+                .hint_text(tr("搜索部门、规则说明"))
+                .desired_width(140.0));
+
+            if ui.button(tr("❌ 重置")).clicked() {
+                self.filter = RecordFilter::default();
+                self.date_from_text.clear();
+                self.date_to_text.clear();
+            }
+        });
+
+        // 筛选条件变化时回到第一页
+        if self.filter != before {
+            self.page = 0;
+        }
+    }
+
+    /// 部门筛选下拉框
+    fn department_combo(ui: &mut egui::Ui, id: &str, selected: &mut Option<String>, options: &[(&str, &str)]) {
+        let selected_text = selected.as_ref()
+            .and_then(|id| options.iter().find(|(dept_id, _)| dept_id == id))
+            .map(|(_, name)| *name)
+            .unwrap_or(tr("所有部门"));
+        egui::ComboBox::from_id_salt(id)
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(selected, None, tr("所有部门"));
+                for (dept_id, name) in options {
+                    ui.selectable_value(selected, Some(dept_id.to_string()), *name);
+                }
+            });
+    }
+
+    /// 可点击排序的表头
+    fn sort_header(&mut self, ui: &mut egui::Ui, title: &str, column: SortColumn) {
+        let text = if self.sort_column == column {
+            format!("{} {}", title, if self.sort_ascending { "▲" } else { "▼" })
+        } else {
+            title.to_string()
+        };
+
+        if ui.add(egui::Button::new(egui::RichText::new(text).strong().size(14.0)).frame(false)).clicked() {
+            if self.sort_column == column {
+                self.sort_ascending = !self.sort_ascending;
+            } else {
+                self.sort_column = column;
+                self.sort_ascending = true;
+            }
+        }
+    }
+
+    /// 显示记录详情窗口
+    fn show_detail(
+        &mut self,
+        ctx: &egui::Context,
+        records: &mut Vec<DrawRecord>,
+        store: &DataStore,
+        current_round: u32,
+        operator: &Operator,
+    ) -> Option<HistoryAction> {
+        let record_id = self.detail_record_id.clone()?;
+        let Some(record) = records.iter().find(|r| r.id == record_id).cloned() else {
+            self.detail_record_id = None;
+            return None;
+        };
+
+        let mut open = true;
+        let mut action = None;
+
+        egui::Window::new(tr("🔍 记录详情"))
+            .open(&mut open)
+            .default_width(420.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                egui::Grid::new("history_detail_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(tr("记录ID:"));
+                        ui.label(&record.id);
+                        ui.end_row();
+                        ui.label(tr("抽签时间:"));
+                        ui.label(record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string());
+                        ui.end_row();
+                        ui.label(tr("轮次:"));
+                        ui.label(Self::round_label(record.round));
+                        ui.end_row();
+                        ui.label(tr("被检部门:"));
+                        ui.label(&record.target_department_name);
+                        ui.end_row();
+                        ui.label(tr("专责类型:"));
+                        ui.label(record.specialty_label());
+                        ui.end_row();
+                        ui.label(tr("抽中结果:"));
+                        ui.label(egui::RichText::new(&record.selected_specialist_name).strong());
+                        ui.end_row();
+                        if let Some(team) = &record.team {
+                            ui.label(tr("检查组:"));
+                            ui.label(trf("本次抽取{}个部门中的第{}个", &[&team.size, &(team.position + 1)]));
+                            ui.end_row();
+                        }
+                        ui.label(tr("随机种子:"));
+                        ui.label(record.seed.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string()));
+                        ui.end_row();
+                        ui.label(tr("操作员:"));
+                        ui.label(record.operator_name.as_deref().unwrap_or("-"));
+                        ui.end_row();
+                        ui.label(tr("见证人:"));
+                        match &record.witness {
+                            Some(witness) => ui.label(format!(
+                                "{}（{}{}）",
+                                witness.name,
+                                witness.confirmed_at.format("%Y-%m-%d %H:%M:%S"),
+                                witness.remote_addr.as_ref().map(|a| trf("，局域网 {}", &[&a])).unwrap_or_default()
+                            )),
+                            None => ui.label("-"),
+                        };
+                        ui.end_row();
+                        ui.label(tr("状态:"));
+                        match &record.voided {
+                            Some(info) => ui.label(egui::RichText::new(trf(
+                                "已作废：{}（{} {}）",
+                                &[&info.reason, &info.operator, &info.timestamp.format("%Y-%m-%d %H:%M:%S")],
+                            )).color(egui::Color32::RED)),
+                            None if record.archived => ui.label(tr("已归档")),
+                            None => ui.label(tr("有效")),
+                        };
+                        ui.end_row();
+                    });
+
+                ui.separator();
+                ui.label(egui::RichText::new(tr("候选名单")).strong());
+                if record.candidates.is_empty() {
+                    ui.label(egui::RichText::new(tr("（该记录生成于旧版本，未保存候选名单）")).color(egui::Color32::GRAY));
+                } else {
+                    ui.label(record.candidates.join(tr("、")));
+                }
+
+                ui.separator();
+                ui.label(egui::RichText::new(tr("规则说明")).strong());
+                if record.seed.is_none() {
+                    ui.label(egui::RichText::new(tr("（该记录生成于旧版本，未保存规则说明）")).color(egui::Color32::GRAY));
+                } else if record.rule_notes.is_empty() {
+                    ui.label(tr("无被排除的部门"));
+                } else {
+                    for note in &record.rule_notes {
+                        ui.label(format!("• {}", note));
+                    }
+                }
+
+                if record.replay.is_some() {
+                    ui.separator();
+                    if ui.button(tr("🎬 回放转盘")).clicked() {
+                        action = Some(HistoryAction::Replay(Box::new(record.clone())));
+                    }
+                }
+
+                ui.separator();
+                if record.is_voided() {
+                    // 本轮作废的记录可以按本轮规则重新抽取
+                    if record.round == current_round
+                        && !record.archived
+                        && operator.role.can_draw()
+                        && ui.button(tr("🔁 重新抽取")).clicked()
+                    {
+                        action = Some(HistoryAction::Redraw {
+                            target_department_id: record.target_department_id.clone(),
+                            specialty: record.specialty_label().to_string(),
+                        });
+                        self.detail_record_id = None;
+                    }
+                } else if !record.archived && operator.role.can_manage() {
+                    ui.label(egui::RichText::new(tr("作废该记录")).strong());
+                    ui.horizontal(|ui| {
+                        ui.label(tr("原因:"));
+                        ui.add(egui::TextEdit::singleline(&mut self.void_reason).hint_text(tr("必填")));
+                    });
+                    ui.label(trf("操作人: {}", &[&operator.display_name]));
+                    if ui.button(egui::RichText::new(tr("⛔ 作废")).color(egui::Color32::RED)).clicked() {
+                        match store.void_record(&record.id, &self.void_reason, operator) {
+                            Ok(voided) => {
+                                store.append_audit(&AuditEntry::new(
+                                    operator,
+                                    AuditAction::VoidRecord,
+                                    format!(
+                                        "{} {} {}",
+                                        voided.target_department_name,
+                                        voided.specialty_label(),
+                                        voided.selected_specialist_name
+                                    ),
+                                ).with_old(&record).with_new(&voided));
+                                if let Ok(loaded) = store.load_records() {
+                                    *records = loaded;
+                                }
+                                self.void_reason.clear();
+                                self.void_error = None;
+                                action = Some(HistoryAction::Voided(Box::new(voided)));
+                            }
+                            Err(e) => self.void_error = Some(e),
+                        }
+                    }
+                    if let Some(error) = &self.void_error {
+                        ui.label(egui::RichText::new(error).color(egui::Color32::RED));
+                    }
+                }
             });
+
+        if !open {
+            self.detail_record_id = None;
+            self.void_error = None;
+        }
+
+        action
+    }
+
+    /// 解析日期输入，空或格式错误返回 None
+    fn parse_date(text: &str) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").ok()
+    }
+
+    /// 轮次显示文本（旧版本记录没有轮次）
+    fn round_label(round: u32) -> String {
+        if round == 0 {
+            tr("旧记录").to_string()
+        } else {
+            trf("第{}轮", &[&round])
+        }
     }
 }
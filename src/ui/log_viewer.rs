@@ -0,0 +1,155 @@
+//! 运行日志面板 - 查看和导出程序运行日志（随问题反馈提交）
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::logging::{self, Level, LogEntry};
+
+/// 运行日志面板
+#[derive(Default)]
+pub struct LogViewer {
+    /// 是否显示
+    pub visible: bool,
+    /// 已加载的日志（按时间先后排列）
+    entries: Vec<LogEntry>,
+    /// 最低显示级别（None 为全部）
+    filter_level: Option<Level>,
+    /// 搜索文本（模块、消息、字段）
+    search: String,
+    /// 提示消息
+    message: Option<String>,
+}
+
+impl LogViewer {
+    /// 打开面板并重新加载日志
+    pub fn open(&mut self) {
+        self.reload();
+        self.message = None;
+        self.visible = true;
+    }
+
+    /// 重新读取日志文件
+    fn reload(&mut self) {
+        self.entries = logging::logger().map(|l| l.read_entries()).unwrap_or_default();
+    }
+
+    /// 显示运行日志面板
+    pub fn show(&mut self, ctx: &egui::Context) {
+        if !self.visible {
+            return;
+        }
+
+        let mut open = self.visible;
+
+        egui::Window::new(tr("📝 运行日志"))
+            .open(&mut open)
+            .default_width(820.0)
+            .default_height(520.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("🔍 搜索:"));
+                    ui.text_edit_singleline(&mut self.search);
+
+                    egui::ComboBox::from_id_salt("log_filter_level")
+                        .selected_text(self.filter_level.map(|l| l.display_name()).unwrap_or(tr("所有级别")))
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.filter_level, None, tr("所有级别"));
+                            for level in Level::all() {
+                                ui.selectable_value(&mut self.filter_level, Some(level), level.display_name());
+                            }
+                        });
+
+                    if ui.button(tr("🔄 刷新")).clicked() {
+                        self.reload();
+                    }
+                });
+
+                let filtered: Vec<&LogEntry> = self.entries.iter()
+                    .rev()
+                    .filter(|e| self.filter_level.is_none_or(|level| e.level <= level))
+                    .filter(|e| self.search.is_empty() || Self::matches_search(e, &self.search))
+                    .collect();
+
+                ui.horizontal(|ui| {
+                    ui.label(trf("显示 {} / 共 {} 条", &[&filtered.len(), &self.entries.len()]));
+                    if ui.add_enabled(logging::logger().is_some(), egui::Button::new(tr("📤 导出日志"))).clicked() {
+                        self.message = Some(Self::export());
+                    }
+                    if let Some(msg) = &self.message {
+                        ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+                    }
+                });
+                if let Some(logger) = logging::logger() {
+                    ui.label(egui::RichText::new(trf("日志文件: {}", &[&logger.path().display()]))
+                        .small()
+                        .color(egui::Color32::GRAY));
+                }
+
+                ui.separator();
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    egui::Grid::new("log_grid")
+                        .num_columns(4)
+                        .striped(true)
+                        .spacing([16.0, 6.0])
+                        .show(ui, |ui| {
+                            ui.strong(tr("时间"));
+                            ui.strong(tr("级别"));
+                            ui.strong(tr("模块"));
+                            ui.strong(tr("内容"));
+                            ui.end_row();
+
+                            for entry in &filtered {
+                                ui.label(entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string());
+                                ui.label(egui::RichText::new(entry.level.display_name()).color(Self::level_color(entry.level)));
+                                ui.label(&entry.target);
+                                ui.vertical(|ui| {
+                                    ui.label(&entry.message);
+                                    for (key, value) in &entry.fields {
+                                        ui.label(egui::RichText::new(format!("{} = {}", key, value)).monospace().small());
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+
+        self.visible = open;
+    }
+
+    /// 级别颜色
+    fn level_color(level: Level) -> egui::Color32 {
+        match level {
+            Level::Error => egui::Color32::RED,
+            Level::Warn => egui::Color32::from_rgb(230, 160, 0),
+            Level::Info => egui::Color32::from_rgb(100, 150, 200),
+            Level::Debug => egui::Color32::GRAY,
+        }
+    }
+
+    /// 搜索匹配（模块、消息、字段）
+    fn matches_search(entry: &LogEntry, search: &str) -> bool {
+        entry.target.contains(search)
+            || entry.message.contains(search)
+            || entry.fields.values().any(|v| v.contains(search))
+    }
+
+    /// 把全部日志文件合并导出到桌面，返回提示消息
+    fn export() -> String {
+        let Some(logger) = logging::logger() else {
+            return tr("未启用运行日志").to_string();
+        };
+        let filename = trf(
+            "运行日志_{}.log",
+            &[&chrono::Local::now().format("%Y%m%d_%H%M%S")],
+        );
+        let desktop = dirs::desktop_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
+        let path = desktop.join(&filename);
+
+        match logger.export(&path) {
+            Ok(()) => trf("已导出到: {}", &[&path.display()]),
+            Err(e) => trf("导出失败: {}", &[&e]),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! 登录界面
+//!
+//! 默认单位首次运行（没有任何账户）时显示创建管理员表单，其他单位的管理员在新增单位时创建。
+//! 账户文件存在但无法读取时只显示错误，不允许登录，也不允许重新创建管理员。
+
+use eframe::egui;
+use crate::i18n::tr;
+use crate::logic::AuthManager;
+use crate::models::{AuditAction, AuditEntry, Operator, Role};
+use crate::storage::DataStore;
+
+/// 登录面板
+#[derive(Default)]
+pub struct LoginPanel {
+    /// 登录名输入
+    username: String,
+    /// 密码输入
+    password: String,
+    /// 首次运行：管理员姓名
+    display_name: String,
+    /// 首次运行：确认密码
+    confirm_password: String,
+    /// 错误提示
+    error: Option<String>,
+}
+
+impl LoginPanel {
+    /// 显示登录界面，登录成功时返回操作员
+    /// 
+    /// `load_error` 为账户或抽签记录文件的读取错误，有错误时不显示登录和创建管理员表单
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        operators: &mut Vec<Operator>,
+        load_error: Option<&str>,
+        store: &DataStore,
+        title: &str,
+    ) -> Option<Operator> {
+        let mut logged_in = None;
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(80.0);
+                ui.heading(egui::RichText::new(title)
+                    .size(24.0)
+                    .color(egui::Color32::from_rgb(50, 100, 180)));
+                ui.add_space(30.0);
+
+                if let Some(error) = load_error {
+                    ui.label(egui::RichText::new(tr("⚠ 账户或抽签记录文件无法读取，已禁止登录。请从备份恢复该文件后重新启动程序。"))
+                        .size(16.0)
+                        .color(egui::Color32::RED));
+                    ui.add_space(10.0);
+                    ui.label(egui::RichText::new(error).color(egui::Color32::GRAY));
+                    return;
+                }
+
+                let setup = operators.is_empty();
+                if setup && !store.is_default_organization() {
+                    // 新单位的管理员在新增单位时创建，不能在登录界面认领
+                    ui.label(egui::RichText::new(tr("⚠ 该单位还没有任何账户，不能在登录界面创建管理员。请从备份恢复该单位的账户文件。"))
+                        .size(16.0)
+                        .color(egui::Color32::RED));
+                    return;
+                }
+                ui.label(egui::RichText::new(if setup { tr("首次使用，请创建管理员账户") } else { tr("请登录") }).size(16.0));
+                ui.add_space(15.0);
+
+                let mut submitted = false;
+                egui::Grid::new("login_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(tr("登录名:"));
+                        ui.text_edit_singleline(&mut self.username);
+                        ui.end_row();
+
+                        if setup {
+                            ui.label(tr("姓名:"));
+                            ui.text_edit_singleline(&mut self.display_name);
+                            ui.end_row();
+                        }
+
+                        ui.label(tr("密码:"));
+                        let response = ui.add(egui::TextEdit::singleline(&mut self.password).password(true));
+                        if !setup && response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                            submitted = true;
+                        }
+                        ui.end_row();
+
+                        if setup {
+                            ui.label(tr("确认密码:"));
+                            ui.add(egui::TextEdit::singleline(&mut self.confirm_password).password(true));
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(15.0);
+                let button_text = if setup { tr("创建并登录") } else { tr("🔑 登录") };
+                if ui.add_sized([160.0, 36.0], egui::Button::new(button_text)).clicked() {
+                    submitted = true;
+                }
+
+                if submitted {
+                    let result = if setup {
+                        self.create_admin(operators, store)
+                    } else {
+                        AuthManager::authenticate(operators, &self.username, &self.password)
+                    };
+                    match result {
+                        Ok(operator) => {
+                            *self = Self::default();
+                            logged_in = Some(operator);
+                        }
+                        Err(e) => {
+                            self.password.clear();
+                            self.confirm_password.clear();
+                            self.error = Some(e);
+                        }
+                    }
+                }
+
+                if let Some(error) = &self.error {
+                    ui.add_space(10.0);
+                    ui.label(egui::RichText::new(error).color(egui::Color32::RED));
+                }
+            });
+        });
+
+        logged_in
+    }
+
+    /// 首次运行时创建管理员账户
+    fn create_admin(&self, operators: &mut Vec<Operator>, store: &DataStore) -> Result<Operator, String> {
+        if self.password != self.confirm_password {
+            return Err(tr("两次输入的密码不一致").to_string());
+        }
+        let admin = AuthManager::create_operator(
+            operators,
+            &self.username,
+            &self.display_name,
+            Role::Admin,
+            &self.password,
+        )?;
+        operators.push(admin.clone());
+        store.save_operators(operators);
+        store.append_audit(&AuditEntry::new(
+            &admin,
+            AuditAction::AddOperator,
+            format!("{}（{}）", admin.display_name, admin.username),
+        ).with_new(&admin.role));
+        Ok(admin)
+    }
+}
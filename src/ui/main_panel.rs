@@ -1,330 +1,502 @@
 //! 主抽签面板
 
+use std::collections::BTreeMap;
 use eframe::egui;
-use crate::models::{Department, DepartmentType, QualitySpecialist, DrawRecord, SpecialtyType};
+use crate::models::{AnimationSettings, Department, DisplaySettings, DrawRules, QualitySpecialist, DrawRecord, Operator, Organization, Specialty, TeamInfo, WitnessInfo, specialty_name};
+use crate::i18n::{tr, trf};
+use crate::logging;
 use crate::logic::DrawEngine;
 use crate::storage::DataStore;
+use crate::server::{LiveSnapshot, RoundResult, WheelSnapshot};
 use super::animation::{AnimationState, AnimationPhase};
+use super::sound::{self, SoundCue};
+use super::theme::Palette;
 
-/// 抽签类型（综合类部门需要两种）
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum DrawType {
-    /// 只抽承压
-    PressureOnly,
-    /// 只抽机电
-    MechanicalOnly,
-    /// 同时抽取（综合类）
-    Both,
+/// 专业显示颜色（按专业在列表中的顺序循环使用）
+const SPECIALTY_COLORS: [(u8, u8, u8); 5] = [
+    (255, 138, 128),
+    (128, 200, 255),
+    (150, 220, 130),
+    (255, 200, 100),
+    (200, 160, 255),
+];
+
+/// 专业的显示颜色
+pub fn specialty_color(specialties: &[Specialty], specialty_id: &str) -> egui::Color32 {
+    let index = specialties.iter().position(|s| s.id == specialty_id).unwrap_or(specialties.len());
+    let (r, g, b) = SPECIALTY_COLORS[index % SPECIALTY_COLORS.len()];
+    egui::Color32::from_rgb(r, g, b)
+}
+
+/// 一次抽取的过程信息（停止后写入抽签记录）
+#[derive(Debug, Clone, Default)]
+pub struct DrawContext {
+    /// 随机种子
+    pub seed: u64,
+    /// 候选名单（打乱前）
+    pub candidates: Vec<String>,
+    /// 规则说明
+    pub rule_notes: Vec<String>,
 }
 
-/// 当前正在抽取的专责类型
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum CurrentDrawing {
-    /// 抽取承压类
-    Pressure,
-    /// 抽取机电类
-    Mechanical,
+/// 一个专业的转盘
+#[derive(Clone)]
+pub struct Wheel {
+    /// 抽取的专业
+    pub specialty: Specialty,
+    /// 动画状态
+    pub animation: AnimationState,
+    /// 抽中的部门名称（检查组按抽中顺序排列，未抽取时为空）
+    pub results: Vec<String>,
+    /// 本次抽取的过程信息
+    pub context: Option<DrawContext>,
+}
+
+impl Wheel {
+    /// 创建空转盘
+    fn new(specialty: Specialty) -> Self {
+        Self {
+            specialty,
+            animation: AnimationState::default(),
+            results: Vec::new(),
+            context: None,
+        }
+    }
+    
+    /// 清除结果和动画
+    fn reset(&mut self) {
+        self.animation = AnimationState::default();
+        self.results.clear();
+        self.context = None;
+    }
 }
 
 /// 主面板
 pub struct MainPanel {
     /// 选中的部门ID
     pub selected_department_id: Option<String>,
-    /// 承压类动画状态
-    pub pressure_animation: AnimationState,
-    /// 机电类动画状态
-    pub mechanical_animation: AnimationState,
-    /// 当前抽签结果 - 承压
-    pub pressure_result: Option<(String, String)>, // (姓名, 部门)
-    /// 当前抽签结果 - 机电
-    pub mechanical_result: Option<(String, String)>,
+    /// 选中部门需要抽取的各专业转盘
+    pub wheels: Vec<Wheel>,
     /// 状态消息
     pub status_message: String,
     /// 是否正在抽签
     pub is_drawing: bool,
-    /// 当前正在抽取的类型
-    pub current_drawing: Option<CurrentDrawing>,
-    /// 本轮已抽中的承压部门列表 (被检部门ID, 抽中部门ID)
-    pub current_round_pressure_depts: Vec<(String, String)>,
-    /// 本轮已抽中的机电部门列表 (被检部门ID, 抽中部门ID)
-    pub current_round_mechanical_depts: Vec<(String, String)>,
+    /// 本轮已抽中的部门，按专业ID分组 (被检部门ID, 抽中部门ID)
+    pub current_round: BTreeMap<String, Vec<(String, String)>>,
+    /// 当前轮次
+    pub round_no: u32,
+    /// 已抽出、等待见证人确认的记录（确认后才保存）
+    pub pending_records: Vec<DrawRecord>,
+    /// 当前单位的抽签规则
+    pub rules: DrawRules,
+    /// 显示设置（转盘配色和字号）
+    pub display: DisplaySettings,
+    /// 转盘动画设置
+    pub animation: AnimationSettings,
+}
+
+/// 键盘操作（空格开始/停止，上下方向键切换被检部门）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCommand {
+    /// 开始或停止抽签
+    StartStop,
+    /// 上一个部门
+    Previous,
+    /// 下一个部门
+    Next,
+}
+
+impl KeyCommand {
+    /// 读取并消耗本帧的快捷键（避免同时触发获得焦点的按钮）
+    pub fn read(ctx: &egui::Context) -> Option<Self> {
+        if ctx.wants_keyboard_input() {
+            return None;
+        }
+        ctx.input_mut(|i| {
+            if i.consume_key(egui::Modifiers::NONE, egui::Key::Space) {
+                Some(KeyCommand::StartStop)
+            } else if i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp) {
+                Some(KeyCommand::Previous)
+            } else if i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown) {
+                Some(KeyCommand::Next)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// 部门分类，按首次出现的顺序排列
+fn categories(departments: &[Department]) -> Vec<&str> {
+    let mut categories: Vec<&str> = Vec::new();
+    for dept in departments {
+        if !categories.contains(&dept.category.as_str()) {
+            categories.push(&dept.category);
+        }
+    }
+    categories
 }
 
 impl Default for MainPanel {
     fn default() -> Self {
         Self {
             selected_department_id: None,
-            pressure_animation: AnimationState::default(),
-            mechanical_animation: AnimationState::default(),
-            pressure_result: None,
-            mechanical_result: None,
-            status_message: "请选择被检查部门，然后点击开始抽签".to_string(),
+            wheels: Vec::new(),
+            status_message: tr("请选择被检查部门，然后点击开始抽签").to_string(),
             is_drawing: false,
-            current_drawing: None,
-            current_round_pressure_depts: Vec::new(),
-            current_round_mechanical_depts: Vec::new(),
+            current_round: BTreeMap::new(),
+            round_no: 1,
+            pending_records: Vec::new(),
+            rules: DrawRules::default(),
+            display: DisplaySettings::default(),
+            animation: AnimationSettings::default(),
         }
     }
 }
 
 impl MainPanel {
-    /// 获取当前选中部门应该抽取的类型
-    pub fn get_draw_type(&self, departments: &[Department]) -> Option<DrawType> {
-        let dept_id = self.selected_department_id.as_ref()?;
-        let dept = departments.iter().find(|d| &d.id == dept_id)?;
-        
-        Some(match dept.department_type {
-            DepartmentType::Pressure => DrawType::PressureOnly,
-            DepartmentType::Mechanical => DrawType::MechanicalOnly,
-            DepartmentType::Comprehensive => DrawType::Both,
-        })
+    /// 本轮某专业已抽中的 (被检部门ID, 抽中部门ID) 列表
+    pub fn round_pairs(&self, specialty_id: &str) -> &[(String, String)] {
+        self.current_round.get(specialty_id).map(Vec::as_slice).unwrap_or(&[])
+    }
+    
+    /// 本轮已抽取的次数
+    pub fn round_count(&self) -> usize {
+        self.current_round.values().map(Vec::len).sum()
     }
     
+    /// 被检部门本轮该专业还需要抽取的检查部门数（检查组人数减去已抽中的部门数）
+    fn remaining(&self, department: &Department, specialty_id: &str) -> usize {
+        let drawn = self.round_pairs(specialty_id).iter().filter(|(t, _)| t == &department.id).count();
+        department.team_size(specialty_id).saturating_sub(drawn)
+    }
     
-    /// 开始抽签 - 已移动到下方 (show_controls附近)
+    /// 开始新一轮：清空本轮结果并保存轮次
+    pub fn start_new_round(&mut self, store: &DataStore) {
+        self.current_round.clear();
+        self.wheels.iter_mut().for_each(Wheel::reset);
+        self.round_no += 1;
+        store.save_current_round(self.round_no);
+    }
     
-    /// 停止抽签
+    /// 是否有转盘正在转动
+    pub fn is_running(&self) -> bool {
+        self.wheels.iter().any(|w| w.animation.is_running())
+    }
+    
+    /// 选择被检部门，按部门需要抽取的专业准备转盘
+    pub fn select_department(&mut self, department_id: &str, departments: &[Department], specialties: &[Specialty]) {
+        self.selected_department_id = Some(department_id.to_string());
+        self.wheels = departments.iter()
+            .find(|d| d.id == department_id)
+            .map(|dept| {
+                dept.needs.iter()
+                    .map(|id| specialties.iter()
+                        .find(|s| &s.id == id)
+                        .cloned()
+                        .unwrap_or_else(|| Specialty::new(id.clone(), specialty_name(specialties, id))))
+                    .map(Wheel::new)
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
     
     /// 停止抽签
     pub fn stop_draw(&mut self) {
-        self.pressure_animation.request_stop();
-        self.mechanical_animation.request_stop();
-        self.status_message = "减速中...".to_string();
+        let rolling = self.wheels.iter().any(|w| w.animation.phase == AnimationPhase::Rolling);
+        for wheel in &mut self.wheels {
+            wheel.animation.request_stop();
+        }
+        if rolling {
+            self.play(SoundCue::Stop);
+        }
+        self.status_message = tr("减速中...").to_string();
+    }
+    
+    /// 开启提示音时播放
+    fn play(&self, cue: SoundCue) {
+        if self.animation.sound {
+            sound::play(cue);
+        }
     }
     
     /// 更新动画并检查完成状态（抽取部门模式）
+    /// 
+    /// 转盘停止后生成的记录进入待确认列表，见证人确认后才计入本轮并保存
     pub fn update(
         &mut self,
         _specialists: &[QualitySpecialist],
         departments: &[Department],
+        specialties: &[Specialty],
         _records: &[DrawRecord],
-        store: &DataStore,
-    ) -> Vec<DrawRecord> {
-        // 更新动画
-        self.pressure_animation.update();
-        self.mechanical_animation.update();
-        
-        let mut new_records = Vec::new();
-        
-        // 检查承压动画是否完成
-        if self.pressure_animation.phase == AnimationPhase::Stopped && self.pressure_result.is_none() {
-            if let Some(dept_name) = &self.pressure_animation.final_result {
-                // 抽取的是部门名称
-                self.pressure_result = Some((dept_name.clone(), "承压类".to_string()));
-                
-                // 保存到本轮已抽中列表和历史记录
-                if let Some(target_id) = &self.selected_department_id {
-                    if let Some(target_dept) = departments.iter().find(|d| &d.id == target_id) {
-                        if let Some(selected_dept) = departments.iter().find(|d| &d.name == dept_name) {
-                            // 保存到本轮列表
-                            self.current_round_pressure_depts.push((target_id.clone(), selected_dept.id.clone()));
-                            // 创建历史记录
-                            let record = DrawRecord::new(
-                                target_id.clone(),
-                                target_dept.name.clone(),
-                                SpecialtyType::Pressure,
-                                selected_dept.id.clone(),  // 用部门ID代替人员ID
-                                selected_dept.name.clone(), // 用部门名称代替人员名称
-                                selected_dept.id.clone(),
-                                selected_dept.name.clone(),
-                            );
-                            new_records.push(record);
-                        }
-                    }
+        operator: &Operator,
+    ) {
+        // 数据管理中修改了部门需要的专业时重新准备转盘
+        if !self.is_drawing && !self.has_pending() {
+            if let Some(target_id) = self.selected_department_id.clone() {
+                let needs = departments.iter().find(|d| d.id == target_id).map(|d| d.needs.as_slice()).unwrap_or(&[]);
+                let current: Vec<&String> = self.wheels.iter().map(|w| &w.specialty.id).collect();
+                if !current.iter().copied().eq(needs.iter()) {
+                    self.select_department(&target_id, departments, specialties);
                 }
             }
         }
         
-        // 检查机电动画是否完成
-        if self.mechanical_animation.phase == AnimationPhase::Stopped && self.mechanical_result.is_none() {
-            if let Some(dept_name) = &self.mechanical_animation.final_result {
-                // 抽取的是部门名称
-                self.mechanical_result = Some((dept_name.clone(), "机电类".to_string()));
-                
-                // 保存到本轮已抽中列表和历史记录
-                if let Some(target_id) = &self.selected_department_id {
-                    if let Some(target_dept) = departments.iter().find(|d| &d.id == target_id) {
-                        if let Some(selected_dept) = departments.iter().find(|d| &d.name == dept_name) {
-                            // 保存到本轮列表
-                            self.current_round_mechanical_depts.push((target_id.clone(), selected_dept.id.clone()));
-                            // 创建历史记录
-                            let record = DrawRecord::new(
-                                target_id.clone(),
-                                target_dept.name.clone(),
-                                SpecialtyType::Mechanical,
-                                selected_dept.id.clone(),
-                                selected_dept.name.clone(),
-                                selected_dept.id.clone(),
-                                selected_dept.name.clone(),
-                            );
-                            new_records.push(record);
-                        }
-                    }
+        let target = self.selected_department_id.as_ref()
+            .and_then(|id| departments.iter().find(|d| &d.id == id));
+        let mut new_records = Vec::new();
+        let mut cue = None;
+        
+        for wheel in &mut self.wheels {
+            let was_rolling = wheel.animation.phase == AnimationPhase::Rolling;
+            wheel.animation.update();
+            // 自动停止
+            if was_rolling && wheel.animation.phase == AnimationPhase::SlowingDown {
+                cue = cue.or(Some(SoundCue::Stop));
+                self.status_message = tr("减速中...").to_string();
+            }
+            
+            // 转盘停止，记录抽中的部门
+            if wheel.animation.phase != AnimationPhase::Stopped || !wheel.results.is_empty() {
+                continue;
+            }
+            let team = wheel.animation.final_team();
+            if team.is_empty() {
+                continue;
+            }
+            wheel.results = team.clone();
+            cue = Some(SoundCue::Result);
+            
+            let Some(target_dept) = target else {
+                continue;
+            };
+            // 检查组的每个部门一条记录，同一次抽取的记录共用组ID
+            let context = wheel.context.take().unwrap_or_default();
+            logging::info("draw", "抽签结果", &[
+                ("target", &target_dept.name),
+                ("specialty", &wheel.specialty.name),
+                ("seed", &context.seed),
+                ("selected", &team.join("、")),
+            ]);
+            let replay = wheel.animation.replay();
+            let group_id = uuid::Uuid::new_v4().to_string();
+            for (position, dept_name) in team.iter().enumerate() {
+                let Some(selected_dept) = departments.iter().find(|d| &d.name == dept_name) else {
+                    continue;
+                };
+                // 创建历史记录（用部门ID、名称代替人员ID、名称）
+                let mut record = DrawRecord::new(
+                    target_dept.id.clone(),
+                    target_dept.name.clone(),
+                    &wheel.specialty,
+                    selected_dept.id.clone(),
+                    selected_dept.name.clone(),
+                    selected_dept.id.clone(),
+                    selected_dept.name.clone(),
+                ).with_draw_info(self.round_no, context.seed, context.candidates.clone(), context.rule_notes.clone())
+                .with_operator(operator.id.clone(), operator.display_name.clone());
+                if let Some(replay) = &replay {
+                    record = record.with_replay(replay.clone());
                 }
+                if team.len() > 1 {
+                    record = record.with_team(TeamInfo {
+                        group_id: group_id.clone(),
+                        position,
+                        size: team.len(),
+                    });
+                }
+                new_records.push(record);
             }
         }
         
-        // 保存新记录到存储
-        for record in &new_records {
-            store.add_record(record.clone());
+        // 等待见证人确认
+        self.pending_records.extend(new_records);
+        if let Some(cue) = cue {
+            self.play(cue);
         }
         
         // 检查是否全部完成
-        let pressure_done = !self.pressure_animation.is_running() || self.pressure_animation.phase == AnimationPhase::Idle;
-        let mechanical_done = !self.mechanical_animation.is_running() || self.mechanical_animation.phase == AnimationPhase::Idle;
-        
-        if self.is_drawing && pressure_done && mechanical_done {
+        if self.is_drawing && !self.is_running() {
             self.is_drawing = false;
-            self.status_message = "抽签完成！".to_string();
+            self.status_message = if self.has_pending() {
+                tr("抽签完成，等待见证人确认").to_string()
+            } else {
+                tr("抽签完成！").to_string()
+            };
         }
-        
-        new_records
     }
     
-    /// 显示部门选择器
-    pub fn show_department_selector(&mut self, ui: &mut egui::Ui, departments: &[Department]) {
-        ui.heading("选择被检查部门");
-        ui.add_space(10.0);
-        
-        // 综合类部门
-        ui.label(egui::RichText::new("━━ 综合类（承压+机电）━━").color(egui::Color32::from_rgb(100, 180, 100)));
-        for dept in departments.iter().filter(|d| d.department_type == DepartmentType::Comprehensive) {
-            let is_selected = self.selected_department_id.as_ref() == Some(&dept.id);
-            // 检查是否已抽过（综合类需要承压和机电都抽过）
-            let drew_pressure = self.current_round_pressure_depts.iter().any(|(t, _)| t == &dept.id);
-            let drew_mechanical = self.current_round_mechanical_depts.iter().any(|(t, _)| t == &dept.id);
-            let fully_done = drew_pressure && drew_mechanical;
-            
-            let label_text = if fully_done {
-                egui::RichText::new(format!("✓ {}", dept.name)).color(egui::Color32::from_rgb(100, 200, 100))
-            } else if drew_pressure || drew_mechanical {
-                egui::RichText::new(format!("◐ {}", dept.name)).color(egui::Color32::from_rgb(200, 200, 100))
-            } else {
-                egui::RichText::new(&dept.name)
-            };
-            
-            if ui.selectable_label(is_selected, label_text).clicked() {
-                self.selected_department_id = Some(dept.id.clone());
-                self.pressure_result = None;
-                self.mechanical_result = None;
-                self.pressure_animation = AnimationState::default();
-                self.mechanical_animation = AnimationState::default();
+    /// 是否有等待见证人确认的结果
+    pub fn has_pending(&self) -> bool {
+        !self.pending_records.is_empty()
+    }
+    
+    /// 见证人确认：记录见证信息，保存并计入本轮
+    /// 
+    /// 保存失败时结果仍保留为待确认，不计入本轮
+    pub fn confirm_pending(&mut self, witness: WitnessInfo, store: &DataStore) -> Result<Vec<DrawRecord>, String> {
+        let records: Vec<DrawRecord> = self.pending_records.iter()
+            .map(|r| r.clone().with_witness(witness.clone()))
+            .collect();
+        store.add_records(&records)?;
+        self.pending_records.clear();
+        store.save_current_round(self.round_no);
+        for record in &records {
+            self.current_round
+                .entry(record.specialty_type.clone())
+                .or_default()
+                .push((record.target_department_id.clone(), record.selected_from_department_id.clone()));
+            logging::info("draw", "见证确认并保存", &[
+                ("record", &record.id),
+                ("target", &record.target_department_name),
+                ("selected", &record.selected_specialist_name),
+                ("witness", &witness.name),
+            ]);
+        }
+        self.status_message = trf("{} 已确认抽签结果", &[&witness.name]);
+        Ok(records)
+    }
+    
+    /// 取消未确认的结果，被检部门可以重新抽取
+    pub fn cancel_pending(&mut self) -> Vec<DrawRecord> {
+        let records = std::mem::take(&mut self.pending_records);
+        if !records.is_empty() {
+            logging::info("draw", "取消未确认的结果", &[("count", &records.len())]);
+        }
+        for record in &records {
+            if let Some(wheel) = self.wheels.iter_mut().find(|w| w.specialty.id == record.specialty_type) {
+                wheel.reset();
             }
         }
-        
+        self.status_message = tr("已取消未确认的结果，可重新抽取").to_string();
+        records
+    }
+    
+    /// 显示部门选择器（按部门分类分组）
+    pub fn show_department_selector(&mut self, ui: &mut egui::Ui, departments: &[Department], specialties: &[Specialty]) {
+        ui.heading(tr("选择被检查部门"));
         ui.add_space(10.0);
         
-        // 承压类部门
-        ui.label(egui::RichText::new("━━ 承压类 ━━").color(egui::Color32::from_rgb(200, 100, 100)));
-        for dept in departments.iter().filter(|d| d.department_type == DepartmentType::Pressure) {
-            let is_selected = self.selected_department_id.as_ref() == Some(&dept.id);
-            let is_done = self.current_round_pressure_depts.iter().any(|(t, _)| t == &dept.id);
-            
-            let label_text = if is_done {
-                egui::RichText::new(format!("✓ {}", dept.name)).color(egui::Color32::from_rgb(100, 200, 100))
-            } else {
-                egui::RichText::new(&dept.name)
-            };
-            
-            if ui.selectable_label(is_selected, label_text).clicked() {
-                self.selected_department_id = Some(dept.id.clone());
-                self.pressure_result = None;
-                self.mechanical_result = None;
-                self.pressure_animation = AnimationState::default();
-                self.mechanical_animation = AnimationState::default();
-            }
+        // 结果确认或取消前不能切换部门
+        if self.has_pending() {
+            ui.label(egui::RichText::new(tr("请先完成见证确认")).color(Palette::of(self.display).in_progress));
+            ui.add_space(5.0);
+            ui.disable();
         }
         
-        ui.add_space(10.0);
-        
-        // 机电类部门
-        ui.label(egui::RichText::new("━━ 机电类 ━━").color(egui::Color32::from_rgb(100, 150, 200)));
-        for dept in departments.iter().filter(|d| d.department_type == DepartmentType::Mechanical) {
-            let is_selected = self.selected_department_id.as_ref() == Some(&dept.id);
-            let is_done = self.current_round_mechanical_depts.iter().any(|(t, _)| t == &dept.id);
-            
-            let label_text = if is_done {
-                egui::RichText::new(format!("✓ {}", dept.name)).color(egui::Color32::from_rgb(100, 200, 100))
-            } else {
-                egui::RichText::new(&dept.name)
-            };
+        let palette = Palette::of(self.display);
+        let mut clicked = None;
+        for (index, category) in categories(departments).iter().enumerate() {
+            if index > 0 {
+                ui.add_space(10.0);
+            }
+            let title = tr(if category.is_empty() { "未分类" } else { category });
+            let (r, g, b) = SPECIALTY_COLORS[(index + 2) % SPECIALTY_COLORS.len()];
+            ui.label(egui::RichText::new(format!("━━ {} ━━", title)).color(egui::Color32::from_rgb(r, g, b)));
             
-            if ui.selectable_label(is_selected, label_text).clicked() {
-                self.selected_department_id = Some(dept.id.clone());
-                self.pressure_result = None;
-                self.mechanical_result = None;
-                self.pressure_animation = AnimationState::default();
-                self.mechanical_animation = AnimationState::default();
+            for dept in departments.iter().filter(|d| &d.category == category) {
+                let is_selected = self.selected_department_id.as_ref() == Some(&dept.id);
+                // 需要多个专业的部门全部抽齐才算完成
+                let started = dept.needs.iter()
+                    .any(|s| self.round_pairs(s).iter().any(|(t, _)| t == &dept.id));
+                let done = started && dept.needs.iter().all(|s| self.remaining(dept, s) == 0);
+                
+                let label_text = if done {
+                    egui::RichText::new(format!("✓ {}", dept.name)).color(palette.done)
+                } else if started {
+                    egui::RichText::new(format!("◐ {}", dept.name)).color(palette.in_progress)
+                } else {
+                    egui::RichText::new(&dept.name)
+                };
+                
+                if ui.selectable_label(is_selected, label_text).clicked() {
+                    clicked = Some(dept.id.clone());
+                }
             }
         }
+        
+        if let Some(dept_id) = clicked {
+            self.select_department(&dept_id, departments, specialties);
+        }
     }
     
     /// 显示抽签动画区域
-    pub fn show_draw_area(&mut self, ui: &mut egui::Ui, departments: &[Department]) {
-        let draw_type = self.get_draw_type(departments);
-        
+    pub fn show_draw_area(&mut self, ui: &mut egui::Ui) {
+        self.show_wheels(ui, 140.0);
+    }
+    
+    /// 显示转盘，`max_radius` 为转盘半径上限（投影窗口使用更大的转盘）
+    pub fn show_wheels(&mut self, ui: &mut egui::Ui, max_radius: f32) {
         ui.vertical_centered(|ui| {
-            // 根据部门类型显示一个或两个滚动区域
-            match draw_type {
-                Some(DrawType::PressureOnly) => {
-                    self.show_single_animation(ui, "承压类抽选", &self.pressure_animation.clone(), self.pressure_result.clone());
+            // 按部门需要的专业显示一个或多个转盘
+            match self.wheels.len() {
+                0 if self.selected_department_id.is_some() => {
+                    ui.label(tr("该部门未设置需要抽取的专业，请在数据管理中设置"));
                 }
-                Some(DrawType::MechanicalOnly) => {
-                    self.show_single_animation(ui, "机电类抽选", &self.mechanical_animation.clone(), self.mechanical_result.clone());
+                0 => {
+                    ui.label(tr("请选择被检查部门"));
                 }
-                Some(DrawType::Both) => {
-                    ui.push_id("dual_wheels", |ui| {
-                        let _available_width = ui.available_width();
-                        // 强制使用双列布局，确保两个都显示
-                        ui.columns(2, |columns| {
-                            columns[0].vertical_centered(|ui| {
-                                self.show_single_animation(ui, "承压类抽选", &self.pressure_animation.clone(), self.pressure_result.clone());
-                            });
-                            columns[1].vertical_centered(|ui| {
-                                self.show_single_animation(ui, "机电类抽选", &self.mechanical_animation.clone(), self.mechanical_result.clone());
-                            });
+                1 => {
+                    self.show_wheel(ui, &self.wheels[0], max_radius);
+                }
+                count => {
+                    ui.push_id("multi_wheels", |ui| {
+                        ui.columns(count, |columns| {
+                            for (column, wheel) in columns.iter_mut().zip(&self.wheels) {
+                                column.vertical_centered(|ui| self.show_wheel(ui, wheel, max_radius));
+                            }
                         });
                     });
                 }
-                None => {
-                    ui.label("请选择被检查部门");
-                }
             }
         });
     }
     
-    /// 显示单个动画区域 - 大转盘效果
-    fn show_single_animation(
-        &self,
+    /// 显示一个专业的转盘
+    fn show_wheel(&self, ui: &mut egui::Ui, wheel: &Wheel, max_radius: f32) {
+        let result = (!wheel.results.is_empty())
+            .then(|| (wheel.results.join("\n"), tr(&wheel.specialty.name).to_string()));
+        let title = match wheel.animation.team_size {
+            1 => trf("{}抽选", &[&tr(&wheel.specialty.name)]),
+            size => trf("{}抽选（{}个部门）", &[&tr(&wheel.specialty.name), &size]),
+        };
+        Self::show_single_animation(ui, self.display, &title, &wheel.animation, result, max_radius);
+    }
+    
+    /// 显示单个动画区域 - 大转盘效果（历史回放共用）
+    pub(super) fn show_single_animation(
         ui: &mut egui::Ui,
+        display: DisplaySettings,
         title: &str,
         animation: &AnimationState,
         result: Option<(String, String)>,
+        max_radius: f32,
     ) {
         use std::f32::consts::PI;
         
         let is_running = animation.is_running();
+        let palette = Palette::of(display);
         
         // 转盘参数 - 根据可用空间动态调整
         let available_width = ui.available_width();
-        // 计算最大可用半径（留出边距）
-        let max_radius = (available_width - 60.0) / 2.0;
-        // 使用较小的值：最大140或可用空间允许的最大值
-        let wheel_radius = max_radius.min(140.0).max(60.0); // 最小60，最大140
+        // 计算可用空间允许的最大半径（留出边距），不超过 max_radius，最小60
+        let wheel_radius = ((available_width - 60.0) / 2.0).min(max_radius).max(60.0);
         let center_radius = wheel_radius * 0.25; // 按比例计算中心大小
+        // 大转盘（投影窗口）按比例放大文字，大字体主题再整体放大
+        let text_scale = (wheel_radius / 140.0).max(1.0) * palette.font_scale;
         
         ui.vertical_centered(|ui| {
             // 标题
             let title_color = if is_running {
-                egui::Color32::from_rgb(255, 215, 0)
+                palette.title_running
             } else if result.is_some() {
-                egui::Color32::from_rgb(50, 255, 100)
+                palette.title_done
             } else {
-                egui::Color32::from_rgb(150, 180, 220)
+                palette.title_idle
             };
             
             ui.label(egui::RichText::new(format!("❖ {} ❖", title))
-                .size(20.0)
+                .size(20.0 * palette.font_scale)
                 .color(title_color)
                 .strong());
             
@@ -344,35 +516,38 @@ impl MainPanel {
             let full_radius = wheel_radius + outer_rim_width;
             
             // 模拟金属拉丝效果 - 深色底
-            painter.circle_filled(center, full_radius, egui::Color32::from_rgb(40, 43, 48));
+            painter.circle_filled(center, full_radius, palette.rim);
             // 金属光泽环
-            painter.circle_stroke(center, full_radius - 2.0, egui::Stroke::new(2.0, egui::Color32::from_rgb(80, 85, 95)));
-            painter.circle_stroke(center, full_radius - 5.0, egui::Stroke::new(4.0, egui::Color32::from_rgb(30, 32, 36)));
-            painter.circle_stroke(center, full_radius - 8.0, egui::Stroke::new(1.0, egui::Color32::from_rgb(100, 105, 115)));
+            let [outer_ring, middle_ring, inner_ring] = palette.rim_rings;
+            painter.circle_stroke(center, full_radius - 2.0, egui::Stroke::new(2.0, outer_ring));
+            painter.circle_stroke(center, full_radius - 5.0, egui::Stroke::new(4.0, middle_ring));
+            painter.circle_stroke(center, full_radius - 8.0, egui::Stroke::new(1.0, inner_ring));
             
             // 3. 转盘背景
-            painter.circle_filled(center, wheel_radius, egui::Color32::from_rgb(25, 25, 30));
+            painter.circle_filled(center, wheel_radius, palette.wheel_background);
 
             // 中奖状态显示
             if let Some((name, dept)) = &result {
                 // 绘制静态的中奖结果盘面
                 
                 // 绘制选中扇形的高亮背景（占满全圆，但稍微暗一点）
-                painter.circle_filled(center, wheel_radius, egui::Color32::from_rgb(30, 40, 30));
+                painter.circle_filled(center, wheel_radius, palette.result_background);
                 
                 // 绘制独特的发光环，表示锁定
+                let [r, g, b, _] = palette.result_glow.to_array();
                 for i in 0..5 {
                     let alpha = (100 - i * 20) as u8;
                     painter.circle_stroke(center, wheel_radius - i as f32 * 2.0, 
-                        egui::Stroke::new(2.0, egui::Color32::from_rgba_unmultiplied(50, 255, 100, alpha)));
+                        egui::Stroke::new(2.0, egui::Color32::from_rgba_unmultiplied(r, g, b, alpha)));
                 }
 
                 // 中心发光区
                 painter.circle_filled(center, wheel_radius * 0.7, egui::Color32::from_black_alpha(100));
                 
-                // 名字 - 根据文字长度动态调整字体大小
-                let name_len = name.chars().count();
-                let font_size = if name_len <= 4 {
+                // 名字 - 根据文字长度动态调整字体大小（检查组每行一个部门）
+                let line_count = name.lines().count().max(1);
+                let name_len = name.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+                let font_size = text_scale / (line_count as f32).sqrt() * if name_len <= 4 {
                     40.0
                 } else if name_len <= 6 {
                     32.0
@@ -386,24 +561,24 @@ impl MainPanel {
                     egui::Align2::CENTER_CENTER,
                     name,
                     egui::FontId::proportional(font_size),
-                    egui::Color32::from_rgb(255, 230, 100),
+                    palette.result_name,
                 );
                 
                 // 部门和小字
                 painter.text(
-                    center + egui::vec2(0.0, 45.0),
+                    center + egui::vec2(0.0, 45.0 * text_scale),
                     egui::Align2::CENTER_CENTER,
                     dept,
-                    egui::FontId::proportional(14.0),
-                    egui::Color32::from_rgb(180, 200, 180),
+                    egui::FontId::proportional(14.0 * text_scale),
+                    palette.result_detail,
                 );
                 
                 painter.text(
-                    center + egui::vec2(0.0, -50.0),
+                    center + egui::vec2(0.0, -50.0 * text_scale),
                     egui::Align2::CENTER_CENTER,
-                    "🎉 中签 🎉",
-                    egui::FontId::proportional(16.0),
-                    egui::Color32::from_rgb(100, 255, 100),
+                    tr("🎉 中签 🎉"),
+                    egui::FontId::proportional(16.0 * text_scale),
+                    palette.result_badge,
                 );
                 
                 // 绘制简化的中心装饰
@@ -416,7 +591,7 @@ impl MainPanel {
             let candidates = &animation.candidates;
             // 如果没候选人
             if candidates.is_empty() && !is_running {
-                 painter.text(center, egui::Align2::CENTER_CENTER, "准备就绪", egui::FontId::proportional(20.0), egui::Color32::GRAY);
+                 painter.text(center, egui::Align2::CENTER_CENTER, tr("准备就绪"), egui::FontId::proportional(20.0 * palette.font_scale), palette.hint);
                  return;
             }
 
@@ -452,22 +627,10 @@ impl MainPanel {
             };
             let rotation_angle = normalized_position * angle_per_segment;
             
-            // 高级配色方案 (Material Design 500/600 series)
-            let colors = [
-                egui::Color32::from_rgb(244, 67, 54),   // Red
-                egui::Color32::from_rgb(255, 193, 7),   // Amber
-                egui::Color32::from_rgb(76, 175, 80),   // Green
-                egui::Color32::from_rgb(33, 150, 243),  // Blue
-                egui::Color32::from_rgb(156, 39, 176),  // Purple
-                egui::Color32::from_rgb(255, 87, 34),   // Deep Orange
-                egui::Color32::from_rgb(0, 188, 212),   // Cyan
-                egui::Color32::from_rgb(63, 81, 181),   // Indigo
-            ];
-            
             for i in 0..num_segments {
                 let start_angle = i as f32 * angle_per_segment - rotation_angle - PI / 2.0;
                 let end_angle = start_angle + angle_per_segment;
-                let color = colors[i % colors.len()];
+                let (color, text_color) = palette.segments[i % palette.segments.len()];
                 
                 // 4. 绘制扇形 (细分以平滑曲线)
                 let segments = 12;
@@ -495,7 +658,7 @@ impl MainPanel {
                 
                 // 6. 分隔线 (金色)
                 let line_end = center + egui::vec2(end_angle.cos() * wheel_radius, end_angle.sin() * wheel_radius);
-                painter.line_segment([center, line_end], egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 223, 128))); // Gold line
+                painter.line_segment([center, line_end], palette.divider);
 
                 // 7. 文字
                 if let Some(name) = display_candidates.get(i) {
@@ -508,7 +671,7 @@ impl MainPanel {
                         text_pos + egui::vec2(1.0, 1.0),
                         egui::Align2::CENTER_CENTER,
                         &name.chars().take(3).collect::<String>(),
-                        egui::FontId::proportional(14.0),
+                        egui::FontId::proportional(14.0 * text_scale),
                         egui::Color32::from_black_alpha(150),
                     );
                     
@@ -516,8 +679,8 @@ impl MainPanel {
                         text_pos,
                         egui::Align2::CENTER_CENTER,
                         &name.chars().take(3).collect::<String>(),
-                        egui::FontId::proportional(14.0),
-                        egui::Color32::WHITE,
+                        egui::FontId::proportional(14.0 * text_scale),
+                        text_color,
                     );
                 }
             }
@@ -533,23 +696,23 @@ impl MainPanel {
                 let pos = center + egui::vec2(angle.cos() * bulb_dist, angle.sin() * bulb_dist);
                 
                 let lit = if is_running { (i + phase_shift) % 2 == 0 } else { true };
-                let color = if lit { egui::Color32::from_rgb(255, 235, 59) } else { egui::Color32::from_rgb(66, 66, 66) };
+                let color = if lit { palette.bulb_on } else { palette.bulb_off };
                 
                 painter.circle_filled(pos, 3.5, color);
                 if lit {
-                    painter.circle_stroke(pos, 4.0, egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(255, 235, 59, 100)));
+                    painter.circle_stroke(pos, 4.0, egui::Stroke::new(1.0, color.gamma_multiply(0.4)));
                 }
             }
             
             // 9. 中心装饰 (精密部件风格)
             // 外环
-            painter.circle_filled(center, center_radius, egui::Color32::from_rgb(20, 20, 25));
-            painter.circle_stroke(center, center_radius, egui::Stroke::new(3.0, egui::Color32::from_rgb(200, 180, 100))); // Gold ring
+            painter.circle_filled(center, center_radius, palette.hub);
+            painter.circle_stroke(center, center_radius, egui::Stroke::new(3.0, palette.hub_ring)); // Gold ring
             
             // 内环（旋转）
             let inner_angle = if is_running { -(time_ms as f32 * 0.005) } else { 0.0 };
             let sub_radius = center_radius * 0.6;
-            painter.circle_stroke(center, sub_radius, egui::Stroke::new(1.5, egui::Color32::from_rgb(100, 200, 255)));
+            painter.circle_stroke(center, sub_radius, egui::Stroke::new(1.5, palette.crosshair));
             
             // 准星十字
             let cross_len = sub_radius - 2.0;
@@ -558,8 +721,8 @@ impl MainPanel {
             let p3 = center + egui::vec2((inner_angle + PI/2.0).cos() * cross_len, (inner_angle + PI/2.0).sin() * cross_len);
             let p4 = center - egui::vec2((inner_angle + PI/2.0).cos() * cross_len, (inner_angle + PI/2.0).sin() * cross_len);
             
-            painter.line_segment([p1, p2], egui::Stroke::new(1.0, egui::Color32::from_rgb(100, 200, 255)));
-            painter.line_segment([p3, p4], egui::Stroke::new(1.0, egui::Color32::from_rgb(100, 200, 255)));
+            painter.line_segment([p1, p2], egui::Stroke::new(1.0, palette.crosshair));
+            painter.line_segment([p3, p4], egui::Stroke::new(1.0, palette.crosshair));
             
             // 中心点
             painter.circle_filled(center, 4.0, egui::Color32::RED);
@@ -574,13 +737,13 @@ impl MainPanel {
                     pointer_tip + egui::vec2(-pointer_w/2.0, -pointer_h), 
                     pointer_tip + egui::vec2(pointer_w/2.0, -pointer_h)
                 ],
-                egui::Color32::from_rgb(255, 60, 60),
+                palette.pointer,
                 egui::Stroke::new(2.0, egui::Color32::WHITE)
             ));
 
             ui.add_space(10.0);
             if is_running {
-                 ui.label(egui::RichText::new("⚡ 正在选定...").size(14.0).color(egui::Color32::LIGHT_YELLOW));
+                 ui.label(egui::RichText::new(tr("⚡ 正在选定...")).size(14.0 * palette.font_scale).color(palette.title_running));
             }
         });
     }
@@ -593,13 +756,14 @@ impl MainPanel {
         departments: &[Department],
         records: &[DrawRecord],
     ) {
+        let scale = self.display.font_scale();
         ui.horizontal(|ui| {
-            let is_running = self.pressure_animation.is_running() || self.mechanical_animation.is_running();
+            let is_running = self.is_running();
             
-            ui.add_enabled_ui(!is_running && self.selected_department_id.is_some(), |ui| {
-                if ui.add_sized([120.0, 40.0], egui::Button::new(
-                    egui::RichText::new("🎲 开始抽签").size(16.0)
-                )).clicked() {
+            ui.add_enabled_ui(!is_running && !self.has_pending() && self.selected_department_id.is_some(), |ui| {
+                if ui.add_sized([120.0 * scale, 40.0 * scale], egui::Button::new(
+                    egui::RichText::new(tr("🎲 开始抽签")).size(16.0 * scale)
+                )).on_hover_text(tr("快捷键：空格开始/停止，↑↓ 切换被检查部门")).clicked() {
                     self.start_draw(specialists, departments, records);
                 }
             });
@@ -607,14 +771,62 @@ impl MainPanel {
             ui.add_space(20.0);
             
             ui.add_enabled_ui(is_running, |ui| {
-                if ui.add_sized([120.0, 40.0], egui::Button::new(
-                    egui::RichText::new("⏹ 停止").size(16.0)
-                )).clicked() {
+                if ui.add_sized([120.0 * scale, 40.0 * scale], egui::Button::new(
+                    egui::RichText::new(tr("⏹ 停止")).size(16.0 * scale)
+                )).on_hover_text(tr("快捷键：空格")).clicked() {
                     self.stop_draw();
                 }
             });
         });
     }
+    
+    /// 执行键盘操作
+    /// 
+    /// 空格在可以开始时开始抽签、转盘转动时停止（观察员不响应）；方向键按部门列表的
+    /// 显示顺序切换被检部门，抽签过程中和结果确认前不切换
+    pub fn handle_key(
+        &mut self,
+        command: KeyCommand,
+        can_draw: bool,
+        specialists: &[QualitySpecialist],
+        departments: &[Department],
+        specialties: &[Specialty],
+        records: &[DrawRecord],
+    ) {
+        match command {
+            KeyCommand::StartStop if can_draw => {
+                if self.is_running() {
+                    self.stop_draw();
+                } else if !self.is_drawing && !self.has_pending() && self.selected_department_id.is_some() {
+                    self.start_draw(specialists, departments, records);
+                }
+            }
+            KeyCommand::StartStop => {}
+            KeyCommand::Previous | KeyCommand::Next => {
+                if self.is_running() || self.is_drawing || self.has_pending() {
+                    return;
+                }
+                let order: Vec<&str> = categories(departments)
+                    .into_iter()
+                    .flat_map(|category| departments.iter().filter(move |d| d.category == category))
+                    .map(|d| d.id.as_str())
+                    .collect();
+                if order.is_empty() {
+                    return;
+                }
+                let current = self.selected_department_id.as_deref()
+                    .and_then(|id| order.iter().position(|d| *d == id));
+                let index = match (command, current) {
+                    (KeyCommand::Next, Some(i)) => (i + 1) % order.len(),
+                    (KeyCommand::Next, None) => 0,
+                    (_, Some(i)) => (i + order.len() - 1) % order.len(),
+                    (_, None) => order.len() - 1,
+                };
+                let department_id = order[index].to_string();
+                self.select_department(&department_id, departments, specialties);
+            }
+        }
+    }
 
     /// 开始抽签（抽取部门而非人员）
     pub fn start_draw(
@@ -626,189 +838,207 @@ impl MainPanel {
         let dept_id = match &self.selected_department_id {
             Some(id) => id.clone(),
             None => {
-                self.status_message = "请先选择被检查部门".to_string();
+                self.status_message = tr("请先选择被检查部门").to_string();
                 return;
             }
         };
+        if self.wheels.is_empty() {
+            self.status_message = tr("该部门未设置需要抽取的专业").to_string();
+            return;
+        }
         
-        let draw_type = match self.get_draw_type(departments) {
-            Some(t) => t,
-            None => return,
+        let Some(target) = departments.iter().find(|d| d.id == dept_id) else {
+            self.status_message = tr("未找到被检查部门").to_string();
+            return;
         };
         
-        // 检查当前被检部门是否在本轮已经抽过（防止重复抽签）
-        let already_drew_pressure = self.current_round_pressure_depts.iter()
-            .any(|(target, _)| target == &dept_id);
-        let already_drew_mechanical = self.current_round_mechanical_depts.iter()
-            .any(|(target, _)| target == &dept_id);
-        
-        match draw_type {
-            DrawType::PressureOnly if already_drew_pressure => {
-                self.status_message = "该部门本轮已抽过承压类，请点击'开始新一轮'重新开始".to_string();
-                return;
-            }
-            DrawType::MechanicalOnly if already_drew_mechanical => {
-                self.status_message = "该部门本轮已抽过机电类，请点击'开始新一轮'重新开始".to_string();
-                return;
-            }
-            DrawType::Both if already_drew_pressure && already_drew_mechanical => {
-                self.status_message = "该部门本轮已抽过，请点击'开始新一轮'重新开始".to_string();
-                return;
-            }
-            _ => {}
+        // 只抽取本轮尚未抽齐的专业（防止重复抽签）
+        let to_draw: Vec<(usize, usize)> = (0..self.wheels.len())
+            .map(|i| (i, self.remaining(target, &self.wheels[i].specialty.id)))
+            .filter(|&(_, size)| size > 0)
+            .collect();
+        if to_draw.is_empty() {
+            self.status_message = tr("该部门本轮已抽过，请点击'开始新一轮'重新开始").to_string();
+            return;
         }
         
         // 重置结果和动画状态
-        self.pressure_result = None;
-        self.mechanical_result = None;
-        self.pressure_animation = AnimationState::default();
-        self.mechanical_animation = AnimationState::default();
+        self.wheels.iter_mut().for_each(Wheel::reset);
         self.is_drawing = true;
-
-        // 获取承压类可选部门（5个分院 + 承压一部 + 承压二部 + 综合检验站）
-        // 需要排除：1.被检查的部门 2.本轮已被抽中的部门 3.交叉回避的部门
-        let get_pressure_depts = |current_round: &Vec<(String, String)>| -> Vec<String> {
-            // 本轮已被抽中作为承压检查员的部门ID
-            let already_selected: Vec<&String> = current_round.iter().map(|(_, selected)| selected).collect();
-            
-            // 交叉回避：如果当前被检部门(dept_id)的人曾被派去检查其他部门，那些部门不能来检查这个部门
-            // 即：找到所有 "被检部门=某部门 且 抽中部门=dept_id" 的记录，这些"某部门"需要回避
-            let cross_avoidance: Vec<&String> = current_round.iter()
-                .filter(|(_, selected)| selected == &dept_id)
-                .map(|(target, _)| target)
-                .collect();
-            
-            departments.iter()
-                .filter(|d| {
-                    // 包含：综合类（分院）和承压类部门
-                    (d.department_type == DepartmentType::Comprehensive || 
-                     d.department_type == DepartmentType::Pressure)
-                    // 排除被检查的部门
-                    && d.id != dept_id
-                    // 排除本轮已被抽中的部门
-                    && !already_selected.contains(&&d.id)
-                    // 排除交叉回避的部门
-                    && !cross_avoidance.contains(&&d.id)
-                })
-                .map(|d| d.name.clone())
-                .collect()
-        };
-
-        // 获取机电类可选部门（5个分院 + 机电一部 + 机电二部）
-        // 需要排除：1.被检查的部门 2.本轮已被抽中的部门 3.交叉回避的部门
-        let get_mechanical_depts = |current_round: &Vec<(String, String)>| -> Vec<String> {
-            // 本轮已被抽中作为机电检查员的部门ID
-            let already_selected: Vec<&String> = current_round.iter().map(|(_, selected)| selected).collect();
-            
-            // 交叉回避：如果当前被检部门的人曾被派去检查其他部门，那些部门不能来检查这个部门
-            let cross_avoidance: Vec<&String> = current_round.iter()
-                .filter(|(_, selected)| selected == &dept_id)
-                .map(|(target, _)| target)
-                .collect();
-            
-            departments.iter()
-                .filter(|d| {
-                    // 包含：综合类（分院）和机电类部门
-                    (d.department_type == DepartmentType::Comprehensive || 
-                     d.department_type == DepartmentType::Mechanical)
-                    // 排除被检查的部门
-                    && d.id != dept_id
-                    // 排除本轮已被抽中的部门
-                    && !already_selected.contains(&&d.id)
-                    // 排除交叉回避的部门
-                    && !cross_avoidance.contains(&&d.id)
-                })
-                .map(|d| d.name.clone())
-                .collect()
-        };
         
-        match draw_type {
-            DrawType::PressureOnly => {
-                let depts = get_pressure_depts(&self.current_round_pressure_depts);
-                if depts.is_empty() {
-                    self.status_message = "没有可抽取的承压类部门！".to_string();
-                    self.is_drawing = false;
-                    return;
+        let mut started = Vec::new();
+        let mut errors = Vec::new();
+        for (i, size) in to_draw {
+            match self.start_wheel(i, departments, &dept_id, size) {
+                Ok(()) => started.push(tr(&self.wheels[i].specialty.name).to_string()),
+                Err(e) => {
+                    logging::warn("draw", "无法开始抽签", &[("error", &e)]);
+                    errors.push(e);
                 }
-                self.pressure_animation.start(depts);
-                self.current_drawing = Some(CurrentDrawing::Pressure);
-                self.status_message = "正在抽取承压类部门...".to_string();
             }
-            DrawType::MechanicalOnly => {
-                let depts = get_mechanical_depts(&self.current_round_mechanical_depts);
-                if depts.is_empty() {
-                    self.status_message = "没有可抽取的机电类部门！".to_string();
-                    self.is_drawing = false;
-                    return;
-                }
-                self.mechanical_animation.start(depts);
-                self.current_drawing = Some(CurrentDrawing::Mechanical);
-                self.status_message = "正在抽取机电类部门...".to_string();
+        }
+        
+        if !started.is_empty() {
+            self.play(SoundCue::Start);
+        }
+        self.status_message = match started.len() {
+            0 => {
+                self.is_drawing = false;
+                errors.into_iter().next().unwrap_or_else(|| tr("没有可抽取的部门！").to_string())
             }
-            DrawType::Both => {
-                // 综合类：同时抽取承压和机电部门（两个转盘独立）
-                let p_depts = get_pressure_depts(&self.current_round_pressure_depts);
-                let m_depts = get_mechanical_depts(&self.current_round_mechanical_depts);
-                
-                if p_depts.is_empty() && m_depts.is_empty() {
-                    self.status_message = "没有可抽取的部门！".to_string();
-                    self.is_drawing = false;
-                    return;
-                }
-                
-                if !p_depts.is_empty() {
-                    self.pressure_animation.start(p_depts);
-                }
-                if !m_depts.is_empty() {
-                    self.mechanical_animation.start(m_depts);
-                }
-                self.current_drawing = None; // 表示同时抽取
-                self.status_message = "正在抽取部门...".to_string();
+            1 => trf("正在抽取{}部门...", &[&started[0]]),
+            _ => tr("正在抽取部门...").to_string(),
+        };
+    }
+    
+    /// 计算候选部门并启动对应专业的转盘
+    /// 
+    /// 中签结果由抽签引擎按随机种子决定，转盘停止时落在该结果上；
+    /// `size` 为本次抽取的部门数，从中签位置起连续的部门组成检查组。
+    /// 候选部门不足时返回错误说明
+    fn start_wheel(&mut self, index: usize, departments: &[Department], dept_id: &str, size: usize) -> Result<(), String> {
+        let specialty = &self.wheels[index].specialty;
+        // 需要排除：1.被检查的部门 2.检查组已有的部门 3.本轮已被抽中的部门 4.交叉回避的部门
+        let result = DrawEngine::get_candidate_departments(departments, dept_id, specialty, self.round_pairs(&specialty.id), &self.rules);
+        if result.departments.len() < size {
+            return Err(trf(
+                "{}需要抽取{}个部门，符合条件的候选部门只有{}个",
+                &[&tr(&specialty.name), &size, &result.departments.len()],
+            ));
+        }
+        
+        let candidates: Vec<String> = result.departments.iter().map(|d| d.name.clone()).collect();
+        let seed = rand::random::<u64>();
+        let (order, members) = DrawEngine::seeded_team_draw(&candidates, seed, size);
+        let context = DrawContext {
+            seed,
+            candidates,
+            rule_notes: result.rule_notes,
+        };
+        
+        let target = departments.iter().find(|d| d.id == dept_id).map_or(dept_id, |d| d.name.as_str());
+        logging::info("draw", "开始抽签", &[
+            ("target", &target),
+            ("specialty", &specialty.name),
+            ("round", &self.round_no),
+            ("seed", &seed),
+            ("size", &size),
+            ("candidates", &context.candidates.join("、")),
+        ]);
+        for note in &context.rule_notes {
+            logging::debug("draw", "规则排除", &[("target", &target), ("note", note)]);
+        }
+        
+        let wheel = &mut self.wheels[index];
+        wheel.animation.start(order, members[0], size, &self.animation);
+        wheel.context = Some(context);
+        Ok(())
+    }
+    
+    /// 作废记录后释放本轮对应的抽签位置，使被检部门可以重新抽取
+    pub fn release_voided(&mut self, record: &DrawRecord) {
+        if record.round != self.round_no {
+            return;
+        }
+        if let Some(pairs) = self.current_round.get_mut(&record.specialty_type) {
+            pairs.retain(|(target, selected)| {
+                !(target == &record.target_department_id && selected == &record.selected_from_department_id)
+            });
+        }
+        // 当前显示的正是被作废的结果时一并清除
+        if self.selected_department_id.as_ref() == Some(&record.target_department_id) {
+            if let Some(wheel) = self.wheels.iter_mut().find(|w| w.specialty.id == record.specialty_type) {
+                wheel.results.retain(|name| name != &record.selected_from_department_name);
             }
         }
     }
     
+    /// 重新抽取作废的位置（按本轮规则，只补抽尚未抽齐的专业和检查组成员）
+    pub fn redraw(
+        &mut self,
+        target_department_id: &str,
+        specialists: &[QualitySpecialist],
+        departments: &[Department],
+        specialties: &[Specialty],
+        records: &[DrawRecord],
+    ) {
+        if self.is_drawing || self.has_pending() {
+            self.status_message = tr("正在抽签或等待见证确认，请稍后再重新抽取").to_string();
+            return;
+        }
+        self.select_department(target_department_id, departments, specialties);
+        self.start_draw(specialists, departments, records);
+    }
+    
+    /// 生成投屏状态快照
+    pub fn live_snapshot(&self, organization: &Organization, departments: &[Department], specialties: &[Specialty]) -> LiveSnapshot {
+        let name = |id: &str| departments.iter()
+            .find(|d| d.id == id)
+            .map(|d| d.name.clone())
+            .unwrap_or_else(|| id.to_string());
+        
+        let wheels = self.wheels.iter()
+            .map(|wheel| WheelSnapshot {
+                specialty: wheel.specialty.id.clone(),
+                specialty_name: wheel.specialty.name.clone(),
+                phase: wheel.animation.phase.clone(),
+                candidates: wheel.animation.candidates.clone(),
+                current_index: wheel.animation.current_index(),
+                result: wheel.animation.final_result.clone(),
+                team: wheel.animation.final_team(),
+            })
+            .collect();
+        
+        let results = self.current_round.iter()
+            .flat_map(|(specialty, pairs)| pairs.iter().map(move |pair| (specialty, pair)))
+            .map(|(specialty, (target, selected))| RoundResult {
+                target: name(target),
+                specialty: specialty_name(specialties, specialty).to_string(),
+                selected: name(selected),
+            })
+            .collect();
+        
+        let pending = self.pending_records.iter()
+            .map(|r| RoundResult {
+                target: r.target_department_name.clone(),
+                specialty: r.specialty_label().to_string(),
+                selected: r.selected_from_department_name.clone(),
+            })
+            .collect();
+        
+        LiveSnapshot {
+            organization: organization.name.clone(),
+            round: self.round_no,
+            target_department: self.selected_department_id.as_deref().map(name),
+            wheels,
+            results,
+            pending,
+        }
+    }
+    
     /// 显示抽签结果（部门模式）
-    pub fn show_results(&self, ui: &mut egui::Ui, departments: &[Department]) {
-        if self.pressure_result.is_none() && self.mechanical_result.is_none() {
+    pub fn show_results(&self, ui: &mut egui::Ui, departments: &[Department], specialties: &[Specialty]) {
+        if self.wheels.iter().all(|w| w.results.is_empty()) {
             return;
         }
         
         let target_dept_name = self.selected_department_id.as_ref()
             .and_then(|id| departments.iter().find(|d| &d.id == id))
             .map(|d| d.name.as_str())
-            .unwrap_or("未知");
-        
-        let draw_type = self.get_draw_type(departments);
+            .unwrap_or(tr("未知"));
         
         ui.group(|ui| {
-            ui.heading(format!("📋 {} 抽签结果", target_dept_name));
+            ui.heading(trf("📋 {} 抽签结果", &[&target_dept_name]));
             ui.separator();
             
-            // 根据 DrawType 过滤显示
-            let show_pressure = matches!(draw_type, Some(DrawType::PressureOnly) | Some(DrawType::Both));
-            let show_mechanical = matches!(draw_type, Some(DrawType::MechanicalOnly) | Some(DrawType::Both));
-            
-            if show_pressure {
-                if let Some((dept_name, _)) = &self.pressure_result {
-                    ui.horizontal(|ui| {
-                        ui.label("承压类质量专责部门：");
-                        ui.label(egui::RichText::new(dept_name)
-                            .color(egui::Color32::from_rgb(50, 150, 250))
-                            .strong()
-                            .size(16.0));
-                    });
-                }
-            }
-            
-            if show_mechanical {
-                if let Some((dept_name, _)) = &self.mechanical_result {
+            for wheel in &self.wheels {
+                if !wheel.results.is_empty() {
                     ui.horizontal(|ui| {
-                        ui.label("机电类质量专责部门：");
-                        ui.label(egui::RichText::new(dept_name)
-                            .color(egui::Color32::from_rgb(50, 200, 100))
+                        ui.label(trf("{}质量专责部门：", &[&tr(&wheel.specialty.name)]));
+                        ui.label(egui::RichText::new(wheel.results.join(tr("、")))
+                            .color(specialty_color(specialties, &wheel.specialty.id))
                             .strong()
-                            .size(16.0));
+                            .size(16.0 * self.display.font_scale()));
                     });
                 }
             }
@@ -4,10 +4,40 @@ mod main_panel;
 mod animation;
 mod settings;
 mod history;
-mod export;
+mod print_dialog;
+mod login;
+mod audit;
+mod server_panel;
+mod presentation;
+mod witness;
+mod undo_dialog;
+mod organization;
+mod schedule;
+mod outcome;
+mod statistics;
+mod theme;
+mod sound;
+mod animation_settings;
+mod replay;
+mod log_viewer;
 
-pub use main_panel::MainPanel;
-pub use animation::AnimationState;
+pub use main_panel::{KeyCommand, MainPanel};
+pub use animation::{AnimationPhase, AnimationState};
 pub use settings::SettingsPanel;
-pub use history::HistoryPanel;
-pub use export::ExportManager;
+pub use history::{HistoryPanel, HistoryAction};
+pub use crate::export::{ExportManager, PrintOptions, PrintOutcome};
+pub use print_dialog::PrintDialog;
+pub use login::LoginPanel;
+pub use audit::AuditPanel;
+pub use server_panel::{ServerAction, ServerPanel};
+pub use presentation::PresentationView;
+pub use witness::{WitnessAction, WitnessDialog};
+pub use undo_dialog::UndoDialog;
+pub use organization::OrganizationPanel;
+pub use schedule::SchedulePanel;
+pub use outcome::OutcomePanel;
+pub use statistics::StatisticsPanel;
+pub use theme::apply_theme;
+pub use animation_settings::AnimationDialog;
+pub use replay::ReplayViewer;
+pub use log_viewer::LogViewer;
@@ -0,0 +1,49 @@
+//! 单位选择界面
+//!
+//! 配置了多个单位时，启动（或注销后切换单位）时选择要进入的单位。
+
+use eframe::egui;
+use crate::i18n::tr;
+use crate::models::Organization;
+
+/// 单位选择面板
+#[derive(Default)]
+pub struct OrganizationPanel {
+    /// 是否显示
+    pub visible: bool,
+}
+
+impl OrganizationPanel {
+    /// 显示单位选择界面，选择后返回单位ID
+    pub fn show(&mut self, ctx: &egui::Context, organizations: &[Organization]) -> Option<String> {
+        let mut selected = None;
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(80.0);
+                ui.heading(egui::RichText::new(tr("质量监督检查抽签程序"))
+                    .size(24.0)
+                    .color(egui::Color32::from_rgb(50, 100, 180)));
+                ui.add_space(30.0);
+                ui.label(egui::RichText::new(tr("请选择单位")).size(16.0));
+                ui.add_space(15.0);
+
+                egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                    for organization in organizations {
+                        if ui.add_sized([260.0, 40.0], egui::Button::new(
+                            egui::RichText::new(format!("🏛 {}", organization.name)).size(16.0)
+                        )).clicked() {
+                            selected = Some(organization.id.clone());
+                        }
+                        ui.add_space(8.0);
+                    }
+                });
+            });
+        });
+
+        if selected.is_some() {
+            self.visible = false;
+        }
+        selected
+    }
+}
@@ -0,0 +1,344 @@
+//! 检查结果跟踪界面
+//!
+//! 默认显示未完成检查的看板，可登记检查进度、问题数、评分、报告和结案日期，并导出年度质量报告。
+
+use chrono::{Datelike, NaiveDate};
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::export::ExportManager;
+use crate::logic::{OutcomeItem, OutcomeManager};
+use crate::models::{
+    AuditAction, AuditEntry, ChangeStamp, DrawRecord, FindingCounts, InspectionOutcome, InspectionStatus, Operator,
+    Organization, ScheduleEntry,
+};
+use crate::storage::DataStore;
+
+/// 检查结果登记表单
+struct OutcomeForm {
+    record_id: String,
+    status: InspectionStatus,
+    findings: FindingCounts,
+    /// 评分（留空表示未评分）
+    score: String,
+    report_path: String,
+    /// 结案日期（YYYY-MM-DD）
+    closed_on: String,
+}
+
+impl OutcomeForm {
+    fn new(record_id: &str, outcome: Option<&InspectionOutcome>) -> Self {
+        Self {
+            record_id: record_id.to_string(),
+            status: outcome.map(|o| o.status).unwrap_or_default(),
+            findings: outcome.map(|o| o.findings).unwrap_or_default(),
+            score: outcome.and_then(|o| o.score).map(|s| s.to_string()).unwrap_or_default(),
+            report_path: outcome.and_then(|o| o.report_path.clone()).unwrap_or_default(),
+            closed_on: outcome.and_then(|o| o.closed_on).map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default(),
+        }
+    }
+
+    /// 转换为检查结果
+    fn to_outcome(&self) -> Result<InspectionOutcome, String> {
+        let mut outcome = InspectionOutcome::new(&self.record_id);
+        outcome.status = self.status;
+        outcome.findings = self.findings;
+        outcome.score = match self.score.trim() {
+            "" => None,
+            score => Some(score.parse().map_err(|_| tr("评分应为数字").to_string())?),
+        };
+        let report_path = self.report_path.trim();
+        outcome.report_path = (!report_path.is_empty()).then(|| report_path.to_string());
+        outcome.closed_on = match self.closed_on.trim() {
+            "" => None,
+            date => Some(NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| "结案日期格式应为 YYYY-MM-DD".to_string())?),
+        };
+        outcome.validate()?;
+        Ok(outcome)
+    }
+}
+
+/// 登记表单中的操作
+enum FormAction {
+    Save,
+    Cancel,
+}
+
+/// 检查结果面板
+pub struct OutcomePanel {
+    /// 是否显示
+    pub visible: bool,
+    /// 显示全年检查（否则只显示未完成的检查）
+    show_all: bool,
+    /// 年度报告年份
+    year: i32,
+    /// 已加载的检查结果
+    outcomes: Vec<InspectionOutcome>,
+    /// 已加载的检查日程（看板中显示检查日期）
+    schedule: Vec<ScheduleEntry>,
+    /// 正在登记的表单
+    form: Option<OutcomeForm>,
+    /// 提示消息
+    message: Option<String>,
+}
+
+impl Default for OutcomePanel {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            show_all: false,
+            year: chrono::Local::now().year(),
+            outcomes: Vec::new(),
+            schedule: Vec::new(),
+            form: None,
+            message: None,
+        }
+    }
+}
+
+impl OutcomePanel {
+    /// 打开面板并重新加载检查结果
+    pub fn open(&mut self, store: &DataStore) {
+        self.outcomes = store.load_outcomes();
+        self.schedule = store.load_schedule();
+        self.form = None;
+        self.message = None;
+        self.visible = true;
+    }
+
+    /// 显示检查结果面板（观察员只能查看和导出）
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        records: &[DrawRecord],
+        organization: &Organization,
+        store: &DataStore,
+        operator: &Operator,
+    ) {
+        if !self.visible {
+            return;
+        }
+
+        let mut open = self.visible;
+        let can_edit = operator.role.can_draw();
+        let outcomes = self.outcomes.clone();
+        let open_items = OutcomeManager::open_items(records, &outcomes);
+        let year_items = OutcomeManager::year_items(records, &outcomes, self.year);
+        let mut years = OutcomeManager::years(records);
+        if !years.contains(&self.year) {
+            years.insert(0, self.year);
+        }
+        let mut edit = None;
+        let mut form_action = None;
+
+        egui::Window::new(tr("📋 检查结果跟踪"))
+            .open(&mut open)
+            .default_width(960.0)
+            .default_height(560.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                // 看板：未完成检查数量
+                let in_progress = open_items.iter().filter(|i| i.status() == InspectionStatus::InProgress).count();
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(trf("未完成检查 {} 项", &[&open_items.len()])).size(16.0).strong());
+                    ui.label(trf("（计划中 {} / 检查中 {}）", &[&(open_items.len() - in_progress), &in_progress]));
+                    ui.separator();
+                    ui.checkbox(&mut self.show_all, tr("显示全年检查"));
+                    ui.separator();
+                    ui.label(tr("年度:"));
+                    egui::ComboBox::from_id_salt("outcome_year")
+                        .selected_text(trf("{}年", &[&self.year]))
+                        .show_ui(ui, |ui| {
+                            for year in &years {
+                                ui.selectable_value(&mut self.year, *year, trf("{}年", &[&year]));
+                            }
+                        });
+                    if ui.add_enabled(!year_items.is_empty(), egui::Button::new(tr("📊 导出年度报告"))).clicked() {
+                        self.message = Some(self.export(&year_items, organization));
+                    }
+                });
+                if let Some(msg) = &self.message {
+                    ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+                }
+                ui.separator();
+
+                let items = if self.show_all { &year_items } else { &open_items };
+                if items.is_empty() {
+                    let hint = if self.show_all { tr("该年度没有有效的抽签记录") } else { tr("没有未完成的检查") };
+                    ui.label(egui::RichText::new(hint).color(egui::Color32::GRAY));
+                }
+
+                let grid_height = if self.form.is_some() { ui.available_height() - 150.0 } else { ui.available_height() };
+                egui::ScrollArea::vertical().max_height(grid_height.max(120.0)).show(ui, |ui| {
+                    egui::Grid::new("outcome_grid")
+                        .num_columns(10)
+                        .striped(true)
+                        .spacing([14.0, 6.0])
+                        .show(ui, |ui| {
+                            for header in [tr("轮次"), tr("被检查部门"), tr("专业"), tr("检查部门"), tr("检查日期"), tr("状态"), tr("问题（严重/一般/轻微）"), tr("评分"), tr("结案日期"), ""] {
+                                ui.strong(header);
+                            }
+                            ui.end_row();
+
+                            for item in items {
+                                if self.show_row(ui, item, can_edit) {
+                                    edit = Some(*item);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                if let Some(form) = &mut self.form {
+                    ui.separator();
+                    if let Some(record) = records.iter().find(|r| r.id == form.record_id) {
+                        ui.label(egui::RichText::new(trf(
+                            "登记检查结果：第{}轮 {} {} → {}",
+                            &[&record.round, &record.target_department_name, &record.specialty_label(), &record.selected_from_department_name],
+                        )).strong());
+                    }
+                    form_action = Self::show_form(ui, form);
+                }
+            });
+
+        if let Some(item) = edit {
+            self.form = Some(OutcomeForm::new(&item.record.id, item.outcome));
+            self.message = None;
+        }
+        match form_action {
+            Some(FormAction::Save) => self.save(records, store, operator),
+            Some(FormAction::Cancel) => self.form = None,
+            None => {}
+        }
+
+        self.visible = open;
+    }
+
+    /// 显示一行检查，返回是否点击了登记
+    fn show_row(&self, ui: &mut egui::Ui, item: &OutcomeItem, can_edit: bool) -> bool {
+        let record = item.record;
+        let outcome = item.outcome;
+        let status = item.status();
+        let status_color = match status {
+            InspectionStatus::Planned => egui::Color32::GRAY,
+            InspectionStatus::InProgress => egui::Color32::from_rgb(230, 160, 40),
+            InspectionStatus::Done => egui::Color32::from_rgb(60, 170, 90),
+        };
+
+        ui.label(record.round.to_string());
+        ui.label(&record.target_department_name);
+        ui.label(record.specialty_label());
+        ui.label(&record.selected_from_department_name);
+        ui.label(self.schedule.iter()
+            .find(|e| e.record_id == record.id)
+            .map(|e| format!("{} {}", e.date.format("%Y-%m-%d"), e.slot.display_name()))
+            .unwrap_or_else(|| tr("未安排").to_string()));
+        ui.label(egui::RichText::new(status.display_name()).color(status_color));
+        let findings = outcome.map(|o| o.findings).unwrap_or_default();
+        ui.label(format!("{} / {} / {}", findings.major, findings.general, findings.minor));
+        ui.label(outcome.and_then(|o| o.score).map(|s| format!("{:.1}", s)).unwrap_or_default());
+        ui.label(outcome.and_then(|o| o.closed_on).map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default());
+        ui.add_enabled(can_edit && self.form.is_none(), egui::Button::new(tr("✏ 登记"))).clicked()
+    }
+
+    /// 显示登记表单，返回点击的操作
+    fn show_form(ui: &mut egui::Ui, form: &mut OutcomeForm) -> Option<FormAction> {
+        let mut action = None;
+        egui::Grid::new("outcome_form").num_columns(2).spacing([10.0, 6.0]).show(ui, |ui| {
+            ui.label(tr("状态:"));
+            ui.horizontal(|ui| {
+                for status in InspectionStatus::all() {
+                    if ui.selectable_value(&mut form.status, status, status.display_name()).clicked()
+                        && status == InspectionStatus::Done
+                        && form.closed_on.trim().is_empty()
+                    {
+                        form.closed_on = chrono::Local::now().format("%Y-%m-%d").to_string();
+                    }
+                }
+            });
+            ui.end_row();
+
+            ui.label(tr("问题数:"));
+            ui.horizontal(|ui| {
+                ui.label(tr("严重"));
+                ui.add(egui::DragValue::new(&mut form.findings.major).range(0..=999));
+                ui.label(tr("一般"));
+                ui.add(egui::DragValue::new(&mut form.findings.general).range(0..=999));
+                ui.label(tr("轻微"));
+                ui.add(egui::DragValue::new(&mut form.findings.minor).range(0..=999));
+            });
+            ui.end_row();
+
+            ui.label(tr("评分:"));
+            ui.add(egui::TextEdit::singleline(&mut form.score).hint_text(tr("0~100，可留空")).desired_width(100.0));
+            ui.end_row();
+
+            ui.label(tr("检查报告:"));
+            ui.add(egui::TextEdit::singleline(&mut form.report_path).hint_text(tr("报告文件路径")).desired_width(360.0));
+            ui.end_row();
+
+            ui.label(tr("结案日期:"));
+            ui.add(egui::TextEdit::singleline(&mut form.closed_on).hint_text(tr("已完成时填写，如 2026-11-20")).desired_width(100.0));
+            ui.end_row();
+        });
+        ui.horizontal(|ui| {
+            if ui.button(tr("💾 保存")).clicked() {
+                action = Some(FormAction::Save);
+            }
+            if ui.button(tr("取消")).clicked() {
+                action = Some(FormAction::Cancel);
+            }
+        });
+        action
+    }
+
+    /// 保存表单，写入审计日志
+    fn save(&mut self, records: &[DrawRecord], store: &DataStore, operator: &Operator) {
+        let Some(form) = &self.form else {
+            return;
+        };
+        let Some(record) = records.iter().find(|r| r.id == form.record_id) else {
+            return;
+        };
+        let outcome = match form.to_outcome() {
+            Ok(outcome) => outcome.stamped(ChangeStamp::now(operator)),
+            Err(e) => {
+                self.message = Some(e);
+                return;
+            }
+        };
+
+        let old = store.set_outcome(outcome.clone());
+        let mut audit = AuditEntry::new(
+            operator,
+            AuditAction::UpdateOutcome,
+            trf(
+                "第{}轮 {} {} → {}：{}",
+                &[&record.round, &record.target_department_name, &record.specialty_label(), &record.selected_from_department_name, &outcome.status.display_name()],
+            ),
+        ).with_new(&outcome);
+        if let Some(old) = &old {
+            audit = audit.with_old(old);
+        }
+        store.append_audit(&audit);
+
+        self.outcomes = store.load_outcomes();
+        self.form = None;
+        self.message = Some(tr("已保存检查结果").to_string());
+    }
+
+    /// 导出年度质量报告到桌面，返回提示消息
+    fn export(&self, items: &[OutcomeItem], organization: &Organization) -> String {
+        let filename = trf(
+            "年度质量报告_{}_{}.xlsx",
+            &[&self.year, &chrono::Local::now().format("%Y%m%d_%H%M%S")],
+        );
+        let desktop = dirs::desktop_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
+        let path = desktop.join(&filename);
+
+        let summaries = OutcomeManager::summarize(items);
+        match ExportManager::export_annual_report(items, &summaries, organization, self.year, &path) {
+            Ok(_) => trf("已导出到: {}", &[&path.display()]),
+            Err(e) => trf("导出失败: {}", &[&e]),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! 投影模式 - 第二个无边框全屏窗口
+//!
+//! 只显示大转盘、被检部门和本轮结果，操作员窗口继续保留部门选择和控制按钮。
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::models::{Department, Organization, Specialty};
+use super::main_panel::{KeyCommand, MainPanel, specialty_color};
+use super::theme::Palette;
+
+/// 投影窗口
+#[derive(Default)]
+pub struct PresentationView {
+    /// 是否显示
+    pub visible: bool,
+}
+
+impl PresentationView {
+    /// 显示投影窗口（Esc 关闭，F11 切换全屏）
+    /// 
+    /// 返回投影窗口中按下的抽签快捷键，由主窗口统一执行
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        main_panel: &mut MainPanel,
+        organization: &Organization,
+        departments: &[Department],
+        specialties: &[Specialty],
+    ) -> Option<KeyCommand> {
+        if !self.visible {
+            return None;
+        }
+        let palette = Palette::of(main_panel.display);
+        let mut command = None;
+
+        let viewport_id = egui::ViewportId::from_hash_of("presentation");
+        let builder = egui::ViewportBuilder::default()
+            .with_title(tr("质量监督检查抽签 - 投影"))
+            .with_decorations(false)
+            .with_fullscreen(true)
+            .with_min_inner_size([800.0, 600.0]);
+
+        ctx.show_viewport_immediate(viewport_id, builder, |ctx, class| {
+            if class == egui::ViewportClass::Embedded {
+                // 不支持多窗口的环境下退化为应用内窗口
+                egui::Window::new(tr("🖥 投影"))
+                    .default_size([900.0, 700.0])
+                    .show(ctx, |ui| Self::show_content(ui, main_panel, organization, departments, specialties));
+                return;
+            }
+
+            command = KeyCommand::read(ctx);
+            egui::CentralPanel::default()
+                .frame(egui::Frame::none().fill(palette.stage_background))
+                .show(ctx, |ui| {
+                    // 非全屏时拖动背景移动窗口（便于拖到投影屏幕后再全屏）
+                    let background = ui.interact(ui.max_rect(), ui.id().with("drag"), egui::Sense::drag());
+                    if background.drag_started() {
+                        ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                    }
+                    Self::show_content(ui, main_panel, organization, departments, specialties);
+                });
+
+            let (close, toggle_fullscreen, fullscreen) = ctx.input(|i| (
+                i.key_pressed(egui::Key::Escape) || i.viewport().close_requested(),
+                i.key_pressed(egui::Key::F11),
+                i.viewport().fullscreen.unwrap_or(false),
+            ));
+            if toggle_fullscreen {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Fullscreen(!fullscreen));
+            }
+            if close {
+                self.visible = false;
+            }
+        });
+        command
+    }
+
+    /// 投影内容：被检部门、大转盘、本轮结果
+    fn show_content(
+        ui: &mut egui::Ui,
+        main_panel: &mut MainPanel,
+        organization: &Organization,
+        departments: &[Department],
+        specialties: &[Specialty],
+    ) {
+        let snapshot = main_panel.live_snapshot(organization, departments, specialties);
+        let palette = Palette::of(main_panel.display);
+        let scale = palette.font_scale;
+
+        ui.vertical_centered(|ui| {
+            ui.add_space(20.0);
+            ui.label(egui::RichText::new(trf("{}质量监督检查抽签", &[&snapshot.organization]))
+                .size(36.0 * scale)
+                .strong()
+                .color(palette.stage_title));
+            ui.add_space(10.0);
+            let target = snapshot.target_department.as_deref().unwrap_or(tr("等待选择被检查部门"));
+            ui.label(egui::RichText::new(trf("第 {} 轮　被检查部门：{}", &[&snapshot.round, &target]))
+                .size(28.0 * scale)
+                .color(palette.stage_target));
+            ui.add_space(20.0);
+
+            // 为结果表留出空间，其余高度给转盘
+            let wheel_radius = ((ui.available_height() - 260.0) / 2.0).max(140.0);
+            main_panel.show_wheels(ui, wheel_radius);
+
+            if !snapshot.results.is_empty() {
+                ui.add_space(20.0);
+                ui.label(egui::RichText::new(trf("第 {} 轮抽签结果", &[&snapshot.round]))
+                    .size(22.0 * scale)
+                    .color(palette.stage_caption));
+                ui.add_space(8.0);
+                egui::ScrollArea::vertical().max_height(180.0).show(ui, |ui| {
+                    egui::Grid::new("presentation_results")
+                        .num_columns(3)
+                        .spacing([40.0, 8.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for result in &snapshot.results {
+                                let color = specialties.iter()
+                                    .find(|s| s.name == result.specialty)
+                                    .map_or(egui::Color32::LIGHT_GRAY, |s| specialty_color(specialties, &s.id));
+                                ui.label(egui::RichText::new(&result.target).size(20.0 * scale));
+                                ui.label(egui::RichText::new(&result.specialty).size(20.0 * scale).color(color));
+                                ui.label(egui::RichText::new(format!("→ {}", result.selected)).size(20.0 * scale).strong());
+                                ui.end_row();
+                            }
+                        });
+                });
+            }
+        });
+    }
+}
@@ -0,0 +1,97 @@
+//! 打印设置对话框
+
+use eframe::egui;
+use crate::i18n::tr;
+use crate::export::{ExportManager, PrintOptions};
+
+/// 打印设置对话框
+#[derive(Default)]
+pub struct PrintDialog {
+    /// 是否显示
+    pub visible: bool,
+    /// 打印选项
+    pub options: PrintOptions,
+    /// 可用打印机列表
+    printers: Vec<String>,
+}
+
+impl PrintDialog {
+    /// 打开对话框并刷新打印机列表
+    pub fn open(&mut self) {
+        self.printers = ExportManager::list_printers();
+        // 已选打印机不存在时回到默认打印机
+        if let Some(printer) = &self.options.printer {
+            if !self.printers.contains(printer) {
+                self.options.printer = None;
+            }
+        }
+        self.visible = true;
+    }
+
+    /// 显示对话框，点击"打印"时返回打印选项
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<PrintOptions> {
+        if !self.visible {
+            return None;
+        }
+
+        let mut open = self.visible;
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new(tr("🖨 打印"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                egui::Grid::new("print_options_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(tr("打印机:"));
+                        if self.printers.is_empty() {
+                            ui.label(egui::RichText::new(tr("在浏览器打印对话框中选择")).color(egui::Color32::GRAY));
+                        } else {
+                            egui::ComboBox::from_id_salt("print_printer")
+                                .selected_text(self.options.printer.as_deref().unwrap_or(tr("默认打印机")))
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut self.options.printer, None, tr("默认打印机"));
+                                    for printer in &self.printers {
+                                        ui.selectable_value(
+                                            &mut self.options.printer,
+                                            Some(printer.clone()),
+                                            printer,
+                                        );
+                                    }
+                                });
+                        }
+                        ui.end_row();
+
+                        ui.label(tr("份数:"));
+                        ui.add_enabled(
+                            !self.printers.is_empty(),
+                            egui::DragValue::new(&mut self.options.copies).range(1..=99),
+                        );
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button(tr("🖨 打印")).clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button(tr("取消")).clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+
+        self.visible = open && !confirmed && !cancelled;
+
+        if confirmed {
+            Some(self.options.clone())
+        } else {
+            None
+        }
+    }
+}
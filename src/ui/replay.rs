@@ -0,0 +1,94 @@
+//! 转盘回放 - 按记录保存的回放数据重新播放抽签时的转盘动画
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::logic::DrawEngine;
+use crate::models::{DisplaySettings, DrawRecord};
+use super::animation::AnimationState;
+use super::main_panel::MainPanel;
+
+/// 转盘回放窗口
+#[derive(Default)]
+pub struct ReplayViewer {
+    /// 是否显示
+    pub visible: bool,
+    /// 正在回放的记录
+    record: Option<DrawRecord>,
+    /// 回放中的转盘
+    animation: AnimationState,
+}
+
+impl ReplayViewer {
+    /// 打开回放窗口并开始播放（记录没有回放数据时不打开）
+    pub fn open(&mut self, record: &DrawRecord) {
+        let Some(replay) = &record.replay else {
+            return;
+        };
+        self.animation = AnimationState::default();
+        self.animation.start_replay(replay);
+        self.record = Some(record.clone());
+        self.visible = true;
+    }
+
+    /// 显示回放窗口
+    pub fn show(&mut self, ctx: &egui::Context, display: DisplaySettings) {
+        if !self.visible {
+            return;
+        }
+        let Some(record) = self.record.clone() else {
+            self.visible = false;
+            return;
+        };
+
+        if self.animation.update() {
+            ctx.request_repaint();
+        }
+
+        let mut open = self.visible;
+        egui::Window::new(tr("🎬 转盘回放"))
+            .open(&mut open)
+            .default_width(460.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "{}  {}  {}",
+                    record.timestamp.format("%Y-%m-%d %H:%M:%S"),
+                    record.target_department_name,
+                    record.specialty_label(),
+                ));
+                match DrawEngine::verify_replay(&record) {
+                    Some(true) => ui.label(egui::RichText::new(tr("✅ 回放与随机种子重算结果一致"))
+                        .color(egui::Color32::from_rgb(100, 200, 100))),
+                    Some(false) => ui.label(egui::RichText::new(tr("⚠ 回放与随机种子重算结果不一致"))
+                        .color(egui::Color32::RED)),
+                    None => ui.label(egui::RichText::new(tr("（该记录缺少随机种子或候选名单，无法复核）"))
+                        .color(egui::Color32::GRAY)),
+                };
+                ui.separator();
+
+                let title = match self.animation.team_size {
+                    1 => trf("{}抽选", &[&record.specialty_label()]),
+                    size => trf("{}抽选（{}个部门）", &[&record.specialty_label(), &size]),
+                };
+                let team = self.animation.final_team();
+                let result = (!team.is_empty())
+                    .then(|| (team.join("\n"), record.specialty_label().to_string()));
+                MainPanel::show_single_animation(ui, display, &title, &self.animation, result, 180.0);
+
+                ui.add_space(8.0);
+                ui.vertical_centered(|ui| {
+                    let running = self.animation.is_running();
+                    if ui.add_enabled(!running, egui::Button::new(tr("🔁 重新播放"))).clicked() {
+                        if let Some(replay) = &record.replay {
+                            self.animation.start_replay(replay);
+                        }
+                    }
+                });
+            });
+
+        if !open {
+            self.visible = false;
+            self.record = None;
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! 检查日程安排界面
+
+use chrono::NaiveDate;
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::export::ExportManager;
+use crate::logic::{ScheduleItem, ScheduleManager};
+use crate::models::{AuditAction, AuditEntry, ChangeStamp, DrawRecord, Operator, Organization, ScheduleEntry, TimeSlot};
+use crate::storage::DataStore;
+
+/// 检查日程安排面板
+pub struct SchedulePanel {
+    /// 是否显示
+    pub visible: bool,
+    /// 当前查看的轮次
+    round: u32,
+    /// 已加载的日程安排
+    schedule: Vec<ScheduleEntry>,
+    /// 正在安排的记录ID
+    editing_id: Option<String>,
+    /// 修改中的日期（YYYY-MM-DD）
+    edit_date: String,
+    /// 修改中的时段
+    edit_slot: TimeSlot,
+    /// 修改中的备注
+    edit_note: String,
+    /// 提示消息
+    message: Option<String>,
+}
+
+impl Default for SchedulePanel {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            round: 0,
+            schedule: Vec::new(),
+            editing_id: None,
+            edit_date: String::new(),
+            edit_slot: TimeSlot::Morning,
+            edit_note: String::new(),
+            message: None,
+        }
+    }
+}
+
+/// 表格中的操作（显示完表格后统一处理）
+enum ScheduleAction {
+    Edit(String),
+    Save(String),
+    Remove(String),
+    Cancel,
+}
+
+impl SchedulePanel {
+    /// 打开面板并重新加载日程，默认查看指定轮次（该轮没有记录时查看最近有记录的一轮）
+    pub fn open(&mut self, store: &DataStore, records: &[DrawRecord], round: u32) {
+        let rounds = Self::rounds(records);
+        self.round = if rounds.contains(&round) {
+            round
+        } else {
+            rounds.last().copied().unwrap_or(round)
+        };
+        self.schedule = store.load_schedule();
+        self.editing_id = None;
+        self.message = None;
+        self.visible = true;
+    }
+
+    /// 有有效记录的轮次
+    fn rounds(records: &[DrawRecord]) -> Vec<u32> {
+        let mut rounds: Vec<u32> = records.iter().filter(|r| !r.is_voided()).map(|r| r.round).collect();
+        rounds.sort_unstable();
+        rounds.dedup();
+        rounds
+    }
+
+    /// 显示检查日程安排面板（观察员只能查看和导出）
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        records: &[DrawRecord],
+        organization: &Organization,
+        store: &DataStore,
+        operator: &Operator,
+    ) {
+        if !self.visible {
+            return;
+        }
+
+        let mut open = self.visible;
+        let can_edit = operator.role.can_draw();
+        let schedule = self.schedule.clone();
+        let items = ScheduleManager::round_items(records, &schedule, self.round);
+        let conflicts = ScheduleManager::conflicts(records, &schedule);
+        let mut action = None;
+
+        egui::Window::new(tr("📅 检查日程安排"))
+            .open(&mut open)
+            .default_width(880.0)
+            .default_height(520.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("轮次:"));
+                    egui::ComboBox::from_id_salt("schedule_round")
+                        .selected_text(trf("第{}轮", &[&self.round]))
+                        .show_ui(ui, |ui| {
+                            for round in Self::rounds(records) {
+                                ui.selectable_value(&mut self.round, round, trf("第{}轮", &[&round]));
+                            }
+                        });
+
+                    let scheduled = items.iter().filter(|i| i.entry.is_some()).count();
+                    ui.label(trf("已安排 {} / 共 {} 项", &[&scheduled, &items.len()]));
+
+                    ui.separator();
+                    if ui.add_enabled(scheduled > 0, egui::Button::new(tr("📅 导出日历 (.ics)"))).clicked() {
+                        self.message = Some(self.export(&items, &conflicts, organization, "ics"));
+                    }
+                    if ui.add_enabled(!items.is_empty(), egui::Button::new(tr("📊 导出安排表"))).clicked() {
+                        self.message = Some(self.export(&items, &conflicts, organization, "xlsx"));
+                    }
+                });
+                if let Some(msg) = &self.message {
+                    ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+                }
+
+                // 本轮涉及的冲突
+                let round_conflicts: Vec<_> = conflicts.iter()
+                    .filter(|c| items.iter().any(|i| c.record_ids.contains(&i.record.id)))
+                    .collect();
+                if !round_conflicts.is_empty() {
+                    ui.add_space(5.0);
+                    for conflict in &round_conflicts {
+                        ui.label(egui::RichText::new(trf("⚠ 冲突：{}", &[&conflict.description()]))
+                            .color(egui::Color32::from_rgb(230, 80, 80)));
+                    }
+                }
+
+                ui.separator();
+
+                if items.is_empty() {
+                    ui.label(egui::RichText::new(tr("该轮没有有效的抽签记录")).color(egui::Color32::GRAY));
+                    return;
+                }
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    egui::Grid::new("schedule_grid")
+                        .num_columns(7)
+                        .striped(true)
+                        .spacing([16.0, 6.0])
+                        .show(ui, |ui| {
+                            for header in [tr("被检查部门"), tr("专业"), tr("检查部门"), tr("检查日期"), tr("时段"), tr("备注"), ""] {
+                                ui.strong(header);
+                            }
+                            ui.end_row();
+
+                            for item in &items {
+                                let conflicting = round_conflicts.iter().any(|c| c.record_ids.contains(&item.record.id));
+                                if let Some(next) = self.show_row(ui, item, conflicting, can_edit) {
+                                    action = Some(next);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+
+        match action {
+            Some(ScheduleAction::Edit(record_id)) => {
+                let entry = schedule.iter().find(|e| e.record_id == record_id);
+                self.edit_date = entry
+                    .map(|e| e.date)
+                    .unwrap_or_else(|| chrono::Local::now().date_naive())
+                    .format("%Y-%m-%d")
+                    .to_string();
+                self.edit_slot = entry.map(|e| e.slot).unwrap_or(TimeSlot::Morning);
+                self.edit_note = entry.map(|e| e.note.clone()).unwrap_or_default();
+                self.editing_id = Some(record_id);
+                self.message = None;
+            }
+            Some(ScheduleAction::Save(record_id)) => {
+                if let Some(record) = records.iter().find(|r| r.id == record_id) {
+                    self.save(record, records, store, operator);
+                }
+            }
+            Some(ScheduleAction::Remove(record_id)) => {
+                if let (Some(record), Some(removed)) = (records.iter().find(|r| r.id == record_id), store.remove_schedule(&record_id)) {
+                    store.append_audit(&AuditEntry::new(
+                        operator,
+                        AuditAction::UpdateSchedule,
+                        trf("取消安排：{}", &[&Self::record_label(record)]),
+                    ).with_old(&removed));
+                    self.schedule = store.load_schedule();
+                    self.message = Some(tr("已取消安排").to_string());
+                }
+            }
+            Some(ScheduleAction::Cancel) => self.editing_id = None,
+            None => {}
+        }
+
+        self.visible = open;
+    }
+
+    /// 显示一行安排，返回点击的操作
+    fn show_row(&mut self, ui: &mut egui::Ui, item: &ScheduleItem, conflicting: bool, can_edit: bool) -> Option<ScheduleAction> {
+        let record = item.record;
+        let mut action = None;
+        let text = |value: String| {
+            let text = egui::RichText::new(value);
+            if conflicting { text.color(egui::Color32::from_rgb(230, 80, 80)) } else { text }
+        };
+
+        ui.label(text(record.target_department_name.clone()));
+        ui.label(text(record.specialty_label().to_string()));
+        ui.label(text(record.selected_from_department_name.clone()));
+
+        if self.editing_id.as_ref() == Some(&record.id) {
+            ui.add(egui::TextEdit::singleline(&mut self.edit_date).hint_text("2026-11-03").desired_width(90.0));
+            egui::ComboBox::from_id_salt(("schedule_slot", &record.id))
+                .selected_text(self.edit_slot.display_name())
+                .width(60.0)
+                .show_ui(ui, |ui| {
+                    for slot in TimeSlot::all() {
+                        ui.selectable_value(&mut self.edit_slot, slot, slot.display_name());
+                    }
+                });
+            ui.add(egui::TextEdit::singleline(&mut self.edit_note).hint_text(tr("带队人员、集合地点等")).desired_width(160.0));
+            ui.horizontal(|ui| {
+                if ui.button(tr("保存")).clicked() {
+                    action = Some(ScheduleAction::Save(record.id.clone()));
+                }
+                if ui.button(tr("取消")).clicked() {
+                    action = Some(ScheduleAction::Cancel);
+                }
+            });
+            return action;
+        }
+
+        match item.entry {
+            Some(entry) => {
+                ui.label(text(entry.date.format("%Y-%m-%d").to_string()));
+                ui.label(text(entry.slot.display_name().to_string()));
+                ui.label(text(entry.note.clone()));
+            }
+            None => {
+                ui.label(egui::RichText::new(tr("未安排")).color(egui::Color32::GRAY));
+                ui.label("");
+                ui.label("");
+            }
+        }
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(can_edit && self.editing_id.is_none(), |ui| {
+                if ui.button(tr("✏ 安排")).clicked() {
+                    action = Some(ScheduleAction::Edit(record.id.clone()));
+                }
+                if item.entry.is_some() && ui.button("✖").on_hover_text(tr("取消安排")).clicked() {
+                    action = Some(ScheduleAction::Remove(record.id.clone()));
+                }
+            });
+        });
+        action
+    }
+
+    /// 保存正在修改的安排，写入审计日志并提示新出现的冲突
+    fn save(&mut self, record: &DrawRecord, records: &[DrawRecord], store: &DataStore, operator: &Operator) {
+        let date = match NaiveDate::parse_from_str(self.edit_date.trim(), "%Y-%m-%d") {
+            Ok(date) => date,
+            Err(_) => {
+                self.message = Some(tr("日期格式应为 YYYY-MM-DD").to_string());
+                return;
+            }
+        };
+        let mut entry = ScheduleEntry::new(&record.id, date, self.edit_slot).stamped(ChangeStamp::now(operator));
+        entry.note = self.edit_note.trim().to_string();
+
+        let old = store.set_schedule(entry.clone());
+        let mut audit = AuditEntry::new(
+            operator,
+            AuditAction::UpdateSchedule,
+            format!("{}：{} {}", Self::record_label(record), date.format("%Y-%m-%d"), self.edit_slot.display_name()),
+        ).with_new(&entry);
+        if let Some(old) = &old {
+            audit = audit.with_old(old);
+        }
+        store.append_audit(&audit);
+
+        self.schedule = store.load_schedule();
+        self.editing_id = None;
+        let conflicts: Vec<String> = ScheduleManager::conflicts(records, &self.schedule)
+            .iter()
+            .filter(|c| c.record_ids.contains(&record.id))
+            .map(|c| c.description())
+            .collect();
+        self.message = Some(if conflicts.is_empty() {
+            tr("已保存安排").to_string()
+        } else {
+            trf("已保存，但存在冲突：{}", &[&conflicts.join(tr("；"))])
+        });
+    }
+
+    /// 审计日志中的记录描述
+    fn record_label(record: &DrawRecord) -> String {
+        trf(
+            "第{}轮 {} {} → {}",
+            &[&record.round, &record.target_department_name, &record.specialty_label(), &record.selected_from_department_name],
+        )
+    }
+
+    /// 导出到桌面，返回提示消息
+    fn export(
+        &self,
+        items: &[ScheduleItem],
+        conflicts: &[crate::logic::ScheduleConflict],
+        organization: &Organization,
+        extension: &str,
+    ) -> String {
+        let filename = trf(
+            "检查日程_第{}轮_{}.{}",
+            &[&self.round, &chrono::Local::now().format("%Y%m%d_%H%M%S"), &extension],
+        );
+        let desktop = dirs::desktop_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
+        let path = desktop.join(&filename);
+
+        let result = if extension == "ics" {
+            ExportManager::export_schedule_to_ics(items, organization, &path)
+        } else {
+            ExportManager::export_schedule_to_excel(items, conflicts, organization, &path)
+        };
+        match result {
+            Ok(_) => trf("已导出到: {}", &[&path.display()]),
+            Err(e) => trf("导出失败: {}", &[&e]),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! 投屏服务设置对话框
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::server::LiveServer;
+
+/// 投屏服务操作
+pub enum ServerAction {
+    /// 在指定地址启动服务
+    Start(SocketAddr),
+    /// 停止服务
+    Stop,
+}
+
+/// 投屏服务设置对话框
+pub struct ServerPanel {
+    /// 是否显示
+    pub visible: bool,
+    /// 端口
+    port: u16,
+    /// 允许局域网访问（否则只监听本机）
+    allow_lan: bool,
+}
+
+impl Default for ServerPanel {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            port: 8765,
+            allow_lan: true,
+        }
+    }
+}
+
+impl ServerPanel {
+    /// 显示对话框，点击启动或停止时返回对应操作
+    pub fn show(&mut self, ctx: &egui::Context, server: Option<&LiveServer>) -> Option<ServerAction> {
+        if !self.visible {
+            return None;
+        }
+
+        let mut open = self.visible;
+        let mut action = None;
+
+        egui::Window::new(tr("📡 投屏服务"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(tr("在投影仪或观察员电脑的浏览器中打开查看地址，即可实时观看转盘（只读）。"));
+                ui.add_space(8.0);
+
+                match server {
+                    Some(server) => {
+                        ui.label(egui::RichText::new(trf("● 运行中（{}）", &[&server.addr()]))
+                            .color(egui::Color32::from_rgb(100, 200, 100)));
+                        ui.add_space(5.0);
+                        for url in server.viewer_urls() {
+                            ui.horizontal(|ui| {
+                                ui.hyperlink_to(&url, &url);
+                                if ui.small_button(tr("📋 复制")).clicked() {
+                                    ctx.copy_text(url.clone());
+                                }
+                            });
+                        }
+                        ui.label(trf("已连接查看器: {}", &[&server.viewer_count()]));
+                        ui.add_space(10.0);
+                        if ui.button(tr("⏹ 停止服务")).clicked() {
+                            action = Some(ServerAction::Stop);
+                        }
+                    }
+                    None => {
+                        egui::Grid::new("server_options_grid")
+                            .num_columns(2)
+                            .spacing([12.0, 8.0])
+                            .show(ui, |ui| {
+                                ui.label(tr("端口:"));
+                                ui.add(egui::DragValue::new(&mut self.port).range(1024..=65535));
+                                ui.end_row();
+
+                                ui.label(tr("访问范围:"));
+                                ui.checkbox(&mut self.allow_lan, tr("允许局域网访问"));
+                                ui.end_row();
+                            });
+                        ui.add_space(10.0);
+                        if ui.button(tr("▶ 启动服务")).clicked() {
+                            let ip = if self.allow_lan {
+                                IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+                            } else {
+                                IpAddr::V4(Ipv4Addr::LOCALHOST)
+                            };
+                            action = Some(ServerAction::Start(SocketAddr::new(ip, self.port)));
+                        }
+                    }
+                }
+            });
+
+        self.visible = open;
+        action
+    }
+}
@@ -1,7 +1,10 @@
-//! 设置管理界面（专责管理、部门管理）
+//! 设置管理界面（专责管理、部门管理、专业管理、账户管理、单位信息）
 
+use std::collections::BTreeMap;
 use eframe::egui;
-use crate::models::{Department, DepartmentType, QualitySpecialist, SpecialtyType};
+use crate::i18n::{tr, trf};
+use crate::logic::{AuthManager, CatalogManager};
+use crate::models::{AuditAction, AuditEntry, ChangeStamp, Department, Operator, Organization, QualitySpecialist, Role, Specialty, specialty_name};
 use crate::storage::DataStore;
 
 /// 设置面板
@@ -13,10 +16,23 @@ pub struct SettingsPanel {
     /// 新增专责表单
     pub new_specialist_name: String,
     pub new_specialist_dept: String,
-    pub new_specialist_type: SpecialtyType,
+    pub new_specialist_type: String,
     /// 新增部门表单
     pub new_dept_name: String,
-    pub new_dept_type: DepartmentType,
+    pub new_dept_category: String,
+    pub new_dept_specialties: Vec<String>,
+    /// 正在修改专业的部门ID
+    pub editing_dept_id: Option<String>,
+    /// 修改中的需要抽取的专业
+    pub editing_needs: Vec<String>,
+    /// 修改中的可派出的专业
+    pub editing_supplies: Vec<String>,
+    /// 修改中的各专业检查组人数
+    pub editing_team_sizes: BTreeMap<String, usize>,
+    /// 新增专业表单
+    pub new_specialty_name: String,
+    /// 部门和专业管理提示消息
+    pub taxonomy_message: Option<String>,
     /// 错误提示消息
     pub error_message: Option<String>,
     
@@ -26,13 +42,43 @@ pub struct SettingsPanel {
     /// 部门筛选
     pub filter_dept: Option<String>,
     /// 专业筛选
-    pub filter_specialty: Option<SpecialtyType>,
+    pub filter_specialty: Option<String>,
+    
+    // --- 账户管理 ---
+    /// 新增账户表单
+    pub new_operator_username: String,
+    pub new_operator_name: String,
+    pub new_operator_role: Role,
+    pub new_operator_password: String,
+    /// 正在重设密码的账户ID
+    pub reset_password_id: Option<String>,
+    /// 重设的新密码
+    pub reset_password_text: String,
+    /// 账户管理提示消息
+    pub account_message: Option<String>,
+    
+    // --- 单位信息 ---
+    /// 修改中的单位配置（打开标签页时从当前单位复制）
+    pub organization_form: Option<Organization>,
+    /// 新增单位表单
+    pub new_org_id: String,
+    pub new_org_name: String,
+    /// 新单位首个管理员账户（登录名、姓名、密码、确认密码）
+    pub new_org_admin_username: String,
+    pub new_org_admin_name: String,
+    pub new_org_admin_password: String,
+    pub new_org_admin_confirm: String,
+    /// 单位信息提示消息
+    pub organization_message: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SettingsTab {
     Specialists,
     Departments,
+    Specialties,
+    Accounts,
+    Organization,
 }
 
 impl Default for SettingsPanel {
@@ -42,25 +88,53 @@ impl Default for SettingsPanel {
             current_tab: SettingsTab::Specialists,
             new_specialist_name: String::new(),
             new_specialist_dept: String::new(),
-            new_specialist_type: SpecialtyType::Pressure,
+            new_specialist_type: String::new(),
             new_dept_name: String::new(),
-            new_dept_type: DepartmentType::Comprehensive,
+            new_dept_category: "综合类".to_string(),
+            new_dept_specialties: Vec::new(),
+            editing_dept_id: None,
+            editing_needs: Vec::new(),
+            editing_supplies: Vec::new(),
+            editing_team_sizes: BTreeMap::new(),
+            new_specialty_name: String::new(),
+            taxonomy_message: None,
             error_message: None,
             search_text: String::new(),
             filter_dept: None,
             filter_specialty: None,
+            new_operator_username: String::new(),
+            new_operator_name: String::new(),
+            new_operator_role: Role::DrawOperator,
+            new_operator_password: String::new(),
+            reset_password_id: None,
+            reset_password_text: String::new(),
+            account_message: None,
+            organization_form: None,
+            new_org_id: String::new(),
+            new_org_name: String::new(),
+            new_org_admin_username: String::new(),
+            new_org_admin_name: String::new(),
+            new_org_admin_password: String::new(),
+            new_org_admin_confirm: String::new(),
+            organization_message: None,
         }
     }
 }
 
 impl SettingsPanel {
-    /// 显示设置面板
+    /// 显示设置面板（仅管理员可以打开）
+    #[allow(clippy::too_many_arguments)]
     pub fn show(
         &mut self,
         ctx: &egui::Context,
         specialists: &mut Vec<QualitySpecialist>,
         departments: &mut Vec<Department>,
+        specialties: &mut Vec<Specialty>,
+        operators: &mut Vec<Operator>,
+        organization: &mut Organization,
+        current_round: &BTreeMap<String, Vec<(String, String)>>,
         store: &DataStore,
+        operator: &Operator,
     ) {
         if !self.visible {
             return;
@@ -68,7 +142,7 @@ impl SettingsPanel {
         
         let mut open = self.visible;
         
-        egui::Window::new("⚙ 数据管理")
+        egui::Window::new(tr("⚙ 数据管理"))
             .open(&mut open)
             .default_width(600.0)
             .default_height(500.0)
@@ -76,12 +150,22 @@ impl SettingsPanel {
             .show(ctx, |ui| {
                 // 标签页选择
                 ui.horizontal(|ui| {
-                    if ui.selectable_label(self.current_tab == SettingsTab::Specialists, "👤 质量专责管理").clicked() {
+                    if ui.selectable_label(self.current_tab == SettingsTab::Specialists, tr("👤 质量专责管理")).clicked() {
                         self.current_tab = SettingsTab::Specialists;
                     }
-                    if ui.selectable_label(self.current_tab == SettingsTab::Departments, "🏢 部门管理").clicked() {
+                    if ui.selectable_label(self.current_tab == SettingsTab::Departments, tr("🏢 部门管理")).clicked() {
                         self.current_tab = SettingsTab::Departments;
                     }
+                    if ui.selectable_label(self.current_tab == SettingsTab::Specialties, tr("🧩 专业管理")).clicked() {
+                        self.current_tab = SettingsTab::Specialties;
+                    }
+                    if ui.selectable_label(self.current_tab == SettingsTab::Accounts, tr("🔑 账户管理")).clicked() {
+                        self.current_tab = SettingsTab::Accounts;
+                    }
+                    if ui.selectable_label(self.current_tab == SettingsTab::Organization, tr("🏛 单位信息")).clicked() {
+                        self.current_tab = SettingsTab::Organization;
+                        self.organization_form = None;
+                    }
                 });
                 
                 ui.separator();
@@ -99,17 +183,22 @@ impl SettingsPanel {
                             &mut self.filter_specialty,
                             specialists,
                             departments,
+                            specialties,
                             store,
+                            operator,
                         );
                     }
                     SettingsTab::Departments => {
-                        Self::show_departments_ui(
-                            ui,
-                            &mut self.new_dept_name,
-                            &mut self.new_dept_type,
-                            departments,
-                            store,
-                        );
+                        self.show_departments_ui(ui, departments, specialties, specialists, current_round, store, operator);
+                    }
+                    SettingsTab::Specialties => {
+                        self.show_specialties_ui(ui, specialties, departments, specialists, store, operator);
+                    }
+                    SettingsTab::Accounts => {
+                        self.show_accounts_ui(ui, operators, store, operator);
+                    }
+                    SettingsTab::Organization => {
+                        self.show_organization_ui(ui, organization, store, operator);
                     }
                 }
             });
@@ -122,29 +211,31 @@ impl SettingsPanel {
         ui: &mut egui::Ui,
         new_name: &mut String,
         new_dept: &mut String,
-        new_type: &mut SpecialtyType,
+        new_type: &mut String,
         error_message: &mut Option<String>,
         search_text: &mut String,
         filter_dept: &mut Option<String>,
-        filter_specialty: &mut Option<SpecialtyType>,
+        filter_specialty: &mut Option<String>,
         specialists: &mut Vec<QualitySpecialist>,
         departments: &[Department],
+        specialties: &[Specialty],
         store: &DataStore,
+        operator: &Operator,
     ) {
         // 新增表单
         ui.group(|ui| {
-            ui.heading("添加新专责");
+            ui.heading(tr("添加新专责"));
             ui.horizontal(|ui| {
-                ui.label("姓名:");
+                ui.label(tr("姓名:"));
                 ui.text_edit_singleline(new_name);
                 
-                ui.label("部门:");
+                ui.label(tr("部门:"));
                 egui::ComboBox::from_id_salt("new_specialist_dept")
                     .selected_text(
                         departments.iter()
                             .find(|d| &d.id == new_dept)
                             .map(|d| d.name.as_str())
-                            .unwrap_or("请选择")
+                            .unwrap_or(tr("请选择"))
                     )
                     .show_ui(ui, |ui| {
                         for dept in departments {
@@ -156,42 +247,39 @@ impl SettingsPanel {
                         }
                     });
                 
-                ui.label("专业:");
+                ui.label(tr("专业:"));
                 egui::ComboBox::from_id_salt("new_specialist_type")
-                    .selected_text(new_type.display_name())
+                    .selected_text(
+                        specialties.iter()
+                            .find(|s| &s.id == new_type)
+                            .map(|s| tr(&s.name))
+                            .unwrap_or(tr("请选择"))
+                    )
                     .show_ui(ui, |ui| {
-                        ui.selectable_value(new_type, SpecialtyType::Pressure, "承压类");
-                        ui.selectable_value(new_type, SpecialtyType::Mechanical, "机电类");
+                        for specialty in specialties {
+                            ui.selectable_value(new_type, specialty.id.clone(), tr(&specialty.name));
+                        }
                     });
                 
-                if ui.button("➕ 添加").clicked() {
-                    if !new_name.trim().is_empty() && !new_dept.is_empty() {
-                        // 检查是否存在重复：同一部门、同一姓名、同一专业
-                        let name_trimmed = new_name.trim();
-                        let is_duplicate = specialists.iter().any(|s| {
-                            s.name == name_trimmed 
-                                && s.department_id == *new_dept 
-                                && s.specialty == *new_type
-                        });
-                        
-                        if is_duplicate {
-                            // 设置错误消息
-                            *error_message = Some(format!(
-                                "⚠ 重复添加：{} 在该部门的{}专业已存在！",
-                                name_trimmed,
-                                new_type.display_name()
-                            ));
-                        } else {
-                            let new_id = uuid::Uuid::new_v4().to_string();
-                            specialists.push(QualitySpecialist::new(
-                                new_id,
-                                name_trimmed,
-                                new_dept.as_str(),
-                                *new_type,
-                            ));
+                if ui.button(tr("➕ 添加")).clicked() && !new_name.trim().is_empty() && !new_dept.is_empty() && !new_type.is_empty() {
+                    let specialist = QualitySpecialist::new(
+                        uuid::Uuid::new_v4().to_string(),
+                        new_name.trim(),
+                        new_dept.as_str(),
+                        new_type.as_str(),
+                    ).stamped(ChangeStamp::now(operator));
+                    // 同一部门、同一姓名、同一专业不能重复添加
+                    match CatalogManager::validate_specialist(specialists, departments, specialties, &specialist) {
+                        Err(e) => *error_message = Some(format!("⚠ {}", e)),
+                        Ok(()) => {
+                            store.append_audit(&AuditEntry::new(
+                                operator,
+                                AuditAction::AddSpecialist,
+                                Self::specialist_label(&specialist, departments, specialties),
+                            ).with_new(&specialist));
+                            specialists.push(specialist);
                             store.save_specialists(specialists);
                             new_name.clear();
-                            // 清除错误消息
                             *error_message = None;
                         }
                     }
@@ -218,10 +306,10 @@ impl SettingsPanel {
         
         // 筛选工具栏
         ui.horizontal(|ui| {
-            ui.label("🔍 搜索:");
+            ui.label(tr("🔍 搜索:"));
             ui.text_edit_singleline(search_text);
             
-            ui.label("筛选:");
+            ui.label(tr("筛选:"));
             
             // 部门筛选
             egui::ComboBox::from_id_salt("filter_dept")
@@ -229,10 +317,10 @@ impl SettingsPanel {
                     filter_dept.as_ref()
                         .and_then(|id| departments.iter().find(|d| &d.id == id))
                         .map(|d| d.name.as_str())
-                        .unwrap_or("所有部门")
+                        .unwrap_or(tr("所有部门"))
                 )
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(filter_dept, None, "所有部门");
+                    ui.selectable_value(filter_dept, None, tr("所有部门"));
                     for dept in departments {
                         ui.selectable_value(
                             filter_dept,
@@ -245,17 +333,18 @@ impl SettingsPanel {
             // 专业筛选
             egui::ComboBox::from_id_salt("filter_specialty")
                 .selected_text(
-                    filter_specialty.as_ref()
-                        .map(|s| s.display_name())
-                        .unwrap_or("所有专业")
+                    filter_specialty.as_deref()
+                        .map(|id| tr(specialty_name(specialties, id)))
+                        .unwrap_or(tr("所有专业"))
                 )
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(filter_specialty, None, "所有专业");
-                    ui.selectable_value(filter_specialty, Some(SpecialtyType::Pressure), "承压类");
-                    ui.selectable_value(filter_specialty, Some(SpecialtyType::Mechanical), "机电类");
+                    ui.selectable_value(filter_specialty, None, tr("所有专业"));
+                    for specialty in specialties {
+                        ui.selectable_value(filter_specialty, Some(specialty.id.clone()), tr(&specialty.name));
+                    }
                 });
                 
-            if ui.button("❌ 重置").clicked() {
+            if ui.button(tr("❌ 重置")).clicked() {
                 search_text.clear();
                 *filter_dept = None;
                 *filter_specialty = None;
@@ -291,17 +380,17 @@ impl SettingsPanel {
             .map(|(i, _)| i)
             .collect();
             
-        ui.heading(format!("专责列表 (显示 {} / 共 {} 人)", sorted_indices.len(), specialists.len()));
+        ui.heading(trf("专责列表 (显示 {} / 共 {} 人)", &[&sorted_indices.len(), &specialists.len()]));
 
         sorted_indices.sort_by(|&a, &b| {
             let dept_a = departments.iter()
                 .find(|d| d.id == specialists[a].department_id)
                 .map(|d| d.name.as_str())
-                .unwrap_or("未知");
+                .unwrap_or(tr("未知"));
             let dept_b = departments.iter()
                 .find(|d| d.id == specialists[b].department_id)
                 .map(|d| d.name.as_str())
-                .unwrap_or("未知");
+                .unwrap_or(tr("未知"));
             dept_a.cmp(dept_b)
         });
         
@@ -315,18 +404,18 @@ impl SettingsPanel {
                     let dept_name = departments.iter()
                         .find(|d| d.id == specialist.department_id)
                         .map(|d| d.name.as_str())
-                        .unwrap_or("未知");
+                        .unwrap_or(tr("未知"));
                     
                     ui.horizontal(|ui| {
                         ui.label(format!(
                             "{}  |  {}  |  {}",
                             specialist.name,
                             dept_name,
-                            specialist.specialty.display_name()
+                            tr(specialty_name(specialties, &specialist.specialty))
                         ));
                         
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("🗑 删除").clicked() {
+                            if ui.button(tr("🗑 删除")).clicked() {
                                 to_delete = Some(idx);
                             }
                         });
@@ -335,100 +424,653 @@ impl SettingsPanel {
                 }
                 
                 if let Some(idx) = to_delete {
-                    specialists.remove(idx);
+                    let removed = specialists.remove(idx);
                     store.save_specialists(specialists);
+                    store.append_audit(&AuditEntry::new(
+                        operator,
+                        AuditAction::DeleteSpecialist,
+                        Self::specialist_label(&removed, departments, specialties),
+                    ).with_old(&removed));
                 }
             });
     }
     
-    /// 显示部门管理UI（静态方法避免借用冲突）
+    /// 显示部门管理UI
+    #[allow(clippy::too_many_arguments)]
     fn show_departments_ui(
+        &mut self,
         ui: &mut egui::Ui,
-        new_name: &mut String,
-        new_type: &mut DepartmentType,
         departments: &mut Vec<Department>,
+        specialties: &[Specialty],
+        specialists: &[QualitySpecialist],
+        current_round: &BTreeMap<String, Vec<(String, String)>>,
         store: &DataStore,
+        operator: &Operator,
     ) {
         // 新增表单
         ui.group(|ui| {
-            ui.heading("添加新部门");
+            ui.heading(tr("添加新部门"));
             ui.horizontal(|ui| {
-                ui.label("名称:");
-                ui.text_edit_singleline(new_name);
+                ui.label(tr("名称:"));
+                ui.add(egui::TextEdit::singleline(&mut self.new_dept_name).desired_width(140.0));
                 
-                ui.label("类型:");
-                egui::ComboBox::from_id_salt("new_dept_type")
-                    .selected_text(new_type.display_name())
-                    .show_ui(ui, |ui| {
-                        ui.selectable_value(new_type, DepartmentType::Comprehensive, "综合类");
-                        ui.selectable_value(new_type, DepartmentType::Pressure, "承压类");
-                        ui.selectable_value(new_type, DepartmentType::Mechanical, "机电类");
-                    });
+                ui.label(tr("分类:"));
+                ui.add(egui::TextEdit::singleline(&mut self.new_dept_category)
+                    .hint_text(tr("如 综合类"))
+                    .desired_width(80.0));
+            });
+            ui.horizontal(|ui| {
+                ui.label(tr("专业:"));
+                for specialty in specialties {
+                    Self::specialty_checkbox(ui, &mut self.new_dept_specialties, specialty);
+                }
                 
-                if ui.button("➕ 添加").clicked() {
-                    if !new_name.trim().is_empty() {
-                        let new_id = uuid::Uuid::new_v4().to_string();
-                        departments.push(Department::new(
-                            new_id,
-                            new_name.trim(),
-                            *new_type,
-                        ));
-                        store.save_departments(departments);
-                        new_name.clear();
-                    }
+                if ui.button(tr("➕ 添加")).clicked() && !self.new_dept_name.trim().is_empty() {
+                    // 新部门需要抽取和可派出的专业相同，可在列表中单独修改
+                    let chosen: Vec<&str> = self.new_dept_specialties.iter().map(String::as_str).collect();
+                    let department = Department::new(
+                        uuid::Uuid::new_v4().to_string(),
+                        self.new_dept_name.trim(),
+                        self.new_dept_category.trim(),
+                        &chosen,
+                    ).stamped(ChangeStamp::now(operator));
+                    store.append_audit(&AuditEntry::new(
+                        operator,
+                        AuditAction::AddDepartment,
+                        format!("{}（{}）", department.name, department.category),
+                    ).with_new(&department));
+                    departments.push(department);
+                    store.save_departments(departments);
+                    self.new_dept_name.clear();
                 }
             });
+            ui.label(egui::RichText::new(tr("被检查时按所需专业抽取，只有能派出该专业的部门参与抽签"))
+                .color(egui::Color32::GRAY));
         });
         
         ui.separator();
         
         // 部门列表
-        ui.heading(format!("部门列表 (共{}个)", departments.len()));
+        ui.heading(trf("部门列表 (共{}个)", &[&departments.len()]));
+        if let Some(msg) = &self.taxonomy_message {
+            ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+        }
         
-        // 创建按类型和名称排序的索引列表
+        // 按分类（首次出现的顺序）和名称排序的索引列表
+        let category_order = |category: &str| departments.iter().position(|d| d.category == category);
         let mut sorted_indices: Vec<usize> = (0..departments.len()).collect();
         sorted_indices.sort_by(|&a, &b| {
-            let type_order = |t: &crate::models::DepartmentType| match t {
-                crate::models::DepartmentType::Comprehensive => 0,
-                crate::models::DepartmentType::Pressure => 1,
-                crate::models::DepartmentType::Mechanical => 2,
-            };
-            let type_cmp = type_order(&departments[a].department_type)
-                .cmp(&type_order(&departments[b].department_type));
-            if type_cmp == std::cmp::Ordering::Equal {
-                departments[a].name.cmp(&departments[b].name)
-            } else {
-                type_cmp
-            }
+            category_order(&departments[a].category)
+                .cmp(&category_order(&departments[b].category))
+                .then_with(|| departments[a].name.cmp(&departments[b].name))
         });
         
         egui::ScrollArea::vertical()
             .max_height(350.0)
             .show(ui, |ui| {
                 let mut to_delete = None;
+                let mut to_save = None;
                 
                 for &idx in &sorted_indices {
                     let dept = &departments[idx];
                     ui.horizontal(|ui| {
-                        ui.label(format!(
-                            "{}  |  {}",
-                            dept.name,
-                            dept.department_type.display_name()
+                        ui.label(trf(
+                            "{}  |  {}  |  需要: {}  |  派出: {}",
+                            &[
+                                &dept.name,
+                                &tr(&dept.category),
+                                &Self::needs_list(dept, specialties),
+                                &Self::specialty_list(&dept.supplies, specialties),
+                            ],
                         ));
                         
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("🗑 删除").clicked() {
+                            if ui.button(tr("🗑 删除")).clicked() {
                                 to_delete = Some(idx);
                             }
+                            if ui.button(tr("✏ 专业")).clicked() {
+                                self.editing_dept_id = Some(dept.id.clone());
+                                self.editing_needs = dept.needs.clone();
+                                self.editing_supplies = dept.supplies.clone();
+                                self.editing_team_sizes = dept.team_sizes.clone();
+                            }
                         });
                     });
+                    
+                    if self.editing_dept_id.as_deref() == Some(dept.id.as_str()) {
+                        ui.horizontal(|ui| {
+                            ui.label(tr("需要抽取:"));
+                            for specialty in specialties {
+                                Self::specialty_checkbox(ui, &mut self.editing_needs, specialty);
+                            }
+                        });
+                        if !self.editing_needs.is_empty() {
+                            ui.horizontal(|ui| {
+                                ui.label(tr("检查组部门数:"));
+                                for specialty in specialties.iter().filter(|s| self.editing_needs.contains(&s.id)) {
+                                    let size = self.editing_team_sizes.entry(specialty.id.clone()).or_insert(1);
+                                    ui.label(tr(&specialty.name));
+                                    ui.add(egui::DragValue::new(size).range(1..=5));
+                                }
+                            });
+                        }
+                        ui.horizontal(|ui| {
+                            ui.label(tr("可以派出:"));
+                            for specialty in specialties {
+                                Self::specialty_checkbox(ui, &mut self.editing_supplies, specialty);
+                            }
+                            if ui.button(tr("确定")).clicked() {
+                                to_save = Some(idx);
+                            }
+                            if ui.button(tr("取消")).clicked() {
+                                self.editing_dept_id = None;
+                            }
+                        });
+                    }
                     ui.separator();
                 }
                 
-                if let Some(idx) = to_delete {
-                    departments.remove(idx);
+                if let Some(idx) = to_save {
+                    let old = departments[idx].clone();
+                    let dept = &mut departments[idx];
+                    dept.needs = std::mem::take(&mut self.editing_needs);
+                    dept.supplies = std::mem::take(&mut self.editing_supplies);
+                    dept.team_sizes.clear();
+                    for (specialty_id, size) in std::mem::take(&mut self.editing_team_sizes) {
+                        if dept.needs_specialty(&specialty_id) {
+                            dept.set_team_size(&specialty_id, size);
+                        }
+                    }
+                    dept.updated = Some(ChangeStamp::now(operator));
+                    store.append_audit(&AuditEntry::new(
+                        operator,
+                        AuditAction::UpdateDepartment,
+                        format!("{}（{}）", dept.name, dept.category),
+                    ).with_old(&old).with_new(&*dept));
                     store.save_departments(departments);
+                    self.editing_dept_id = None;
+                }
+                
+                // 仍有专责或本轮抽签结果引用的部门不能删除
+                if let Some(idx) = to_delete {
+                    if CatalogManager::department_in_use(specialists, current_round.values().flatten(), &departments[idx].id) {
+                        self.taxonomy_message = Some(trf(
+                            "⚠ 部门 {} 仍有专责或本轮抽签结果，请先移除其专责或开始新一轮",
+                            &[&departments[idx].name],
+                        ));
+                    } else {
+                        let removed = departments.remove(idx);
+                        store.save_departments(departments);
+                        store.append_audit(&AuditEntry::new(
+                            operator,
+                            AuditAction::DeleteDepartment,
+                            format!("{}（{}）", removed.name, removed.category),
+                        ).with_old(&removed));
+                        self.taxonomy_message = None;
+                    }
+                }
+            });
+    }
+    
+    /// 显示单位信息UI（名称、标志、报表抬头、抽签规则，以及新增单位）
+    fn show_organization_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        organization: &mut Organization,
+        store: &DataStore,
+        operator: &Operator,
+    ) {
+        let form = self.organization_form.get_or_insert_with(|| organization.clone());
+        
+        ui.group(|ui| {
+            ui.heading(tr("当前单位"));
+            egui::Grid::new("organization_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(tr("单位ID:"));
+                    ui.label(&form.id);
+                    ui.end_row();
+                    
+                    ui.label(tr("单位名称:"));
+                    ui.text_edit_singleline(&mut form.name);
+                    ui.end_row();
+                    
+                    ui.label(tr("标志图片:"));
+                    let mut logo = form.logo.clone().unwrap_or_default();
+                    if ui.add(egui::TextEdit::singleline(&mut logo).hint_text(tr("图片文件路径（可选）"))).changed() {
+                        form.logo = Some(logo.trim().to_string()).filter(|l| !l.is_empty());
+                    }
+                    ui.end_row();
+                    
+                    ui.label(tr("报表抬头:"));
+                    let default_header = trf("{}质量监督检查抽签结果", &[&form.name.trim()]);
+                    ui.add(egui::TextEdit::singleline(&mut form.report_header).hint_text(default_header));
+                    ui.end_row();
+                    
+                    ui.label(tr("抽签规则:"));
+                    ui.vertical(|ui| {
+                        ui.checkbox(&mut form.rules.once_per_round, tr("本轮已被抽中的部门不再参与抽取"));
+                        ui.checkbox(&mut form.rules.cross_avoidance, tr("交叉回避（不能反过来检查本轮检查过自己的部门）"));
+                    });
+                    ui.end_row();
+                });
+            
+            ui.add_space(5.0);
+            if ui.button(tr("💾 保存")).clicked() {
+                if form.name.trim().is_empty() {
+                    self.organization_message = Some(tr("⚠ 请填写单位名称").to_string());
+                } else {
+                    form.name = form.name.trim().to_string();
+                    let updated = form.clone().stamped(ChangeStamp::now(operator));
+                    store.save_organization(&updated);
+                    store.append_audit(&AuditEntry::new(
+                        operator,
+                        AuditAction::UpdateOrganization,
+                        updated.name.clone(),
+                    ).with_old(&*organization).with_new(&updated));
+                    *organization = updated;
+                    self.organization_message = Some(tr("单位信息已保存").to_string());
+                }
+            }
+        });
+        
+        ui.separator();
+        
+        ui.group(|ui| {
+            ui.heading(tr("新增单位"));
+            ui.label(egui::RichText::new(tr("每个单位的部门、专业、专责、账户和抽签记录相互独立，启动时选择进入的单位"))
+                .color(egui::Color32::GRAY));
+            ui.label(egui::RichText::new(tr("新单位的管理员账户在此一并创建，其他人不能在登录界面为新单位创建管理员"))
+                .color(egui::Color32::GRAY));
+            egui::Grid::new("new_organization_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(tr("单位ID:"));
+                    ui.add(egui::TextEdit::singleline(&mut self.new_org_id).desired_width(100.0).hint_text(tr("如 yc")));
+                    ui.end_row();
+                    
+                    ui.label(tr("名称:"));
+                    ui.text_edit_singleline(&mut self.new_org_name);
+                    ui.end_row();
+                    
+                    ui.label(tr("管理员登录名:"));
+                    ui.text_edit_singleline(&mut self.new_org_admin_username);
+                    ui.end_row();
+                    
+                    ui.label(tr("管理员姓名:"));
+                    ui.text_edit_singleline(&mut self.new_org_admin_name);
+                    ui.end_row();
+                    
+                    ui.label(tr("管理员密码:"));
+                    ui.add(egui::TextEdit::singleline(&mut self.new_org_admin_password).password(true));
+                    ui.end_row();
+                    
+                    ui.label(tr("确认密码:"));
+                    ui.add(egui::TextEdit::singleline(&mut self.new_org_admin_confirm).password(true));
+                    ui.end_row();
+                });
+            
+            if ui.button(tr("➕ 添加")).clicked() {
+                match self.create_organization(store, operator) {
+                    Ok(created) => {
+                        self.organization_message = Some(trf(
+                            "已新增单位 {}，注销后可选择进入并用其管理员账户登录",
+                            &[&created.name],
+                        ));
+                        self.new_org_id.clear();
+                        self.new_org_name.clear();
+                        self.new_org_admin_username.clear();
+                        self.new_org_admin_name.clear();
+                    }
+                    Err(e) => {
+                        self.organization_message = Some(format!("⚠ {}", e));
+                    }
+                }
+                self.new_org_admin_password.clear();
+                self.new_org_admin_confirm.clear();
+            }
+        });
+        
+        if let Some(msg) = &self.organization_message {
+            ui.add_space(5.0);
+            ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+        }
+    }
+    
+    /// 新增单位及其首个管理员账户，并分别记入当前单位和新单位的审计日志
+    fn create_organization(&self, store: &DataStore, operator: &Operator) -> Result<Organization, String> {
+        if self.new_org_admin_password != self.new_org_admin_confirm {
+            return Err(tr("两次输入的密码不一致").to_string());
+        }
+        let admin = AuthManager::create_operator(
+            &[],
+            &self.new_org_admin_username,
+            &self.new_org_admin_name,
+            Role::Admin,
+            &self.new_org_admin_password,
+        )?;
+        let new = Organization::new(&self.new_org_id, &self.new_org_name)
+            .stamped(ChangeStamp::now(operator));
+        let created = store.create_organization(new, &admin)?;
+        store.append_audit(&AuditEntry::new(
+            operator,
+            AuditAction::AddOrganization,
+            format!("{}（{}）", created.name, created.id),
+        ).with_new(&created));
+        store.for_organization(&created.id).append_audit(&AuditEntry::new(
+            operator,
+            AuditAction::AddOperator,
+            format!("{}（{}）", admin.display_name, admin.username),
+        ).with_new(&admin.role));
+        Ok(created)
+    }
+    
+    /// 显示专业管理UI
+    fn show_specialties_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        specialties: &mut Vec<Specialty>,
+        departments: &[Department],
+        specialists: &[QualitySpecialist],
+        store: &DataStore,
+        operator: &Operator,
+    ) {
+        // 新增表单
+        ui.group(|ui| {
+            ui.heading(tr("添加新专业"));
+            ui.horizontal(|ui| {
+                ui.label(tr("名称:"));
+                ui.text_edit_singleline(&mut self.new_specialty_name);
+                
+                if ui.button(tr("➕ 添加")).clicked() {
+                    let specialty = Specialty::new(uuid::Uuid::new_v4().to_string(), self.new_specialty_name.trim())
+                        .stamped(ChangeStamp::now(operator));
+                    if let Err(e) = CatalogManager::validate_specialty(specialties, &specialty) {
+                        self.taxonomy_message = Some(format!("⚠ {}", e));
+                    } else {
+                        store.append_audit(&AuditEntry::new(
+                            operator,
+                            AuditAction::AddSpecialty,
+                            specialty.name.clone(),
+                        ).with_new(&specialty));
+                        self.taxonomy_message = Some(trf("已添加专业 {}，请在部门管理中设置需要和派出该专业的部门", &[&specialty.name]));
+                        specialties.push(specialty);
+                        store.save_specialties(specialties);
+                        self.new_specialty_name.clear();
+                    }
+                }
+            });
+            
+            if let Some(msg) = &self.taxonomy_message {
+                ui.add_space(5.0);
+                ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+            }
+        });
+        
+        ui.separator();
+        ui.heading(trf("专业列表 (共{}个)", &[&specialties.len()]));
+        
+        egui::ScrollArea::vertical()
+            .max_height(350.0)
+            .show(ui, |ui| {
+                let mut to_delete = None;
+                
+                for (idx, specialty) in specialties.iter().enumerate() {
+                    let needed = departments.iter().filter(|d| d.needs_specialty(&specialty.id)).count();
+                    let supplied = departments.iter().filter(|d| d.supplies_specialty(&specialty.id)).count();
+                    let staff = specialists.iter().filter(|s| s.specialty == specialty.id).count();
+                    ui.horizontal(|ui| {
+                        ui.label(trf(
+                            "{}  |  需要的部门 {}  |  派出的部门 {}  |  专责 {} 人",
+                            &[&tr(&specialty.name), &needed, &supplied, &staff],
+                        ));
+                        
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button(tr("🗑 删除")).clicked() {
+                                to_delete = Some((idx, CatalogManager::specialty_in_use(departments, specialists, &specialty.id)));
+                            }
+                        });
+                    });
+                    ui.separator();
+                }
+                
+                match to_delete {
+                    // 仍被部门或专责使用的专业不能删除，历史记录中的名称不受影响
+                    Some((idx, true)) => {
+                        self.taxonomy_message = Some(trf(
+                            "⚠ 专业 {} 仍被部门或专责使用，请先在部门管理和专责管理中移除",
+                            &[&tr(&specialties[idx].name)],
+                        ));
+                    }
+                    Some((idx, false)) => {
+                        let removed = specialties.remove(idx);
+                        store.save_specialties(specialties);
+                        store.append_audit(&AuditEntry::new(
+                            operator,
+                            AuditAction::DeleteSpecialty,
+                            removed.name.clone(),
+                        ).with_old(&removed));
+                        self.taxonomy_message = None;
+                    }
+                    None => {}
                 }
             });
     }
+    
+    /// 专业勾选框（勾选时加入列表，取消时移出）
+    fn specialty_checkbox(ui: &mut egui::Ui, selected: &mut Vec<String>, specialty: &Specialty) {
+        let mut checked = selected.contains(&specialty.id);
+        if ui.checkbox(&mut checked, tr(&specialty.name)).changed() {
+            if checked {
+                selected.push(specialty.id.clone());
+            } else {
+                selected.retain(|id| id != &specialty.id);
+            }
+        }
+    }
+    
+    /// 部门需要抽取的专业列表，检查组有多个部门时注明部门数，如"承压类×2、机电类"
+    fn needs_list(dept: &Department, specialties: &[Specialty]) -> String {
+        if dept.needs.is_empty() {
+            return tr("无").to_string();
+        }
+        dept.needs.iter()
+            .map(|id| match dept.team_size(id) {
+                1 => tr(specialty_name(specialties, id)).to_string(),
+                size => format!("{}×{}", tr(specialty_name(specialties, id)), size),
+            })
+            .collect::<Vec<_>>()
+            .join(tr("、"))
+    }
+    
+    /// 专业名称列表，如"承压类、机电类"
+    fn specialty_list(ids: &[String], specialties: &[Specialty]) -> String {
+        if ids.is_empty() {
+            return tr("无").to_string();
+        }
+        ids.iter()
+            .map(|id| tr(specialty_name(specialties, id)))
+            .collect::<Vec<_>>()
+            .join(tr("、"))
+    }
+    
+    /// 显示账户管理UI
+    fn show_accounts_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        operators: &mut Vec<Operator>,
+        store: &DataStore,
+        current: &Operator,
+    ) {
+        // 新增表单
+        ui.group(|ui| {
+            ui.heading(tr("添加新账户"));
+            ui.horizontal(|ui| {
+                ui.label(tr("登录名:"));
+                ui.add(egui::TextEdit::singleline(&mut self.new_operator_username).desired_width(90.0));
+                ui.label(tr("姓名:"));
+                ui.add(egui::TextEdit::singleline(&mut self.new_operator_name).desired_width(90.0));
+                ui.label(tr("密码:"));
+                ui.add(egui::TextEdit::singleline(&mut self.new_operator_password)
+                    .password(true)
+                    .desired_width(90.0));
+                
+                egui::ComboBox::from_id_salt("new_operator_role")
+                    .selected_text(self.new_operator_role.display_name())
+                    .show_ui(ui, |ui| {
+                        for role in Role::all() {
+                            ui.selectable_value(&mut self.new_operator_role, role, role.display_name());
+                        }
+                    });
+                
+                if ui.button(tr("➕ 添加")).clicked() {
+                    match AuthManager::create_operator(
+                        operators,
+                        &self.new_operator_username,
+                        &self.new_operator_name,
+                        self.new_operator_role,
+                        &self.new_operator_password,
+                    ) {
+                        Ok(new_operator) => {
+                            self.account_message = Some(trf("已添加账户 {}", &[&new_operator.label()]));
+                            store.append_audit(&AuditEntry::new(
+                                current,
+                                AuditAction::AddOperator,
+                                Self::operator_label(&new_operator),
+                            ).with_new(&new_operator.role));
+                            operators.push(new_operator);
+                            store.save_operators(operators);
+                            self.new_operator_username.clear();
+                            self.new_operator_name.clear();
+                            self.new_operator_password.clear();
+                        }
+                        Err(e) => self.account_message = Some(format!("⚠ {}", e)),
+                    }
+                }
+            });
+            
+            if let Some(msg) = &self.account_message {
+                ui.add_space(5.0);
+                ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+            }
+        });
+        
+        ui.separator();
+        ui.heading(trf("账户列表 (共{}个)", &[&operators.len()]));
+        
+        egui::ScrollArea::vertical()
+            .max_height(350.0)
+            .show(ui, |ui| {
+                let mut to_delete = None;
+                let mut role_change = None;
+                let mut password_reset = None;
+                
+                for (idx, account) in operators.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}  |  {}", account.username, account.display_name));
+                        if account.id == current.id {
+                            ui.label(egui::RichText::new(tr("（当前登录）")).color(egui::Color32::GRAY));
+                        }
+                        
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            // 不能删除自己
+                            if ui.add_enabled(account.id != current.id, egui::Button::new(tr("🗑 删除"))).clicked() {
+                                to_delete = Some(idx);
+                            }
+                            if ui.button(tr("🔑 重设密码")).clicked() {
+                                self.reset_password_id = Some(account.id.clone());
+                                self.reset_password_text.clear();
+                            }
+                            let mut role = account.role;
+                            egui::ComboBox::from_id_salt(("operator_role", idx))
+                                .selected_text(role.display_name())
+                                .show_ui(ui, |ui| {
+                                    for r in Role::all() {
+                                        ui.selectable_value(&mut role, r, r.display_name());
+                                    }
+                                });
+                            if role != account.role {
+                                role_change = Some((idx, role));
+                            }
+                        });
+                    });
+                    
+                    if self.reset_password_id.as_deref() == Some(account.id.as_str()) {
+                        ui.horizontal(|ui| {
+                            ui.label(tr("新密码:"));
+                            ui.add(egui::TextEdit::singleline(&mut self.reset_password_text).password(true));
+                            if ui.button(tr("确定")).clicked() {
+                                password_reset = Some(idx);
+                            }
+                            if ui.button(tr("取消")).clicked() {
+                                self.reset_password_id = None;
+                            }
+                        });
+                    }
+                    ui.separator();
+                }
+                
+                if let Some((idx, role)) = role_change {
+                    if AuthManager::keeps_admin(operators, &operators[idx].id, Some(role)) {
+                        store.append_audit(&AuditEntry::new(
+                            current,
+                            AuditAction::ChangeRole,
+                            Self::operator_label(&operators[idx]),
+                        ).with_old(&operators[idx].role).with_new(&role));
+                        operators[idx].role = role;
+                        store.save_operators(operators);
+                        self.account_message = None;
+                    } else {
+                        self.account_message = Some(tr("⚠ 至少需要保留一个管理员").to_string());
+                    }
+                }
+                
+                if let Some(idx) = password_reset {
+                    match AuthManager::set_password(&mut operators[idx], &self.reset_password_text) {
+                        Ok(()) => {
+                            store.save_operators(operators);
+                            store.append_audit(&AuditEntry::new(
+                                current,
+                                AuditAction::ResetPassword,
+                                Self::operator_label(&operators[idx]),
+                            ));
+                            self.account_message = Some(trf("已重设 {} 的密码", &[&operators[idx].display_name]));
+                            self.reset_password_id = None;
+                        }
+                        Err(e) => self.account_message = Some(format!("⚠ {}", e)),
+                    }
+                    self.reset_password_text.clear();
+                }
+                
+                if let Some(idx) = to_delete {
+                    if AuthManager::keeps_admin(operators, &operators[idx].id, None) {
+                        let removed = operators.remove(idx);
+                        store.save_operators(operators);
+                        store.append_audit(&AuditEntry::new(
+                            current,
+                            AuditAction::DeleteOperator,
+                            Self::operator_label(&removed),
+                        ).with_old(&removed.role));
+                    } else {
+                        self.account_message = Some(tr("⚠ 至少需要保留一个管理员").to_string());
+                    }
+                }
+            });
+    }
+    
+    /// 审计日志中的专责说明，如"张三（宁东分院 / 承压类）"
+    fn specialist_label(specialist: &QualitySpecialist, departments: &[Department], specialties: &[Specialty]) -> String {
+        let dept_name = departments.iter()
+            .find(|d| d.id == specialist.department_id)
+            .map(|d| d.name.as_str())
+            .unwrap_or(tr("未知"));
+        format!("{}（{} / {}）", specialist.name, dept_name, specialty_name(specialties, &specialist.specialty))
+    }
+    
+    /// 审计日志中的账户说明（不包含密码信息）
+    fn operator_label(operator: &Operator) -> String {
+        format!("{}（{}）", operator.display_name, operator.username)
+    }
 }
@@ -0,0 +1,78 @@
+//! 抽签提示音
+//!
+//! 不引入音频库，调用系统自带的播放方式：Windows 直接调用 kernel32 的 `Beep`，macOS 用 `afplay` 播放系统音效，
+//! Linux/UOS 用 `canberra-gtk-play` 播放桌面主题音效。播放失败（如未安装）时静默忽略。
+
+#[cfg(not(target_os = "windows"))]
+use std::process::{Command, Stdio};
+
+/// 提示音
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundCue {
+    /// 转盘开始转动
+    Start,
+    /// 开始减速
+    Stop,
+    /// 转盘停下，出现结果
+    Result,
+}
+
+/// 在后台线程播放提示音（蜂鸣会阻塞到结束，不能放在界面线程）
+pub fn play(cue: SoundCue) {
+    std::thread::spawn(move || play_blocking(cue));
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "kernel32")]
+extern "system" {
+    fn Beep(frequency: u32, duration: u32) -> i32;
+}
+
+/// 用系统蜂鸣播放提示音
+#[cfg(target_os = "windows")]
+fn play_blocking(cue: SoundCue) {
+    let (frequency, millis) = match cue {
+        SoundCue::Start => (660, 120),
+        SoundCue::Stop => (520, 120),
+        SoundCue::Result => (880, 350),
+    };
+    // SAFETY: Beep 只接收两个整数参数，没有指针或其他前置条件
+    unsafe {
+        Beep(frequency, millis);
+    }
+}
+
+/// 调用系统命令播放提示音（等待命令结束，不留下僵尸进程）
+#[cfg(not(target_os = "windows"))]
+fn play_blocking(cue: SoundCue) {
+    let _ = command(cue)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+/// 播放提示音的系统命令
+#[cfg(target_os = "macos")]
+fn command(cue: SoundCue) -> Command {
+    let name = match cue {
+        SoundCue::Start => "Tink",
+        SoundCue::Stop => "Pop",
+        SoundCue::Result => "Glass",
+    };
+    let mut command = Command::new("afplay");
+    command.arg(format!("/System/Library/Sounds/{}.aiff", name));
+    command
+}
+
+/// 播放提示音的系统命令
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn command(cue: SoundCue) -> Command {
+    let id = match cue {
+        SoundCue::Start => "dialog-information",
+        SoundCue::Stop => "message",
+        SoundCue::Result => "complete",
+    };
+    let mut command = Command::new("canberra-gtk-play");
+    command.args(["--id", id]);
+    command
+}
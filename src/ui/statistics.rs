@@ -0,0 +1,264 @@
+//! 抽签统计界面
+//!
+//! 统计全部有效记录：各部门被检和检查次数、配对热力图、卡方公平性检验和季度趋势。
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::export::ExportManager;
+use crate::logic::{DrawStatistics, SpecialtyStatistics, StatsManager};
+use crate::models::{Department, DrawRecord, Organization, Specialty};
+
+/// 统计页签
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatsTab {
+    /// 部门次数
+    Counts,
+    /// 配对热力图
+    Heatmap,
+    /// 公平性检验
+    Fairness,
+    /// 季度趋势
+    Trend,
+}
+
+/// 抽签统计面板
+pub struct StatisticsPanel {
+    /// 是否显示
+    pub visible: bool,
+    /// 当前页签
+    tab: StatsTab,
+    /// 选中的专业（统计结果中的序号）
+    specialty: usize,
+    /// 打开时计算的统计结果
+    stats: Option<DrawStatistics>,
+    /// 提示消息
+    message: Option<String>,
+}
+
+impl Default for StatisticsPanel {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            tab: StatsTab::Counts,
+            specialty: 0,
+            stats: None,
+            message: None,
+        }
+    }
+}
+
+impl StatisticsPanel {
+    /// 打开面板并重新统计
+    pub fn open(&mut self, records: &[DrawRecord], departments: &[Department], specialties: &[Specialty]) {
+        let stats = StatsManager::compute(records, departments, specialties);
+        if self.specialty >= stats.specialties.len() {
+            self.specialty = 0;
+        }
+        self.stats = Some(stats);
+        self.message = None;
+        self.visible = true;
+    }
+
+    /// 显示抽签统计面板
+    pub fn show(&mut self, ctx: &egui::Context, organization: &Organization) {
+        if !self.visible {
+            return;
+        }
+        let Some(stats) = self.stats.take() else {
+            self.visible = false;
+            return;
+        };
+
+        let mut open = self.visible;
+        egui::Window::new(tr("📈 抽签统计"))
+            .open(&mut open)
+            .default_width(900.0)
+            .default_height(560.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.tab, StatsTab::Counts, tr("部门次数"));
+                    ui.selectable_value(&mut self.tab, StatsTab::Heatmap, tr("配对热力图"));
+                    ui.selectable_value(&mut self.tab, StatsTab::Fairness, tr("公平性检验"));
+                    ui.selectable_value(&mut self.tab, StatsTab::Trend, tr("季度趋势"));
+                    ui.separator();
+                    ui.label(trf("有效记录 {} 条", &[&stats.total_draws]));
+                    ui.separator();
+                    if ui.add_enabled(stats.total_draws > 0, egui::Button::new(tr("📊 导出Excel"))).clicked() {
+                        self.message = Some(Self::export(&stats, organization));
+                    }
+                });
+                if let Some(msg) = &self.message {
+                    ui.label(egui::RichText::new(msg).color(egui::Color32::from_rgb(100, 150, 200)));
+                }
+                ui.separator();
+
+                if stats.total_draws == 0 {
+                    ui.label(egui::RichText::new(tr("暂无有效的抽签记录")).color(egui::Color32::GRAY));
+                    return;
+                }
+
+                if matches!(self.tab, StatsTab::Counts | StatsTab::Heatmap) {
+                    ui.horizontal(|ui| {
+                        ui.label(tr("专业:"));
+                        for (i, specialty) in stats.specialties.iter().enumerate() {
+                            ui.selectable_value(&mut self.specialty, i, &specialty.specialty_name);
+                        }
+                    });
+                    ui.add_space(5.0);
+                }
+
+                egui::ScrollArea::both().show(ui, |ui| match self.tab {
+                    StatsTab::Counts => Self::show_counts(ui, &stats.specialties[self.specialty]),
+                    StatsTab::Heatmap => Self::show_heatmap(ui, &stats.specialties[self.specialty]),
+                    StatsTab::Fairness => Self::show_fairness(ui, &stats),
+                    StatsTab::Trend => Self::show_trend(ui, &stats),
+                });
+            });
+
+        self.stats = Some(stats);
+        self.visible = open;
+    }
+
+    /// 各部门被检、检查和期望次数
+    fn show_counts(ui: &mut egui::Ui, specialty: &SpecialtyStatistics) {
+        let max = specialty.departments.iter().map(|d| d.as_inspector).max().unwrap_or(0).max(1);
+        egui::Grid::new("stats_counts")
+            .num_columns(5)
+            .striped(true)
+            .spacing([20.0, 6.0])
+            .show(ui, |ui| {
+                for header in [tr("部门"), tr("被检次数"), tr("检查次数"), tr("期望检查次数"), ""] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+
+                for department in &specialty.departments {
+                    ui.label(&department.name);
+                    ui.label(department.as_target.to_string());
+                    ui.label(department.as_inspector.to_string());
+                    ui.label(format!("{:.2}", department.expected));
+                    ui.add(egui::ProgressBar::new(department.as_inspector as f32 / max as f32).desired_width(200.0));
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 配对热力图：行为被检部门，列为检查部门
+    fn show_heatmap(ui: &mut egui::Ui, specialty: &SpecialtyStatistics) {
+        ui.label(egui::RichText::new(tr("行：被检部门　列：检查部门")).color(egui::Color32::GRAY));
+        ui.add_space(5.0);
+        let max = specialty.pairs.iter().flatten().copied().max().unwrap_or(0).max(1);
+        let cell = egui::vec2(56.0, 26.0);
+
+        egui::Grid::new("stats_heatmap")
+            .num_columns(specialty.departments.len() + 1)
+            .spacing([2.0, 2.0])
+            .show(ui, |ui| {
+                ui.label("");
+                for department in &specialty.departments {
+                    ui.add_sized(cell, egui::Label::new(egui::RichText::new(&department.name).small()).truncate());
+                }
+                ui.end_row();
+
+                for (target, counts) in specialty.pairs.iter().enumerate() {
+                    ui.label(&specialty.departments[target].name);
+                    for count in counts {
+                        let ratio = *count as f32 / max as f32;
+                        let fill = egui::Color32::from_rgb(0x44, 0x72, 0xC4).gamma_multiply(0.15 + 0.85 * ratio);
+                        let (rect, response) = ui.allocate_exact_size(cell, egui::Sense::hover());
+                        if *count > 0 {
+                            ui.painter().rect_filled(rect, 2.0, fill);
+                        } else {
+                            ui.painter().rect_stroke(rect, 2.0, egui::Stroke::new(1.0, egui::Color32::from_gray(90)));
+                        }
+                        ui.painter().text(
+                            rect.center(),
+                            egui::Align2::CENTER_CENTER,
+                            count.to_string(),
+                            egui::FontId::proportional(13.0),
+                            if ratio > 0.5 { egui::Color32::WHITE } else { ui.visuals().text_color() },
+                        );
+                        response.on_hover_text(trf("{} 次", &[&count]));
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 各专业的卡方公平性检验
+    fn show_fairness(ui: &mut egui::Ui, stats: &DrawStatistics) {
+        ui.label(egui::RichText::new(
+            tr("每次抽取在候选名单内等概率，部门的期望次数为其参与的每次抽取的 1/候选数 之和；没有候选名单的旧记录不参与检验。")
+        ).color(egui::Color32::GRAY));
+        ui.add_space(5.0);
+
+        egui::Grid::new("stats_fairness")
+            .num_columns(7)
+            .striped(true)
+            .spacing([20.0, 6.0])
+            .show(ui, |ui| {
+                for header in [tr("专业"), tr("记录数"), tr("参与检验"), tr("卡方值"), tr("自由度"), tr("p值"), tr("结论")] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+
+                for specialty in &stats.specialties {
+                    let fairness = &specialty.fairness;
+                    ui.label(&specialty.specialty_name);
+                    ui.label(specialty.draws.to_string());
+                    ui.label(fairness.draws.to_string());
+                    ui.label(format!("{:.3}", fairness.chi_square));
+                    ui.label(fairness.degrees_of_freedom.to_string());
+                    ui.label(fairness.p_value.map(|p| format!("{:.4}", p)).unwrap_or_else(|| "-".to_string()));
+                    let color = match fairness.is_fair() {
+                        Some(true) => egui::Color32::from_rgb(60, 170, 90),
+                        Some(false) => egui::Color32::from_rgb(230, 80, 80),
+                        None => egui::Color32::GRAY,
+                    };
+                    ui.label(egui::RichText::new(fairness.conclusion()).color(color));
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 季度趋势：每季度记录数及各专业占比
+    fn show_trend(ui: &mut egui::Ui, stats: &DrawStatistics) {
+        let max = stats.quarters.iter().map(|q| q.draws).max().unwrap_or(0).max(1);
+        egui::Grid::new("stats_trend")
+            .num_columns(stats.specialties.len() + 3)
+            .striped(true)
+            .spacing([20.0, 6.0])
+            .show(ui, |ui| {
+                ui.strong(tr("季度"));
+                ui.strong(tr("合计"));
+                for specialty in &stats.specialties {
+                    ui.strong(&specialty.specialty_name);
+                }
+                ui.label("");
+                ui.end_row();
+
+                for quarter in &stats.quarters {
+                    ui.label(&quarter.label);
+                    ui.label(quarter.draws.to_string());
+                    for count in &quarter.by_specialty {
+                        ui.label(count.to_string());
+                    }
+                    ui.add(egui::ProgressBar::new(quarter.draws as f32 / max as f32).desired_width(240.0));
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// 导出到桌面，返回提示消息
+    fn export(stats: &DrawStatistics, organization: &Organization) -> String {
+        let filename = trf("抽签统计_{}.xlsx", &[&chrono::Local::now().format("%Y%m%d_%H%M%S")]);
+        let desktop = dirs::desktop_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
+        let path = desktop.join(&filename);
+
+        match ExportManager::export_statistics_to_excel(stats, organization, &path) {
+            Ok(_) => trf("已导出到: {}", &[&path.display()]),
+            Err(e) => trf("导出失败: {}", &[&e]),
+        }
+    }
+}
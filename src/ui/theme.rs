@@ -0,0 +1,229 @@
+//! 显示主题 - 界面配色、转盘配色和字号
+//!
+//! 深色、浅色和高对比度三种配色，可叠加投影大字体。主题在登录界面或顶部栏切换，
+//! 保存在数据根目录的 `display.json`。
+
+use eframe::egui::{self, Color32, Stroke};
+use crate::models::{DisplaySettings, Theme};
+
+/// 转盘扇形配色（填充色、文字色）
+const DARK_SEGMENTS: [(Color32, Color32); 8] = [
+    (Color32::from_rgb(244, 67, 54), Color32::WHITE),   // Red
+    (Color32::from_rgb(255, 193, 7), Color32::WHITE),   // Amber
+    (Color32::from_rgb(76, 175, 80), Color32::WHITE),   // Green
+    (Color32::from_rgb(33, 150, 243), Color32::WHITE),  // Blue
+    (Color32::from_rgb(156, 39, 176), Color32::WHITE),  // Purple
+    (Color32::from_rgb(255, 87, 34), Color32::WHITE),   // Deep Orange
+    (Color32::from_rgb(0, 188, 212), Color32::WHITE),   // Cyan
+    (Color32::from_rgb(63, 81, 181), Color32::WHITE),   // Indigo
+];
+
+/// 高对比度扇形：深浅交替，文字取反色
+const HIGH_CONTRAST_SEGMENTS: [(Color32, Color32); 4] = [
+    (Color32::from_rgb(255, 215, 0), Color32::BLACK),
+    (Color32::BLACK, Color32::WHITE),
+    (Color32::WHITE, Color32::BLACK),
+    (Color32::from_rgb(0, 60, 200), Color32::WHITE),
+];
+
+/// 转盘和投影使用的颜色
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    /// 转盘扇形（填充色、文字色）
+    pub segments: &'static [(Color32, Color32)],
+    /// 扇形分隔线
+    pub divider: Stroke,
+    /// 转盘标题：转动中、已抽中、空闲
+    pub title_running: Color32,
+    pub title_done: Color32,
+    pub title_idle: Color32,
+    /// 外壳底色和光泽环（外、中、内）
+    pub rim: Color32,
+    pub rim_rings: [Color32; 3],
+    /// 转盘背景
+    pub wheel_background: Color32,
+    /// 中签盘面背景和发光环
+    pub result_background: Color32,
+    pub result_glow: Color32,
+    /// 中签部门名称、专业小字、“中签”标语
+    pub result_name: Color32,
+    pub result_detail: Color32,
+    pub result_badge: Color32,
+    /// “准备就绪”等提示文字
+    pub hint: Color32,
+    /// 灯泡亮、灭
+    pub bulb_on: Color32,
+    pub bulb_off: Color32,
+    /// 中心装饰：底色、金环、准星
+    pub hub: Color32,
+    pub hub_ring: Color32,
+    pub crosshair: Color32,
+    /// 指针
+    pub pointer: Color32,
+    /// 部门列表中已抽完、抽取中的部门
+    pub done: Color32,
+    pub in_progress: Color32,
+    /// 投影窗口背景、标题、被检部门、结果表标题
+    pub stage_background: Color32,
+    pub stage_title: Color32,
+    pub stage_target: Color32,
+    pub stage_caption: Color32,
+    /// 文字放大倍数
+    pub font_scale: f32,
+}
+
+impl Palette {
+    /// 按显示设置取配色
+    pub fn of(settings: DisplaySettings) -> Self {
+        let font_scale = settings.font_scale();
+        match settings.theme {
+            Theme::Dark => Self {
+                segments: &DARK_SEGMENTS,
+                divider: Stroke::new(1.5, Color32::from_rgb(255, 223, 128)),
+                title_running: Color32::from_rgb(255, 215, 0),
+                title_done: Color32::from_rgb(50, 255, 100),
+                title_idle: Color32::from_rgb(150, 180, 220),
+                rim: Color32::from_rgb(40, 43, 48),
+                rim_rings: [
+                    Color32::from_rgb(80, 85, 95),
+                    Color32::from_rgb(30, 32, 36),
+                    Color32::from_rgb(100, 105, 115),
+                ],
+                wheel_background: Color32::from_rgb(25, 25, 30),
+                result_background: Color32::from_rgb(30, 40, 30),
+                result_glow: Color32::from_rgb(50, 255, 100),
+                result_name: Color32::from_rgb(255, 230, 100),
+                result_detail: Color32::from_rgb(180, 200, 180),
+                result_badge: Color32::from_rgb(100, 255, 100),
+                hint: Color32::GRAY,
+                bulb_on: Color32::from_rgb(255, 235, 59),
+                bulb_off: Color32::from_rgb(66, 66, 66),
+                hub: Color32::from_rgb(20, 20, 25),
+                hub_ring: Color32::from_rgb(200, 180, 100),
+                crosshair: Color32::from_rgb(100, 200, 255),
+                pointer: Color32::from_rgb(255, 60, 60),
+                done: Color32::from_rgb(100, 200, 100),
+                in_progress: Color32::from_rgb(200, 200, 100),
+                stage_background: Color32::from_rgb(15, 27, 45),
+                stage_title: Color32::from_rgb(111, 168, 255),
+                stage_target: Color32::from_rgb(255, 215, 106),
+                stage_caption: Color32::from_rgb(183, 198, 221),
+                font_scale,
+            },
+            Theme::Light => Self {
+                segments: &DARK_SEGMENTS,
+                divider: Stroke::new(1.5, Color32::WHITE),
+                title_running: Color32::from_rgb(200, 120, 0),
+                title_done: Color32::from_rgb(20, 140, 50),
+                title_idle: Color32::from_rgb(50, 90, 150),
+                rim: Color32::from_rgb(190, 195, 205),
+                rim_rings: [
+                    Color32::from_rgb(230, 232, 238),
+                    Color32::from_rgb(160, 165, 175),
+                    Color32::from_rgb(245, 246, 250),
+                ],
+                wheel_background: Color32::from_rgb(235, 237, 242),
+                result_background: Color32::from_rgb(232, 245, 233),
+                result_glow: Color32::from_rgb(30, 160, 60),
+                result_name: Color32::from_rgb(150, 80, 0),
+                result_detail: Color32::from_rgb(60, 90, 60),
+                result_badge: Color32::from_rgb(20, 140, 50),
+                hint: Color32::DARK_GRAY,
+                bulb_on: Color32::from_rgb(255, 193, 7),
+                bulb_off: Color32::from_rgb(170, 170, 170),
+                hub: Color32::WHITE,
+                hub_ring: Color32::from_rgb(180, 140, 40),
+                crosshair: Color32::from_rgb(30, 110, 200),
+                pointer: Color32::from_rgb(220, 40, 40),
+                done: Color32::from_rgb(30, 130, 50),
+                in_progress: Color32::from_rgb(170, 120, 0),
+                stage_background: Color32::from_rgb(245, 247, 250),
+                stage_title: Color32::from_rgb(30, 80, 160),
+                stage_target: Color32::from_rgb(170, 90, 0),
+                stage_caption: Color32::from_rgb(70, 80, 100),
+                font_scale,
+            },
+            Theme::HighContrast => Self {
+                segments: &HIGH_CONTRAST_SEGMENTS,
+                divider: Stroke::new(3.0, Color32::WHITE),
+                title_running: Color32::from_rgb(255, 215, 0),
+                title_done: Color32::from_rgb(0, 255, 0),
+                title_idle: Color32::WHITE,
+                rim: Color32::BLACK,
+                rim_rings: [Color32::WHITE, Color32::BLACK, Color32::WHITE],
+                wheel_background: Color32::BLACK,
+                result_background: Color32::BLACK,
+                result_glow: Color32::from_rgb(0, 255, 0),
+                result_name: Color32::from_rgb(255, 215, 0),
+                result_detail: Color32::WHITE,
+                result_badge: Color32::from_rgb(0, 255, 0),
+                hint: Color32::WHITE,
+                bulb_on: Color32::from_rgb(255, 215, 0),
+                bulb_off: Color32::from_rgb(60, 60, 60),
+                hub: Color32::BLACK,
+                hub_ring: Color32::from_rgb(255, 215, 0),
+                crosshair: Color32::WHITE,
+                pointer: Color32::from_rgb(255, 0, 0),
+                done: Color32::from_rgb(0, 255, 0),
+                in_progress: Color32::from_rgb(255, 215, 0),
+                stage_background: Color32::BLACK,
+                stage_title: Color32::WHITE,
+                stage_target: Color32::from_rgb(255, 215, 0),
+                stage_caption: Color32::WHITE,
+                font_scale,
+            },
+        }
+    }
+}
+
+/// 应用主题：界面配色和各类文字的字号
+pub fn apply_theme(ctx: &egui::Context, settings: DisplaySettings) {
+    let (preference, visuals) = match settings.theme {
+        Theme::Dark => (egui::ThemePreference::Dark, egui::Visuals::dark()),
+        Theme::Light => (egui::ThemePreference::Light, egui::Visuals::light()),
+        Theme::HighContrast => (egui::ThemePreference::Dark, high_contrast_visuals()),
+    };
+    ctx.set_theme(preference);
+
+    let scale = settings.font_scale();
+    let mut style = (*ctx.style()).clone();
+    style.visuals = visuals;
+    for (text_style, size) in [
+        (egui::TextStyle::Small, 10.0),
+        (egui::TextStyle::Body, 14.0),
+        (egui::TextStyle::Button, 14.0),
+        (egui::TextStyle::Monospace, 13.0),
+        (egui::TextStyle::Heading, 18.0),
+    ] {
+        style.text_styles.insert(text_style, egui::FontId::proportional(size * scale));
+    }
+    style.spacing.interact_size.y = 20.0 * scale;
+    ctx.set_style(style);
+}
+
+/// 高对比度界面：黑底白字，控件加粗白色边框，选中项黄底黑字
+fn high_contrast_visuals() -> egui::Visuals {
+    let mut visuals = egui::Visuals::dark();
+    let yellow = Color32::from_rgb(255, 215, 0);
+    visuals.panel_fill = Color32::BLACK;
+    visuals.window_fill = Color32::BLACK;
+    visuals.extreme_bg_color = Color32::BLACK;
+    visuals.faint_bg_color = Color32::from_gray(35);
+    visuals.window_stroke = Stroke::new(2.0, Color32::WHITE);
+    visuals.selection.bg_fill = yellow;
+    visuals.selection.stroke = Stroke::new(2.0, Color32::BLACK);
+    visuals.hyperlink_color = yellow;
+
+    let widgets = &mut visuals.widgets;
+    widgets.noninteractive.fg_stroke = Stroke::new(1.0, Color32::WHITE);
+    widgets.noninteractive.bg_stroke = Stroke::new(1.0, Color32::WHITE);
+    for widget in [&mut widgets.inactive, &mut widgets.hovered, &mut widgets.active, &mut widgets.open] {
+        widget.fg_stroke = Stroke::new(2.0, Color32::WHITE);
+        widget.bg_stroke = Stroke::new(2.0, Color32::WHITE);
+        widget.bg_fill = Color32::from_gray(20);
+        widget.weak_bg_fill = Color32::from_gray(20);
+    }
+    widgets.hovered.bg_stroke = Stroke::new(2.0, yellow);
+    widgets.active.bg_stroke = Stroke::new(3.0, yellow);
+    visuals
+}
@@ -0,0 +1,92 @@
+//! 撤销上一次抽签对话框
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::models::DrawRecord;
+
+/// 撤销上一次抽签对话框
+pub struct UndoDialog {
+    /// 是否显示
+    pub visible: bool,
+    /// 将被撤销的记录
+    records: Vec<DrawRecord>,
+    /// 撤销原因
+    reason: String,
+    /// 错误提示
+    error: Option<String>,
+}
+
+impl Default for UndoDialog {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            records: Vec::new(),
+            reason: tr("选错被检查部门").to_string(),
+            error: None,
+        }
+    }
+}
+
+impl UndoDialog {
+    /// 打开对话框
+    pub fn open(&mut self, records: Vec<DrawRecord>) {
+        *self = Self {
+            visible: true,
+            records,
+            ..Self::default()
+        };
+    }
+
+    /// 显示对话框，确认撤销时返回撤销原因
+    pub fn show(&mut self, ctx: &egui::Context) -> Option<String> {
+        if !self.visible {
+            return None;
+        }
+
+        let mut open = self.visible;
+        let mut confirmed = None;
+        let mut cancelled = false;
+
+        egui::Window::new(tr("↩ 撤销上一次抽签"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(tr("以下结果将被作废，被检部门可重新抽取："));
+                ui.add_space(5.0);
+                for record in &self.records {
+                    ui.label(egui::RichText::new(trf(
+                        "第{}轮  {} {} → {}",
+                        &[&record.round, &record.target_department_name, &record.specialty_label(), &record.selected_from_department_name],
+                    )).strong());
+                }
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.label(tr("撤销原因:"));
+                    ui.text_edit_singleline(&mut self.reason);
+                });
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button(egui::RichText::new(tr("↩ 撤销")).color(egui::Color32::RED)).clicked() {
+                        if self.reason.trim().is_empty() {
+                            self.error = Some(tr("请填写撤销原因").to_string());
+                        } else {
+                            confirmed = Some(self.reason.trim().to_string());
+                        }
+                    }
+                    if ui.button(tr("取消")).clicked() {
+                        cancelled = true;
+                    }
+                });
+
+                if let Some(error) = &self.error {
+                    ui.label(egui::RichText::new(error).color(egui::Color32::RED));
+                }
+            });
+
+        self.visible = open && confirmed.is_none() && !cancelled;
+        confirmed
+    }
+}
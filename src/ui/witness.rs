@@ -0,0 +1,120 @@
+//! 见证确认对话框
+//!
+//! 转盘停止后由见证人（执行抽签的操作员以外的账户）输入登录名和密码确认结果，
+//! 确认后记录才保存；结果有误时可填写原因取消并重新抽取。
+
+use eframe::egui;
+use crate::i18n::{tr, trf};
+use crate::logic::AuthManager;
+use crate::models::{DrawRecord, Operator};
+
+/// 见证确认操作
+pub enum WitnessAction {
+    /// 见证人已通过验证
+    Confirm(Operator),
+    /// 取消结果（原因）
+    Cancel(String),
+}
+
+/// 见证确认对话框
+#[derive(Default)]
+pub struct WitnessDialog {
+    /// 见证人登录名
+    username: String,
+    /// 见证人密码
+    password: String,
+    /// 取消原因
+    cancel_reason: String,
+    /// 错误提示
+    error: Option<String>,
+}
+
+impl WitnessDialog {
+    /// 有待确认的结果时显示对话框
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        pending: &[DrawRecord],
+        operators: &[Operator],
+        operator: &Operator,
+        lan_enabled: bool,
+    ) -> Option<WitnessAction> {
+        if pending.is_empty() {
+            return None;
+        }
+
+        let mut action = None;
+
+        egui::Window::new(tr("✍ 见证确认"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -60.0))
+            .show(ctx, |ui| {
+                ui.label(tr("以下抽签结果需见证人确认后才会保存："));
+                ui.add_space(5.0);
+                for record in pending {
+                    ui.label(egui::RichText::new(trf(
+                        "第{}轮  {} {} → {}",
+                        &[&record.round, &record.target_department_name, &record.specialty_label(), &record.selected_from_department_name],
+                    )).strong());
+                }
+                if lan_enabled {
+                    ui.label(egui::RichText::new(tr("也可在局域网查看器页面上确认")).color(egui::Color32::GRAY));
+                }
+                ui.separator();
+
+                if operators.iter().all(|o| o.id == operator.id) {
+                    ui.label(egui::RichText::new(tr("没有其他账户可作为见证人，请管理员在账户管理中添加（如观察员账户）"))
+                        .color(egui::Color32::from_rgb(200, 150, 50)));
+                }
+
+                egui::Grid::new("witness_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(tr("见证人登录名:"));
+                        ui.text_edit_singleline(&mut self.username);
+                        ui.end_row();
+
+                        ui.label(tr("密码:"));
+                        ui.add(egui::TextEdit::singleline(&mut self.password).password(true));
+                        ui.end_row();
+                    });
+
+                ui.add_space(5.0);
+                if ui.button(tr("✔ 见证人确认")).clicked() {
+                    match AuthManager::authenticate_witness(operators, &operator.id, &self.username, &self.password) {
+                        Ok(witness) => {
+                            *self = Self::default();
+                            action = Some(WitnessAction::Confirm(witness));
+                        }
+                        Err(e) => {
+                            self.password.clear();
+                            self.error = Some(e);
+                        }
+                    }
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label(tr("取消原因:"));
+                    ui.text_edit_singleline(&mut self.cancel_reason);
+                    if ui.button(tr("✖ 取消结果")).clicked() {
+                        if self.cancel_reason.trim().is_empty() {
+                            self.error = Some(tr("请填写取消原因").to_string());
+                        } else {
+                            let reason = self.cancel_reason.trim().to_string();
+                            *self = Self::default();
+                            action = Some(WitnessAction::Cancel(reason));
+                        }
+                    }
+                });
+
+                if let Some(error) = &self.error {
+                    ui.label(egui::RichText::new(error).color(egui::Color32::RED));
+                }
+            });
+
+        action
+    }
+}
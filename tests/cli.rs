@@ -0,0 +1,215 @@
+//! 命令行版本集成测试
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use quality_draw::logic::AuthManager;
+use quality_draw::models::{AuditAction, Role};
+use quality_draw::storage::DataStore;
+
+fn cli(data_dir: &Path, args: &[&str]) -> Output {
+    cli_with_witness(data_dir, "witness", args)
+}
+
+/// 以 admin 登录、由 `witness` 见证运行命令行程序
+fn cli_with_witness(data_dir: &Path, witness: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_quality_draw-cli"))
+        .arg("--data-dir")
+        .arg(data_dir)
+        .env("QUALITY_DRAW_PASSWORD", "secret1")
+        .env("QUALITY_DRAW_WITNESS_PASSWORD", "secret2")
+        .args(["--user", "admin", "--witness", witness, "--json"])
+        .args(args)
+        .output()
+        .expect("运行命令行程序失败")
+}
+
+/// 保存抽签员 admin 和见证人 witness 两个账户
+fn save_accounts(store: &DataStore) {
+    let admin = AuthManager::create_operator(&[], "admin", "管理员", Role::Admin, "secret1").unwrap();
+    let witness = AuthManager::create_operator(&[], "witness", "见证人", Role::Observer, "secret2").unwrap();
+    store.save_operators(&[admin, witness]);
+}
+
+fn json(output: &Output) -> serde_json::Value {
+    serde_json::from_slice(&output.stdout).expect("输出不是合法的 JSON")
+}
+
+#[test]
+fn test_draw_round_and_verify() {
+    let data_dir = std::env::temp_dir().join(format!("quality_draw_cli_{}", uuid::Uuid::new_v4()));
+    let store = DataStore::with_dir(&data_dir);
+    save_accounts(&store);
+
+    // 见证人不能是抽签员本人：先显示结果，确认未通过时丢弃结果并记录取消
+    let output = cli_with_witness(&data_dir, "admin", &["draw-round"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("待见证人确认"));
+    assert!(store.load_records().unwrap().is_empty());
+    assert!(store.load_audit().iter().any(|e| e.action == AuditAction::CancelDraw));
+
+    let output = cli(&data_dir, &["draw-round", "--seed", "42"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    let draws = json(&output)["draws"].as_array().unwrap().len();
+    assert!(draws > 0);
+    assert_eq!(store.load_records().unwrap().len(), draws);
+    assert!(store.load_records().unwrap().iter().all(|r| r.witness.as_ref().is_some_and(|w| w.name == "见证人")));
+
+    // 本轮已全部抽完，重复抽取同一部门应失败
+    let target = store.load_records().unwrap()[0].target_department_id.clone();
+    let specialty = store.load_records().unwrap()[0].specialty_type.to_lowercase();
+    assert!(!cli(&data_dir, &["draw", "--target", &target, "--specialty", &specialty]).status.success());
+
+    let output = cli(&data_dir, &["verify"]);
+    assert!(output.status.success());
+    assert!(json(&output).as_array().unwrap().iter().all(|r| r["verified"] == true));
+
+    let output = cli(&data_dir, &["stats"]);
+    assert!(output.status.success());
+    let stats = json(&output);
+    assert_eq!(stats["total_draws"], draws);
+    assert!(stats["specialties"].as_array().unwrap().iter().all(|s| s["fairness"]["draws"].as_u64().unwrap() > 0));
+
+    // 关闭交叉回避作为候选规则时，同时输出当前规则和候选规则的模拟结果
+    let output = cli(&data_dir, &["simulate", "--rounds", "50", "--seed", "1", "--cross-avoidance", "false"]);
+    assert!(output.status.success());
+    let reports = json(&output);
+    let reports = reports.as_array().unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[1]["rules"]["cross_avoidance"], false);
+    assert!(reports.iter().all(|r| r["rounds"] == 50 && r["dead_end_rate"].as_f64().unwrap() <= 1.0));
+
+    let csv = data_dir.join("out.csv");
+    assert!(cli(&data_dir, &["export", "--format", "csv", "--output", csv.to_str().unwrap()]).status.success());
+    assert_eq!(std::fs::read_to_string(&csv).unwrap().lines().count(), draws + 1);
+
+    // 英文导出：表头和预置专业名称使用英文
+    assert!(cli(&data_dir, &["--lang", "en-US", "export", "--format", "csv", "--output", csv.to_str().unwrap()]).status.success());
+    let content = std::fs::read_to_string(&csv).unwrap();
+    assert!(content.starts_with("\u{feff}Draw time,Round,"));
+    assert!(!content.contains("承压类"));
+
+    // 记录文件损坏时拒绝抽签和归档，不能用空列表覆盖抽签历史
+    std::fs::write(data_dir.join("records.json"), "[{").unwrap();
+    assert!(store.load_records().is_err());
+    assert!(store.archive_round(1).is_err());
+    let output = cli(&data_dir, &["start-round"]);
+    assert!(!output.status.success());
+    assert!(json(&output)["error"].as_str().unwrap().contains("records.json"));
+    assert_eq!(std::fs::read_to_string(data_dir.join("records.json")).unwrap(), "[{");
+
+    std::fs::remove_dir_all(&data_dir).ok();
+}
+
+#[test]
+fn test_team_draw() {
+    let data_dir = std::env::temp_dir().join(format!("quality_draw_cli_{}", uuid::Uuid::new_v4()));
+    let store = DataStore::with_dir(&data_dir);
+    save_accounts(&store);
+    let mut departments = store.load_departments();
+    departments.iter_mut().find(|d| d.id == "nd").unwrap().set_team_size("Pressure", 2);
+    store.save_departments(&departments);
+
+    // 一次抽出两个检查部门，分组保存
+    let output = cli(&data_dir, &["draw", "--target", "nd", "--specialty", "承压类", "--seed", "5"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(json(&output)[0]["selected_department_ids"].as_array().unwrap().len(), 2);
+    let records = store.load_records().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_ne!(records[0].selected_from_department_id, records[1].selected_from_department_id);
+    assert_eq!(records[0].team.as_ref().unwrap().group_id, records[1].team.as_ref().unwrap().group_id);
+
+    // 检查组已抽齐，不能再抽
+    assert!(!cli(&data_dir, &["draw", "--target", "nd", "--specialty", "承压类"]).status.success());
+
+    let output = cli(&data_dir, &["verify"]);
+    assert!(output.status.success());
+    assert!(json(&output).as_array().unwrap().iter().all(|r| r["verified"] == true));
+
+    // 账户文件损坏时拒绝登录，不能当作首次运行
+    std::fs::write(data_dir.join("operators.json"), "{").unwrap();
+    let output = cli(&data_dir, &["start-round"]);
+    assert!(!output.status.success());
+    assert!(json(&output)["error"].as_str().unwrap().contains("operators.json"));
+
+    std::fs::remove_dir_all(&data_dir).ok();
+}
+
+#[test]
+fn test_schedule() {
+    let data_dir = std::env::temp_dir().join(format!("quality_draw_cli_{}", uuid::Uuid::new_v4()));
+    let store = DataStore::with_dir(&data_dir);
+    save_accounts(&store);
+    assert!(cli(&data_dir, &["draw-round", "--seed", "42"]).status.success());
+
+    // 同一检查部门被抽去检查两个不同的被检部门
+    let records = store.load_records().unwrap();
+    let (first, second) = records.iter()
+        .flat_map(|a| records.iter().map(move |b| (a, b)))
+        .find(|(a, b)| a.selected_from_department_id == b.selected_from_department_id && a.target_department_id != b.target_department_id)
+        .expect("本轮应有检查部门检查多个被检部门");
+
+    let output = cli(&data_dir, &["schedule", "set", "--record", &first.id[..8], "--date", "2026-11-03", "--slot", "morning"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    assert!(json(&output)["conflicts"].as_array().unwrap().is_empty());
+
+    // 同一天再安排检查另一个被检部门：保存成功但提示冲突
+    let output = cli(&data_dir, &["schedule", "set", "--record", &second.id, "--date", "2026-11-03", "--slot", "afternoon"]);
+    assert!(output.status.success());
+    assert_eq!(json(&output)["conflicts"].as_array().unwrap().len(), 1);
+    assert_eq!(store.load_schedule().len(), 2);
+
+    let output = cli(&data_dir, &["schedule", "list"]);
+    assert_eq!(json(&output)["items"].as_array().unwrap().len(), records.len());
+    assert_eq!(json(&output)["conflicts"].as_array().unwrap().len(), 1);
+
+    let ics = data_dir.join("schedule.ics");
+    assert!(cli(&data_dir, &["schedule", "export", "--format", "ics", "--output", ics.to_str().unwrap()]).status.success());
+    let content = std::fs::read_to_string(&ics).unwrap();
+    assert_eq!(content.matches("BEGIN:VEVENT").count(), 2);
+    assert!(content.contains("DTSTART:20261103T090000"));
+    assert!(content.contains(&format!("UID:{}@quality_draw", second.id)));
+
+    assert!(cli(&data_dir, &["schedule", "remove", "--record", &second.id]).status.success());
+    assert_eq!(store.load_schedule().len(), 1);
+
+    std::fs::remove_dir_all(&data_dir).ok();
+}
+
+#[test]
+fn test_outcome() {
+    let data_dir = std::env::temp_dir().join(format!("quality_draw_cli_{}", uuid::Uuid::new_v4()));
+    let store = DataStore::with_dir(&data_dir);
+    save_accounts(&store);
+    assert!(cli(&data_dir, &["draw-round", "--seed", "42"]).status.success());
+    let records = store.load_records().unwrap();
+
+    // 尚未登记结果的检查都在未完成列表中
+    let output = cli(&data_dir, &["outcome", "list"]);
+    assert_eq!(json(&output).as_array().unwrap().len(), records.len());
+
+    let record = &records[0].id[..8];
+    let output = cli(&data_dir, &["outcome", "set", "--record", record, "--status", "in-progress", "--general", "2"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(json(&output)["status"], "InProgress");
+
+    // 评分超出范围不能保存
+    assert!(!cli(&data_dir, &["outcome", "set", "--record", record, "--score", "120"]).status.success());
+
+    // 完成时保留已登记的问题数，默认今天结案
+    let output = cli(&data_dir, &["outcome", "set", "--record", record, "--status", "done", "--minor", "1", "--score", "92.5"]);
+    assert!(output.status.success());
+    assert_eq!(json(&output)["findings"]["general"], 2);
+    assert!(json(&output)["closed_on"].is_string());
+
+    let output = cli(&data_dir, &["outcome", "list"]);
+    assert_eq!(json(&output).as_array().unwrap().len(), records.len() - 1);
+
+    let report = data_dir.join("report.xlsx");
+    let output = cli(&data_dir, &["outcome", "export", "--output", report.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(json(&output)["done"], 1);
+    assert!(report.exists());
+
+    std::fs::remove_dir_all(&data_dir).ok();
+}